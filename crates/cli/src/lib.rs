@@ -18,6 +18,7 @@ use roc_collections::MutMap;
 use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
+use roc_glue::types::PositionalNaming;
 use roc_load::{ExpectMetadata, Threading};
 #[cfg(not(windows))]
 use roc_module::symbol::ModuleId;
@@ -95,6 +96,14 @@ pub const FLAG_PP_PLATFORM: &str = "platform";
 pub const FLAG_PP_DYLIB: &str = "lib";
 pub const FLAG_MIGRATE: &str = "migrate";
 pub const FLAG_DOCS_ROOT: &str = "root-dir";
+pub const FLAG_GLUE_POSITIONAL_FIELDS: &str = "positional-fields";
+pub const FLAG_GLUE_OPAQUE: &str = "opaque";
+pub const FLAG_GLUE_NEST_PAYLOADS: &str = "nest-payloads";
+pub const FLAG_GLUE_EXPLICIT_PADDING: &str = "explicit-padding";
+pub const FLAG_GLUE_PAYLOAD_ACCESSORS: &str = "payload-accessors";
+pub const FLAG_GLUE_SORT_BY_NAME: &str = "sort-by-name";
+pub const FLAG_GLUE_SIZE_MANIFEST: &str = "size-manifest";
+pub const FLAG_GLUE_KEEP: &str = "keep";
 
 pub const VERSION: &str = env!("ROC_VERSION");
 const DEFAULT_GENERATED_DOCS_DIR: &str = "generated-docs";
@@ -436,7 +445,7 @@ pub fn build_app() -> Command {
             .arg(&flag_dev)
             .arg(
                 Arg::new(GLUE_SPEC)
-                    .help("The specification for how to translate Roc types into output files.")
+                    .help("The specification for how to translate Roc types into output files.\nThis can also be the name of a bundled spec: c, rust, zig, python, wit, or describe.")
                     .value_parser(value_parser!(PathBuf))
                     .required(true)
             )
@@ -454,6 +463,63 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME)
             )
             .arg(flag_linker.clone())
+            .arg(
+                Arg::new(FLAG_GLUE_POSITIONAL_FIELDS)
+                    .long(FLAG_GLUE_POSITIONAL_FIELDS)
+                    .help("How to name the fields of tag payloads, which only have positions: index (0), underscore (_0), f (f0), or field (field0)")
+                    .value_parser(PossibleValuesParser::new(PositionalNaming::ALL.map(PositionalNaming::name)))
+                    .default_value(PositionalNaming::default().name())
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_OPAQUE)
+                    .long(FLAG_GLUE_OPAQUE)
+                    .help("The name of a type whose fields the host shouldn't see; it only gets the type's size and alignment.\nThis can be given more than once, and the type can't contain anything reference-counted.")
+                    .action(ArgAction::Append)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_NEST_PAYLOADS)
+                    .long(FLAG_GLUE_NEST_PAYLOADS)
+                    .help("Declare each tag union's payload structs inside a module named after the union, rather than as top-level types.\nThis only affects glue targets which have modules, like rust.")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_EXPLICIT_PADDING)
+                    .long(FLAG_GLUE_EXPLICIT_PADDING)
+                    .help("Declare the padding in structs as explicit `_padN` fields, so their layout doesn't depend on the host compiler's.\nThis currently only affects rust glue.")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_PAYLOAD_ACCESSORS)
+                    .long(FLAG_GLUE_PAYLOAD_ACCESSORS)
+                    .help("Generate a `get_<tag>` accessor for tag payloads, which returns nothing for the union's other tags.\nThis currently only affects rust glue.")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_SORT_BY_NAME)
+                    .long(FLAG_GLUE_SORT_BY_NAME)
+                    .help("Declare types alphabetically, wherever their dependencies allow, so the output doesn't churn when unrelated types change.\nThis currently only affects rust glue.")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_SIZE_MANIFEST)
+                    .long(FLAG_GLUE_SIZE_MANIFEST)
+                    .help("Also write the size and alignment of every named type, for each architecture, to this file.\nCommitting it lets CI notice when a platform's types change size.")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+            )
+            .arg(
+                Arg::new(FLAG_GLUE_KEEP)
+                    .long(FLAG_GLUE_KEEP)
+                    .help("The name of a type to generate glue for, along with the types it uses. This can be given more than once, and without it every type is generated.\nEntry points are only generated if their type is kept.")
+                    .action(ArgAction::Append)
+                    .required(false)
+            )
         )
         .subcommand(Command::new(CMD_PREPROCESS_HOST)
             .about("Runs the surgical linker preprocessor to generate `.rh` and `.rm` files.")
@@ -7,14 +7,18 @@ use roc_cli::{
     AnnotationProblem, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DOCS,
     CMD_FORMAT, CMD_FORMAT_ANNOTATE, CMD_GLUE, CMD_LICENSES, CMD_PREPROCESS_HOST, CMD_REPL,
     CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_DOCS_ROOT,
-    FLAG_LIB, FLAG_MAIN, FLAG_MIGRATE, FLAG_NO_COLOR, FLAG_NO_HEADER, FLAG_NO_LINK, FLAG_OUTPUT,
-    FLAG_PP_DYLIB, FLAG_PP_HOST, FLAG_PP_PLATFORM, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME,
-    FLAG_VERBOSE, GLUE_DIR, GLUE_SPEC, ROC_FILE, VERSION,
+    FLAG_GLUE_EXPLICIT_PADDING, FLAG_GLUE_KEEP, FLAG_GLUE_NEST_PAYLOADS, FLAG_GLUE_OPAQUE,
+    FLAG_GLUE_PAYLOAD_ACCESSORS, FLAG_GLUE_POSITIONAL_FIELDS, FLAG_GLUE_SIZE_MANIFEST,
+    FLAG_GLUE_SORT_BY_NAME, FLAG_LIB, FLAG_MAIN, FLAG_MIGRATE, FLAG_NO_COLOR, FLAG_NO_HEADER,
+    FLAG_NO_LINK, FLAG_OUTPUT, FLAG_PP_DYLIB, FLAG_PP_HOST, FLAG_PP_PLATFORM, FLAG_STDIN,
+    FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, FLAG_VERBOSE, GLUE_DIR, GLUE_SPEC, ROC_FILE, VERSION,
 };
 use roc_docs::generate_docs_html;
 use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
+use roc_glue::emit::EmitTarget;
+use roc_glue::GlueOptions;
 use roc_load::{LoadingProblem, Threading};
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_target::Target;
@@ -122,15 +126,60 @@ fn main() -> io::Result<()> {
             let target = Triple::host().into();
             let linking_strategy = default_linking_strategy(matches, link_type, target);
 
+            let options = GlueOptions {
+                positional_naming: matches
+                    .get_one::<String>(FLAG_GLUE_POSITIONAL_FIELDS)
+                    .unwrap()
+                    .parse()
+                    .unwrap(),
+                opaque_names: matches
+                    .get_many::<String>(FLAG_GLUE_OPAQUE)
+                    .into_iter()
+                    .flatten()
+                    .cloned()
+                    .collect(),
+                nest_payload_types: matches.get_flag(FLAG_GLUE_NEST_PAYLOADS),
+                explicit_padding: matches.get_flag(FLAG_GLUE_EXPLICIT_PADDING),
+                payload_accessors: matches.get_flag(FLAG_GLUE_PAYLOAD_ACCESSORS),
+                sort_by_name: matches.get_flag(FLAG_GLUE_SORT_BY_NAME),
+                size_manifest: matches.get_one::<PathBuf>(FLAG_GLUE_SIZE_MANIFEST).cloned(),
+                keep_names: matches
+                    .get_many::<String>(FLAG_GLUE_KEEP)
+                    .into_iter()
+                    .flatten()
+                    .cloned()
+                    .collect(),
+            };
+
+            // a spec that isn't a file on disk may be the name of one of the bundled specs
+            let bundled_target = match spec_path.exists() {
+                true => None,
+                false => spec_path
+                    .to_str()
+                    .and_then(|name| name.parse::<EmitTarget>().ok()),
+            };
+
             if !output_path.exists() || output_path.is_dir() {
-                roc_glue::generate(
-                    input_path,
-                    output_path,
-                    spec_path,
-                    backend,
-                    link_type,
-                    linking_strategy,
-                )
+                match bundled_target {
+                    Some(target) => roc_glue::emit::write(
+                        target,
+                        input_path,
+                        output_path,
+                        backend,
+                        link_type,
+                        linking_strategy,
+                        &options,
+                    ),
+                    None => roc_glue::generate(
+                        input_path,
+                        output_path,
+                        spec_path,
+                        backend,
+                        link_type,
+                        linking_strategy,
+                        &options,
+                    ),
+                }
             } else {
                 eprintln!("`roc glue` must be given a directory to output into, because the glue might generate multiple files.");
 
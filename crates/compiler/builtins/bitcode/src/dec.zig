@@ -50,6 +50,27 @@ pub const RocDec = extern struct {
         return @as(f64, @floatFromInt(dec.num)) / comptime @as(f64, @floatFromInt(one_point_zero_i128));
     }
 
+    // A Dec is never too big for an F32 or F64, so this only ever reports
+    // whether the float had to round.
+    pub fn toFloatChecked(dec: RocDec, comptime F: type) num_.ToFloatCheckedResult(F) {
+        // 10^18 is 5^18 * 2^18, so a Dec is exactly a float only when it's a whole
+        // number of 2^-18ths, and that number has few enough bits to fit in F.
+        const five_to_the_18: i128 = comptime math.pow(i128, 5, decimal_places);
+
+        if (@rem(dec.num, five_to_the_18) != 0) {
+            return .{ .value = @floatCast(dec.toF64()), .inexact = true, .out_of_bounds = false };
+        }
+
+        const units = @divExact(dec.num, five_to_the_18);
+        const rounded: F = @floatFromInt(units);
+
+        return .{
+            .value = math.ldexp(rounded, -@as(i32, decimal_places)),
+            .inexact = @as(i128, @intFromFloat(rounded)) != units,
+            .out_of_bounds = false,
+        };
+    }
+
     // TODO: If Str.toDec eventually supports more error types, return errors here.
     // For now, just return null which will give the default error.
     pub fn fromStr(roc_str: RocStr) ?RocDec {
@@ -580,6 +601,34 @@ pub const RocDec = extern struct {
     pub fn atan(self: RocDec) RocDec {
         return fromF64(math.atan(self.toF64())).?;
     }
+
+    pub fn sinh(self: RocDec) RocDec {
+        return fromF64(math.sinh(self.toF64())).?;
+    }
+
+    pub fn cosh(self: RocDec) RocDec {
+        return fromF64(math.cosh(self.toF64())).?;
+    }
+
+    pub fn tanh(self: RocDec) RocDec {
+        return fromF64(math.tanh(self.toF64())).?;
+    }
+
+    pub fn asinh(self: RocDec) RocDec {
+        return fromF64(math.asinh(self.toF64())).?;
+    }
+
+    pub fn acosh(self: RocDec) RocDec {
+        return fromF64(math.acosh(self.toF64())).?;
+    }
+
+    pub fn atanh(self: RocDec) RocDec {
+        return fromF64(math.atanh(self.toF64())).?;
+    }
+
+    pub fn atan2(y: RocDec, x: RocDec) RocDec {
+        return fromF64(math.atan2(y.toF64(), x.toF64())).?;
+    }
 };
 
 // A number has `k` trailling zeros if `10^k` divides into it cleanly
@@ -926,6 +975,21 @@ test "fromF64 overflow" {
     try expectEqual(dec, null);
 }
 
+test "toFloatChecked: exact" {
+    try expectEqual(num_.ToFloatCheckedResult(f64){ .value = 25.5, .inexact = false, .out_of_bounds = false }, RocDec.fromF64(25.5).?.toFloatChecked(f64));
+    try expectEqual(num_.ToFloatCheckedResult(f32){ .value = -0.25, .inexact = false, .out_of_bounds = false }, RocDec.fromStr(RocStr.init("-0.25", 5)).?.toFloatChecked(f32));
+    try expectEqual(num_.ToFloatCheckedResult(f64){ .value = 0.0, .inexact = false, .out_of_bounds = false }, (RocDec{ .num = 0 }).toFloatChecked(f64));
+}
+
+test "toFloatChecked: inexact" {
+    const tenth = RocDec.fromStr(RocStr.init("0.1", 3)).?;
+    try expectEqual(num_.ToFloatCheckedResult(f64){ .value = 0.1, .inexact = true, .out_of_bounds = false }, tenth.toFloatChecked(f64));
+
+    // 2^24 + 1 is a whole number, but it has too many bits for an f32.
+    try expectEqual(num_.ToFloatCheckedResult(f32){ .value = 16777216.0, .inexact = true, .out_of_bounds = false }, RocDec.fromU64(16777217).toFloatChecked(f32));
+    try expectEqual(false, RocDec.fromU64(16777217).toFloatChecked(f64).inexact);
+}
+
 test "fromStr: empty" {
     const roc_str = RocStr.init("", 0);
     const dec = RocDec.fromStr(roc_str);
@@ -1468,6 +1532,35 @@ pub fn toF64(arg: RocDec) callconv(.C) f64 {
     return @call(.always_inline, RocDec.toF64, .{arg});
 }
 
+pub fn exportToFloatChecked(comptime F: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(arg: RocDec) callconv(.C) num_.ToFloatCheckedResult(F) {
+            return @call(.always_inline, RocDec.toFloatChecked, .{ arg, F });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(F), .linkage = .strong });
+}
+
+// The LLVM backend can't easily take the struct above back from zig, so it asks
+// for the two parts separately.
+pub fn exportToFloat(comptime F: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(arg: RocDec) callconv(.C) F {
+            return @call(.always_inline, RocDec.toFloatChecked, .{ arg, F }).value;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(F), .linkage = .strong });
+}
+
+pub fn exportToFloatInexact(comptime F: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(arg: RocDec) callconv(.C) bool {
+            return @call(.always_inline, RocDec.toFloatChecked, .{ arg, F }).inexact;
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(F), .linkage = .strong });
+}
+
 pub fn exportFromInt(comptime T: type, comptime name: []const u8) void {
     const f = struct {
         fn func(self: T) callconv(.C) i128 {
@@ -1565,6 +1658,34 @@ pub fn atanC(arg: RocDec) callconv(.C) i128 {
     return @call(.always_inline, RocDec.atan, .{arg}).num;
 }
 
+pub fn sinhC(arg: RocDec) callconv(.C) i128 {
+    return @call(.always_inline, RocDec.sinh, .{arg}).num;
+}
+
+pub fn coshC(arg: RocDec) callconv(.C) i128 {
+    return @call(.always_inline, RocDec.cosh, .{arg}).num;
+}
+
+pub fn tanhC(arg: RocDec) callconv(.C) i128 {
+    return @call(.always_inline, RocDec.tanh, .{arg}).num;
+}
+
+pub fn asinhC(arg: RocDec) callconv(.C) i128 {
+    return @call(.always_inline, RocDec.asinh, .{arg}).num;
+}
+
+pub fn acoshC(arg: RocDec) callconv(.C) i128 {
+    return @call(.always_inline, RocDec.acosh, .{arg}).num;
+}
+
+pub fn atanhC(arg: RocDec) callconv(.C) i128 {
+    return @call(.always_inline, RocDec.atanh, .{arg}).num;
+}
+
+pub fn atan2C(arg1: RocDec, arg2: RocDec) callconv(.C) i128 {
+    return @call(.always_inline, RocDec.atan2, .{ arg1, arg2 }).num;
+}
+
 pub fn addOrPanicC(arg1: RocDec, arg2: RocDec) callconv(.C) RocDec {
     return @call(.always_inline, RocDec.add, .{ arg1, arg2 });
 }
@@ -1589,6 +1710,11 @@ pub fn mulSaturatedC(arg1: RocDec, arg2: RocDec) callconv(.C) RocDec {
     return @call(.always_inline, RocDec.mulSaturated, .{ arg1, arg2 });
 }
 
+// Dec has no fused form; the product is rounded to 18 decimal places before the add.
+pub fn mulAddC(arg1: RocDec, arg2: RocDec, arg3: RocDec) callconv(.C) RocDec {
+    return RocDec.add(RocDec.mul(arg1, arg2), arg3);
+}
+
 pub fn exportRound(comptime T: type, comptime name: []const u8) void {
     const f = struct {
         fn func(input: RocDec) callconv(.C) T {
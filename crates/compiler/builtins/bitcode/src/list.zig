@@ -790,6 +790,11 @@ pub fn listConcat(
 
             return list_a;
         }
+    } else if (list_a.isEmpty() and list_a.getCapacity() == 0) {
+        // Nothing to keep from a, so take over b instead of copying it into a fresh allocation.
+        // This is the common first step when building a list up by concatenating onto [].
+        list_a.decref(alignment, element_width, elements_refcounted, dec);
+        return list_b;
     } else if (list_a.isUnique()) {
         const total_length: usize = list_a.len() + list_b.len();
 
@@ -957,8 +962,8 @@ pub fn listClone(
 
 pub fn listCapacity(
     list: RocList,
-) callconv(.C) usize {
-    return list.getCapacity();
+) callconv(.C) u64 {
+    return @intCast(list.getCapacity());
 }
 
 pub fn listAllocationPtr(
@@ -987,6 +992,35 @@ test "listConcat: non-unique with unique overlapping" {
     try expect(concatted.eql(wanted));
 }
 
+test "listConcat: unique list with spare capacity is extended in place" {
+    const prefix = RocList.fromSlice(u8, &[_]u8{ 1, 2 }, false);
+    const reserved = listReserve(prefix, @alignOf(u8), 8, @sizeOf(u8), false, rcNone, .Immutable);
+    const reserved_bytes = reserved.bytes;
+
+    const suffix = RocList.fromSlice(u8, &[_]u8{ 3, 4, 5 }, false);
+    const extended = listConcat(reserved, suffix, @alignOf(u8), @sizeOf(u8), false, rcNone, rcNone);
+    defer extended.decref(@alignOf(u8), @sizeOf(u8), false, rcNone);
+
+    const wanted = RocList.fromSlice(u8, &[_]u8{ 1, 2, 3, 4, 5 }, false);
+    defer wanted.decref(@alignOf(u8), @sizeOf(u8), false, rcNone);
+
+    try expect(extended.eql(wanted));
+    try expectEqual(reserved_bytes, extended.bytes);
+}
+
+test "listConcat: empty list takes over the other list's allocation" {
+    const suffix = RocList.fromSlice(u8, &[_]u8{ 3, 4, 5 }, false);
+    const suffix_bytes = suffix.bytes;
+
+    const concatted = listConcat(RocList.empty(), suffix, @alignOf(u8), @sizeOf(u8), false, rcNone, rcNone);
+    defer concatted.decref(@alignOf(u8), @sizeOf(u8), false, rcNone);
+
+    try expectEqual(suffix_bytes, concatted.bytes);
+
+    const both_empty = listConcat(RocList.empty(), RocList.empty(), @alignOf(u8), @sizeOf(u8), false, rcNone, rcNone);
+    try expectEqual(@as(?[*]u8, null), both_empty.bytes);
+}
+
 pub fn listConcatUtf8(
     list: RocList,
     string: str.RocStr,
@@ -1017,3 +1051,109 @@ test "listConcatUtf8" {
     defer expected.decref(1, 1, false, &rcNone);
     try expect(ret.eql(expected));
 }
+
+// The number of steps of size `step` from `from` up to (but not including) `to`.
+// Requires `from <= to`. Computed in 128 bits so the span of any integer type fits.
+fn rangeLength(comptime T: type, from: T, to: T, step: u128) usize {
+    const distance: u128 = switch (@typeInfo(T).Int.signedness) {
+        .signed => @bitCast(@as(i128, to) -% @as(i128, from)),
+        .unsigned => @as(u128, to) - @as(u128, from),
+    };
+
+    return @intCast(distance / step + @intFromBool(distance % step != 0));
+}
+
+fn buildRange(comptime T: type, start: T, length: usize, ascending: bool, step: T) RocList {
+    const list = RocList.allocateExact(@alignOf(T), length, @sizeOf(T), false);
+
+    if (list.elements(T)) |elements| {
+        var value = start;
+        for (0..length) |i| {
+            elements[i] = value;
+            // the step after the last element may leave the range of T
+            value = if (ascending) value +% step else value -% step;
+        }
+    }
+
+    return list;
+}
+
+fn listRange(comptime T: type, start: T, end: T) RocList {
+    if (start <= end) {
+        return buildRange(T, start, rangeLength(T, start, end, 1), true, 1);
+    } else {
+        return buildRange(T, start, rangeLength(T, end, start, 1), false, 1);
+    }
+}
+
+fn listRangeWithStep(comptime T: type, start: T, end: T, step: T) RocList {
+    if (step > 0 and start < end) {
+        return buildRange(T, start, rangeLength(T, start, end, @intCast(step)), true, step);
+    }
+
+    if (@typeInfo(T).Int.signedness == .signed and step < 0 and start > end) {
+        // keep adding the (negative) step: negating minInt(T) would overflow
+        const magnitude: u128 = @abs(step);
+        return buildRange(T, start, rangeLength(T, end, start, magnitude), true, step);
+    }
+
+    // a zero step, or a step pointing away from `end`, never reaches it
+    return RocList.empty();
+}
+
+pub fn exportRange(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(start: T, end: T) callconv(.C) RocList {
+            return @call(.always_inline, listRange, .{ T, start, end });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportRangeWithStep(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(start: T, end: T, step: T) callconv(.C) RocList {
+            return @call(.always_inline, listRangeWithStep, .{ T, start, end, step });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+test "listRange: ascending and descending" {
+    const up = listRange(i64, 2, 5);
+    defer up.decref(@alignOf(i64), @sizeOf(i64), false, rcNone);
+    const wanted_up = RocList.fromSlice(i64, &[_]i64{ 2, 3, 4 }, false);
+    defer wanted_up.decref(@alignOf(i64), @sizeOf(i64), false, rcNone);
+    try expect(up.eql(wanted_up));
+
+    const down = listRange(u8, 3, 0);
+    defer down.decref(@alignOf(u8), @sizeOf(u8), false, rcNone);
+    const wanted_down = RocList.fromSlice(u8, &[_]u8{ 3, 2, 1 }, false);
+    defer wanted_down.decref(@alignOf(u8), @sizeOf(u8), false, rcNone);
+    try expect(down.eql(wanted_down));
+
+    try expectEqual(@as(usize, 0), listRange(i8, 7, 7).len());
+}
+
+test "listRangeWithStep: uneven step stops before end" {
+    const up = listRangeWithStep(u8, 250, 255, 2);
+    defer up.decref(@alignOf(u8), @sizeOf(u8), false, rcNone);
+    const wanted = RocList.fromSlice(u8, &[_]u8{ 250, 252, 254 }, false);
+    defer wanted.decref(@alignOf(u8), @sizeOf(u8), false, rcNone);
+    try expect(up.eql(wanted));
+}
+
+test "listRangeWithStep: negative step" {
+    const down = listRangeWithStep(i8, 100, -128, -100);
+    defer down.decref(@alignOf(i8), @sizeOf(i8), false, rcNone);
+    const wanted = RocList.fromSlice(i8, &[_]i8{ 100, 0, -100 }, false);
+    defer wanted.decref(@alignOf(i8), @sizeOf(i8), false, rcNone);
+    try expect(down.eql(wanted));
+}
+
+test "listRangeWithStep: zero or wrong-signed step is empty" {
+    try expectEqual(@as(usize, 0), listRangeWithStep(i64, 0, 10, 0).len());
+    try expectEqual(@as(usize, 0), listRangeWithStep(i64, 0, 10, -1).len());
+    try expectEqual(@as(usize, 0), listRangeWithStep(i64, 10, 0, 1).len());
+    try expectEqual(@as(usize, 0), listRangeWithStep(u32, 10, 0, 1).len());
+}
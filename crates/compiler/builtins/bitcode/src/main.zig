@@ -23,12 +23,17 @@ comptime {
 comptime {
     exportDecFn(dec.absC, "abs");
     exportDecFn(dec.acosC, "acos");
+    exportDecFn(dec.acoshC, "acosh");
     exportDecFn(dec.addC, "add_with_overflow");
     exportDecFn(dec.addOrPanicC, "add_or_panic");
     exportDecFn(dec.addSaturatedC, "add_saturated");
     exportDecFn(dec.asinC, "asin");
+    exportDecFn(dec.asinhC, "asinh");
     exportDecFn(dec.atanC, "atan");
+    exportDecFn(dec.atanhC, "atanh");
+    exportDecFn(dec.atan2C, "atan2");
     exportDecFn(dec.cosC, "cos");
+    exportDecFn(dec.coshC, "cosh");
     exportDecFn(dec.divC, "div");
     exportDecFn(dec.eqC, "eq");
     exportDecFn(dec.fromF32C, "from_float.f32");
@@ -40,14 +45,23 @@ comptime {
     exportDecFn(dec.mulC, "mul_with_overflow");
     exportDecFn(dec.mulOrPanicC, "mul_or_panic");
     exportDecFn(dec.mulSaturatedC, "mul_saturated");
+    exportDecFn(dec.mulAddC, "mul_add");
     exportDecFn(dec.negateC, "negate");
     exportDecFn(dec.neqC, "neq");
     exportDecFn(dec.sinC, "sin");
+    exportDecFn(dec.sinhC, "sinh");
     exportDecFn(dec.subC, "sub_with_overflow");
     exportDecFn(dec.subOrPanicC, "sub_or_panic");
     exportDecFn(dec.subSaturatedC, "sub_saturated");
     exportDecFn(dec.tanC, "tan");
+    exportDecFn(dec.tanhC, "tanh");
     exportDecFn(dec.toF64, "to_f64");
+    dec.exportToFloatChecked(f32, ROC_BUILTINS ++ ".dec.to_float_checked.");
+    dec.exportToFloatChecked(f64, ROC_BUILTINS ++ ".dec.to_float_checked.");
+    dec.exportToFloat(f32, ROC_BUILTINS ++ ".dec.to_float.");
+    dec.exportToFloat(f64, ROC_BUILTINS ++ ".dec.to_float.");
+    dec.exportToFloatInexact(f32, ROC_BUILTINS ++ ".dec.to_float_inexact.");
+    dec.exportToFloatInexact(f64, ROC_BUILTINS ++ ".dec.to_float_inexact.");
     exportDecFn(dec.toI128, "to_i128");
     exportDecFn(dec.fromI128, "from_i128");
     exportDecFn(dec.to_str, "to_str");
@@ -84,6 +98,11 @@ comptime {
     exportListFn(list.listConcatUtf8, "concat_utf8");
     exportListFn(list.listIncref, "incref");
     exportListFn(list.listDecref, "decref");
+
+    for (INTEGERS) |T| {
+        list.exportRange(T, ROC_BUILTINS ++ ".list.range.");
+        list.exportRangeWithStep(T, ROC_BUILTINS ++ ".list.range_with_step.");
+    }
 }
 
 // Num Module
@@ -123,7 +142,10 @@ comptime {
 
     for (INTEGERS, 0..) |T, i| {
         num.exportPow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".pow_int.");
+        num.exportPowIntChecked(T, ROC_BUILTINS ++ "." ++ NUM ++ ".pow_int_checked.");
         num.exportDivCeil(T, ROC_BUILTINS ++ "." ++ NUM ++ ".div_ceil.");
+        num.exportDivTruncChecked(T, ROC_BUILTINS ++ "." ++ NUM ++ ".div_trunc_checked.");
+        num.exportRemChecked(T, ROC_BUILTINS ++ "." ++ NUM ++ ".rem_checked.");
 
         num.exportRound(f32, T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f32.");
         num.exportRound(f64, T, ROC_BUILTINS ++ "." ++ NUM ++ ".round_f64.");
@@ -134,6 +156,10 @@ comptime {
 
         num.exportNumToFloatCast(T, f32, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_float_cast_f32.");
         num.exportNumToFloatCast(T, f64, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_float_cast_f64.");
+        num.exportNumToFloatChecked(T, f32, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_f32_checked.");
+        num.exportNumToFloatChecked(T, f64, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_f64_checked.");
+        num.exportNumTo128(T, i128, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_i128.");
+        num.exportNumTo128(T, u128, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_u128.");
 
         num.exportAddWithOverflow(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_with_overflow.");
         num.exportAddOrPanic(T, ROC_BUILTINS ++ "." ++ NUM ++ ".add_or_panic.");
@@ -166,9 +192,20 @@ comptime {
     }
 
     for (FLOATS) |T| {
+        num.exportNumToFloatChecked(T, f32, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_f32_checked.");
+        num.exportNumToFloatChecked(T, f64, ROC_BUILTINS ++ "." ++ NUM ++ ".num_to_f64_checked.");
+
         num.exportAsin(T, ROC_BUILTINS ++ "." ++ NUM ++ ".asin.");
         num.exportAcos(T, ROC_BUILTINS ++ "." ++ NUM ++ ".acos.");
+        num.exportMulAdd(T, ROC_BUILTINS ++ "." ++ NUM ++ ".mul_add.");
         num.exportAtan(T, ROC_BUILTINS ++ "." ++ NUM ++ ".atan.");
+        num.exportAtan2(T, ROC_BUILTINS ++ "." ++ NUM ++ ".atan2.");
+        num.exportSinh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sinh.");
+        num.exportCosh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".cosh.");
+        num.exportTanh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".tanh.");
+        num.exportAsinh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".asinh.");
+        num.exportAcosh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".acosh.");
+        num.exportAtanh(T, ROC_BUILTINS ++ "." ++ NUM ++ ".atanh.");
 
         num.exportSin(T, ROC_BUILTINS ++ "." ++ NUM ++ ".sin.");
         num.exportCos(T, ROC_BUILTINS ++ "." ++ NUM ++ ".cos.");
@@ -194,6 +231,7 @@ const str = @import("str.zig");
 comptime {
     exportStrFn(str.init, "init");
     exportStrFn(str.strSplitOn, "str_split_on");
+    exportStrFn(str.strSplitOnAny, "str_split_on_any");
     exportStrFn(str.countSegments, "count_segments");
     exportStrFn(str.countUtf8Bytes, "count_utf8_bytes");
     exportStrFn(str.isEmpty, "is_empty");
@@ -221,9 +259,13 @@ comptime {
     exportStrFn(str.strWithAsciiLowercased, "with_ascii_lowercased");
     exportStrFn(str.strWithAsciiUppercased, "with_ascii_uppercased");
     exportStrFn(str.strCaselessAsciiEquals, "caseless_ascii_equals");
+    exportStrFn(str.strCompare, "compare");
+    exportStrFn(str.strSplitFirst, "split_first");
+    exportStrFn(str.strSplitLast, "split_last");
 
     for (INTEGERS) |T| {
         str.exportFromInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_int.");
+        str.exportFromIntWithRadix(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_int_with_radix.");
         num.exportParseInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".to_int.");
     }
 
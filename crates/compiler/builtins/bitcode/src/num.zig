@@ -107,6 +107,150 @@ pub fn exportNumToFloatCast(comptime T: type, comptime F: type, comptime name: [
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
 }
 
+// `value` is always the nearest float. `inexact` means it had to round, and
+// `out_of_bounds` means the number was too big for the float at all, in which
+// case `value` is infinite.
+pub fn ToFloatCheckedResult(comptime F: type) type {
+    return extern struct {
+        value: F,
+        inexact: bool,
+        out_of_bounds: bool,
+    };
+}
+
+fn numToFloatChecked(comptime T: type, comptime F: type, x: T) ToFloatCheckedResult(F) {
+    switch (@typeInfo(T)) {
+        .Int => |int| {
+            const value: F = @floatFromInt(x);
+
+            // Only a U128 going to an F32 can overflow.
+            if (std.math.isInf(value)) {
+                return .{ .value = value, .inexact = false, .out_of_bounds = true };
+            }
+
+            // Check the range before converting back, since converting an
+            // out-of-range float to an integer is illegal. The bounds are powers
+            // of two, so they're exact.
+            const magnitude_bits = if (int.signedness == .signed) int.bits - 1 else int.bits;
+            const upper = std.math.ldexp(@as(F, 1.0), magnitude_bits);
+            const lower = if (int.signedness == .signed) -upper else 0;
+
+            const exact = value >= lower and value < upper and @as(T, @intFromFloat(value)) == x;
+
+            return .{ .value = value, .inexact = !exact, .out_of_bounds = false };
+        },
+        .Float => {
+            const value: F = @floatCast(x);
+
+            if (std.math.isInf(value) and !std.math.isInf(x)) {
+                return .{ .value = value, .inexact = false, .out_of_bounds = true };
+            }
+
+            const exact = @as(T, @floatCast(value)) == x or std.math.isNan(x);
+
+            return .{ .value = value, .inexact = !exact, .out_of_bounds = false };
+        },
+        else => @compileError("numToFloatChecked needs an int or float, not " ++ @typeName(T)),
+    }
+}
+
+pub fn exportNumToFloatChecked(comptime T: type, comptime F: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(x: T) callconv(.C) ToFloatCheckedResult(F) {
+            return numToFloatChecked(T, F, x);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+test "numToFloatChecked: exact integers" {
+    try std.testing.expectEqual(ToFloatCheckedResult(f64){ .value = 42.0, .inexact = false, .out_of_bounds = false }, numToFloatChecked(i64, f64, 42));
+    try std.testing.expectEqual(ToFloatCheckedResult(f64){ .value = -9007199254740992.0, .inexact = false, .out_of_bounds = false }, numToFloatChecked(i64, f64, -9007199254740992));
+    try std.testing.expectEqual(false, numToFloatChecked(i64, f64, std.math.minInt(i64)).inexact);
+    try std.testing.expectEqual(false, numToFloatChecked(u8, f32, 255).inexact);
+}
+
+test "numToFloatChecked: integers that round" {
+    // 2^53 + 1 is the first integer an f64 can't represent.
+    try std.testing.expectEqual(ToFloatCheckedResult(f64){ .value = 9007199254740992.0, .inexact = true, .out_of_bounds = false }, numToFloatChecked(i64, f64, 9007199254740993));
+
+    // These round up to a power of two just past the top of the integer type,
+    // which is still a perfectly good float.
+    try std.testing.expectEqual(ToFloatCheckedResult(f64){ .value = 9223372036854775808.0, .inexact = true, .out_of_bounds = false }, numToFloatChecked(i64, f64, std.math.maxInt(i64)));
+    try std.testing.expectEqual(ToFloatCheckedResult(f64){ .value = 18446744073709551616.0, .inexact = true, .out_of_bounds = false }, numToFloatChecked(u64, f64, std.math.maxInt(u64)));
+}
+
+test "numToFloatChecked: integers that are too big" {
+    const result = numToFloatChecked(u128, f32, std.math.maxInt(u128));
+    try std.testing.expectEqual(true, result.out_of_bounds);
+    try std.testing.expectEqual(false, result.inexact);
+    try std.testing.expect(std.math.isPositiveInf(result.value));
+}
+
+test "numToFloatChecked: floats" {
+    try std.testing.expectEqual(ToFloatCheckedResult(f64){ .value = @as(f32, 0.1), .inexact = false, .out_of_bounds = false }, numToFloatChecked(f32, f64, 0.1));
+    try std.testing.expectEqual(ToFloatCheckedResult(f32){ .value = 0.5, .inexact = false, .out_of_bounds = false }, numToFloatChecked(f64, f32, 0.5));
+    try std.testing.expectEqual(ToFloatCheckedResult(f32){ .value = 0.1, .inexact = true, .out_of_bounds = false }, numToFloatChecked(f64, f32, 0.1));
+    try std.testing.expectEqual(ToFloatCheckedResult(f32){ .value = std.math.inf(f32), .inexact = false, .out_of_bounds = false }, numToFloatChecked(f64, f32, std.math.inf(f64)));
+    try std.testing.expectEqual(false, numToFloatChecked(f64, f32, std.math.nan(f64)).inexact);
+}
+
+test "numToFloatChecked: floats that are too big" {
+    const result = numToFloatChecked(f64, f32, 1e300);
+    try std.testing.expectEqual(true, result.out_of_bounds);
+    try std.testing.expectEqual(false, result.inexact);
+    try std.testing.expect(std.math.isPositiveInf(result.value));
+}
+
+// Widening to 128 bits extends according to the *source* signedness, so
+// negative numbers keep their two's complement bit pattern. Going from a
+// negative number to a u128 therefore wraps modulo 2^128 (-1 becomes maxInt).
+fn numTo128(comptime T: type, comptime W: type, x: T) W {
+    const Wide = std.meta.Int(@typeInfo(T).Int.signedness, 128);
+    const wide: Wide = x;
+    return @bitCast(wide);
+}
+
+pub fn exportNumTo128(comptime T: type, comptime W: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(x: T) callconv(.C) W {
+            return numTo128(T, W, x);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+test "numTo128: sign extension" {
+    try std.testing.expectEqual(@as(i128, -15), numTo128(i8, i128, -15));
+    try std.testing.expectEqual(@as(i128, std.math.minInt(i64)), numTo128(i64, i128, std.math.minInt(i64)));
+    try std.testing.expectEqual(@as(i128, 255), numTo128(u8, i128, 255));
+    try std.testing.expectEqual(@as(u128, std.math.maxInt(u64)), numTo128(u64, u128, std.math.maxInt(u64)));
+}
+
+test "numTo128: negative to unsigned wraps" {
+    try std.testing.expectEqual(@as(u128, std.math.maxInt(u128)), numTo128(i8, u128, -1));
+    try std.testing.expectEqual(@as(u128, 1 << 127), numTo128(i128, u128, std.math.minInt(i128)));
+    try std.testing.expectEqual(@as(i128, -1), numTo128(u128, i128, std.math.maxInt(u128)));
+}
+
+pub fn exportMulAdd(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(a: T, b: T, c: T) callconv(.C) T {
+            // Uses the hardware FMA instruction when there is one, and a
+            // correctly-rounded software implementation otherwise.
+            return @mulAdd(T, a, b, c);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+test "mulAdd: rounds only once" {
+    // 0.1 * 10.0 rounds to exactly 1.0, so multiplying and then adding gives 0.
+    const x: f64 = 0.1;
+    try std.testing.expectEqual(@as(f64, 0.0), x * 10.0 - 1.0);
+    try std.testing.expectEqual(@as(f64, 5.551115123125783e-17), @mulAdd(f64, x, 10.0, -1.0));
+}
+
 pub fn exportPow(comptime T: type, comptime name: []const u8) void {
     const f = struct {
         fn func(base: T, exp: T) callconv(.C) T {
@@ -130,6 +274,34 @@ pub fn exportPow(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
 }
 
+// powi squares its way through the exponent and checks every multiplication,
+// so an intermediate overflow is reported even if later steps would not overflow.
+fn powIntChecked(comptime T: type, base: T, exp: T) WithOverflow(T) {
+    if (std.math.powi(T, base, exp)) |value| {
+        return .{ .value = value, .has_overflowed = false };
+    } else |err| switch (err) {
+        error.Overflow => return .{ .value = 0, .has_overflowed = true },
+        // a negative exponent; pow_int rounds these to 0 as well
+        error.Underflow => return .{ .value = 0, .has_overflowed = false },
+    }
+}
+
+pub fn exportPowIntChecked(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(base: T, exp: T) callconv(.C) WithOverflow(T) {
+            return @call(.always_inline, powIntChecked, .{ T, base, exp });
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+test "powIntChecked" {
+    try std.testing.expectEqual(WithOverflow(i64){ .value = 1024, .has_overflowed = false }, powIntChecked(i64, 2, 10));
+    try std.testing.expect(powIntChecked(i64, 2, 63).has_overflowed);
+    try std.testing.expectEqual(std.math.minInt(i64), powIntChecked(i64, -2, 63).value);
+    try std.testing.expect(powIntChecked(u8, 2, 8).has_overflowed);
+}
+
 pub fn exportIsNan(comptime T: type, comptime name: []const u8) void {
     const f = struct {
         fn func(input: T) callconv(.C) bool {
@@ -184,6 +356,78 @@ pub fn exportAtan(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
 }
 
+pub fn exportSinh(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(input: T) callconv(.C) T {
+            return std.math.sinh(input);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportCosh(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(input: T) callconv(.C) T {
+            return std.math.cosh(input);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportTanh(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(input: T) callconv(.C) T {
+            return std.math.tanh(input);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportAsinh(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(input: T) callconv(.C) T {
+            return std.math.asinh(input);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportAcosh(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(input: T) callconv(.C) T {
+            // acosh is only defined for inputs of at least 1.
+            if (input < 1) {
+                return std.math.nan(T);
+            }
+            return std.math.acosh(input);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportAtanh(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(input: T) callconv(.C) T {
+            // atanh is only defined strictly between -1 and 1; rather than
+            // returning an infinity at the boundaries, treat them as out of domain too.
+            if (!(@abs(input) < 1)) {
+                return std.math.nan(T);
+            }
+            return std.math.atanh(input);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportAtan2(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(y: T, x: T) callconv(.C) T {
+            return std.math.atan2(y, x);
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
 pub fn exportSin(comptime T: type, comptime name: []const u8) void {
     const f = struct {
         fn func(input: T) callconv(.C) T {
@@ -276,6 +520,39 @@ pub fn exportDivCeil(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
 }
 
+pub fn DivCheckedResult(comptime T: type) type {
+    // Same shape as ToIntCheckedResult: the flag goes last so the Roc record
+    // `{ b : Bool, a : Int a }` lines up regardless of the integer width.
+    return extern struct {
+        value: T,
+        divided_by_zero: bool,
+    };
+}
+
+pub fn exportDivTruncChecked(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(a: T, b: T) callconv(.C) DivCheckedResult(T) {
+            if (b == 0) {
+                return .{ .value = 0, .divided_by_zero = true };
+            }
+            return .{ .value = @divTrunc(a, b), .divided_by_zero = false };
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+pub fn exportRemChecked(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(a: T, b: T) callconv(.C) DivCheckedResult(T) {
+            if (b == 0) {
+                return .{ .value = 0, .divided_by_zero = true };
+            }
+            return .{ .value = @rem(a, b), .divided_by_zero = false };
+        }
+    }.func;
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
 pub fn ToIntCheckedResult(comptime T: type) type {
     // On the Roc side we sort by alignment; putting the errorcode last
     // always works out (no number with smaller alignment than 1).
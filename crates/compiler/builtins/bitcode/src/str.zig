@@ -583,6 +583,44 @@ fn strFromIntHelp(comptime T: type, int: T) RocStr {
     return RocStr.init(&buf, result.len);
 }
 
+// Num.toStrWithRadix
+pub fn exportFromIntWithRadix(comptime T: type, comptime name: []const u8) void {
+    const f = struct {
+        fn func(int: T, radix: u8) callconv(.C) RocStr {
+            return @call(.always_inline, strFromIntWithRadixHelp, .{ T, int, radix });
+        }
+    }.func;
+
+    @export(f, .{ .name = name ++ @typeName(T), .linkage = .strong });
+}
+
+// The radix is checked on the Roc side, so it's always in 2...36 here.
+fn strFromIntWithRadixHelp(comptime T: type, int: T, radix: u8) RocStr {
+    // base 2 needs one digit per bit, plus a sign
+    var buf: [@bitSizeOf(T) + 1]u8 = undefined;
+    const len = std.fmt.formatIntBuf(&buf, int, radix, .lower, .{});
+
+    return RocStr.init(&buf, len);
+}
+
+test "strFromIntWithRadixHelp: hex, binary, and the minimum value" {
+    const hex = strFromIntWithRadixHelp(i64, 255, 16);
+    defer hex.decref();
+    try expect(mem.eql(u8, "ff", hex.asSlice()));
+
+    const binary = strFromIntWithRadixHelp(u8, 10, 2);
+    defer binary.decref();
+    try expect(mem.eql(u8, "1010", binary.asSlice()));
+
+    const min = strFromIntWithRadixHelp(i8, -128, 2);
+    defer min.decref();
+    try expect(mem.eql(u8, "-10000000", min.asSlice()));
+
+    const base36 = strFromIntWithRadixHelp(u16, 35, 36);
+    defer base36.decref();
+    try expect(mem.eql(u8, "z", base36.asSlice()));
+}
+
 // Str.fromFloat
 pub fn exportFromFloat(comptime T: type, comptime name: []const u8) void {
     const f = struct {
@@ -960,6 +998,137 @@ test "strSplitHelp: overlapping delimiter 2" {
     try expect(array[2].eq(expected[2]));
 }
 
+// Str.split_on_any
+pub fn strSplitOnAny(string: RocStr, delimiters: RocList) callconv(.C) RocList {
+    const delimiter_strs = delimiterSlice(delimiters);
+    const segment_count = countSegmentsOnAny(string.asSlice(), delimiter_strs);
+    const list = RocList.allocate(@alignOf(RocStr), segment_count, @sizeOf(RocStr), true);
+
+    if (list.bytes) |bytes| {
+        const strings = @as([*]RocStr, @ptrCast(@alignCast(bytes)));
+        strSplitOnAnyHelp(strings, string, delimiter_strs);
+    }
+
+    return list;
+}
+
+fn delimiterSlice(delimiters: RocList) []const RocStr {
+    if (delimiters.elements(RocStr)) |elements| {
+        return elements[0..delimiters.len()];
+    }
+
+    return &[_]RocStr{};
+}
+
+// The length of the longest non-empty delimiter starting at `index`, if any.
+// Empty delimiters never match, like in Str.split_on.
+fn longestDelimiterAt(haystack: []const u8, index: usize, delimiters: []const RocStr) ?usize {
+    var longest: ?usize = null;
+
+    for (delimiters) |delimiter| {
+        const needle = delimiter.asSlice();
+
+        if (needle.len > (longest orelse 0) and mem.startsWith(u8, haystack[index..], needle)) {
+            longest = needle.len;
+        }
+    }
+
+    return longest;
+}
+
+fn countSegmentsOnAny(haystack: []const u8, delimiters: []const RocStr) usize {
+    var count: usize = 1;
+    var index: usize = 0;
+
+    while (index < haystack.len) {
+        if (longestDelimiterAt(haystack, index, delimiters)) |delimiter_len| {
+            count += 1;
+            index += delimiter_len;
+        } else {
+            index += 1;
+        }
+    }
+
+    return count;
+}
+
+fn strSplitOnAnyHelp(array: [*]RocStr, string: RocStr, delimiters: []const RocStr) void {
+    const haystack = string.asSlice();
+
+    var i: usize = 0;
+    var segment_start: usize = 0;
+    var index: usize = 0;
+
+    while (index < haystack.len) {
+        if (longestDelimiterAt(haystack, index, delimiters)) |delimiter_len| {
+            array[i] = substringUnsafe(string, segment_start, index - segment_start);
+            i += 1;
+
+            index += delimiter_len;
+            segment_start = index;
+        } else {
+            index += 1;
+        }
+    }
+
+    array[i] = substringUnsafe(string, segment_start, haystack.len - segment_start);
+    i += 1;
+
+    // Correct refcount for all of the splits made.
+    string.incref(i); // i == array.len()
+}
+
+test "strSplitOnAny: adjacent delimiters make empty segments" {
+    // Str.split_on_any(",a;,b", [",", ";"]) == ["", "a", "", "b"]
+    const str = RocStr.init(",a;,b", 5);
+    const delimiters = [_]RocStr{ RocStr.init(",", 1), RocStr.init(";", 1) };
+
+    try expectEqual(@as(usize, 4), countSegmentsOnAny(str.asSlice(), &delimiters));
+
+    var array: [4]RocStr = undefined;
+    const array_ptr: [*]RocStr = &array;
+
+    strSplitOnAnyHelp(array_ptr, str, &delimiters);
+
+    defer {
+        for (array) |roc_str| {
+            roc_str.decref();
+        }
+
+        str.decref();
+    }
+
+    try expect(array[0].eq(RocStr.empty()));
+    try expect(array[1].eq(RocStr.init("a", 1)));
+    try expect(array[2].eq(RocStr.empty()));
+    try expect(array[3].eq(RocStr.init("b", 1)));
+}
+
+test "strSplitOnAny: longest delimiter wins" {
+    // Str.split_on_any("a->b-c", ["-", "->"]) == ["a", "b", "c"]
+    const str = RocStr.init("a->b-c", 6);
+    const delimiters = [_]RocStr{ RocStr.init("-", 1), RocStr.init("->", 2) };
+
+    try expectEqual(@as(usize, 3), countSegmentsOnAny(str.asSlice(), &delimiters));
+
+    var array: [3]RocStr = undefined;
+    const array_ptr: [*]RocStr = &array;
+
+    strSplitOnAnyHelp(array_ptr, str, &delimiters);
+
+    defer {
+        for (array) |roc_str| {
+            roc_str.decref();
+        }
+
+        str.decref();
+    }
+
+    try expect(array[0].eq(RocStr.init("a", 1)));
+    try expect(array[1].eq(RocStr.init("b", 1)));
+    try expect(array[2].eq(RocStr.init("c", 1)));
+}
+
 // This is used for `Str.splitOn : Str, Str -> List Str
 // It is used to count how many segments the input `_str`
 // needs to be broken into, so that we can allocate a array
@@ -1062,6 +1231,70 @@ test "countSegments: overlapping delimiter 2" {
     try expectEqual(segments_count, 3);
 }
 
+// What `Str.split_first` and `Str.split_last` get back when the delimiter isn't present.
+const SPLIT_NOT_FOUND: u64 = std.math.maxInt(u64);
+
+// Returns the byte index of the first occurrence of `delimiter` in `string`.
+pub fn strSplitFirst(string: RocStr, delimiter: RocStr) callconv(.C) u64 {
+    if (mem.indexOf(u8, string.asSlice(), delimiter.asSlice())) |index| {
+        return @intCast(index);
+    }
+
+    return SPLIT_NOT_FOUND;
+}
+
+// Returns the byte index of the last occurrence of `delimiter` in `string`.
+pub fn strSplitLast(string: RocStr, delimiter: RocStr) callconv(.C) u64 {
+    if (mem.lastIndexOf(u8, string.asSlice(), delimiter.asSlice())) |index| {
+        return @intCast(index);
+    }
+
+    return SPLIT_NOT_FOUND;
+}
+
+test "strSplitFirst: delimiter interspersed" {
+    const str = RocStr.init("foo/bar/baz", 11);
+    const delimiter = RocStr.init("/", 1);
+
+    try expectEqual(@as(u64, 3), strSplitFirst(str, delimiter));
+}
+
+test "strSplitLast: delimiter interspersed" {
+    const str = RocStr.init("foo/bar/baz", 11);
+    const delimiter = RocStr.init("/", 1);
+
+    try expectEqual(@as(u64, 7), strSplitLast(str, delimiter));
+}
+
+test "strSplitFirst: overlapping delimiter" {
+    const str = RocStr.init("aaa", 3);
+    const delimiter = RocStr.init("aa", 2);
+
+    try expectEqual(@as(u64, 0), strSplitFirst(str, delimiter));
+    try expectEqual(@as(u64, 1), strSplitLast(str, delimiter));
+}
+
+test "strSplitFirst: delimiter not present" {
+    const str = RocStr.init("foo", 3);
+    const delimiter = RocStr.init("z", 1);
+
+    try expectEqual(SPLIT_NOT_FOUND, strSplitFirst(str, delimiter));
+    try expectEqual(SPLIT_NOT_FOUND, strSplitLast(str, delimiter));
+    try expectEqual(SPLIT_NOT_FOUND, strSplitFirst(RocStr.empty(), delimiter));
+}
+
+test "strSplitFirst: big str" {
+    const str_arr = "this is a big string that will not fit in a small string/rest";
+    const str = RocStr.init(str_arr, str_arr.len);
+    defer str.decref();
+
+    const delimiter = RocStr.init("/", 1);
+
+    try expect(!str.isSmallStr());
+    try expectEqual(@as(u64, str_arr.len - 5), strSplitFirst(str, delimiter));
+    try expectEqual(@as(u64, str_arr.len - 5), strSplitLast(str, delimiter));
+}
+
 pub fn countUtf8Bytes(string: RocStr) callconv(.C) u64 {
     return @intCast(string.len());
 }
@@ -1347,6 +1580,14 @@ fn strJoinWith(list: RocListStr, separator: RocStr) RocStr {
         const ptr = @as([*]RocStr, @ptrCast(list.list_elements));
         const slice: []RocStr = ptr[0..len];
 
+        if (len == 1) {
+            // There's no separator to insert, so share the element rather than copy it.
+            // The list is borrowed, so the result needs its own reference.
+            const substr = slice[0];
+            substr.incref(1);
+            return substr;
+        }
+
         // determine the size of the result
         var total_size: usize = 0;
         for (slice) |substr| {
@@ -1360,6 +1601,16 @@ fn strJoinWith(list: RocListStr, separator: RocStr) RocStr {
         const result_ptr = result.asU8ptrMut();
 
         var offset: usize = 0;
+        if (separator.len() == 0) {
+            // Nothing to interleave, so this is plain concatenation.
+            for (slice) |substr| {
+                substr.memcpy(result_ptr + offset);
+                offset += substr.len();
+            }
+
+            return result;
+        }
+
         for (slice[0 .. len - 1]) |substr| {
             substr.memcpy(result_ptr + offset);
             offset += substr.len();
@@ -1411,6 +1662,70 @@ test "RocStr.joinWith: result is big" {
     try expect(roc_result.eq(result));
 }
 
+test "RocStr.joinWith: single element is shared, not copied" {
+    const sep_len = 2;
+    var sep: [sep_len]u8 = ", ".*;
+    const sep_ptr: [*]u8 = &sep;
+    var roc_sep = RocStr.init(sep_ptr, sep_len);
+
+    const elem_len = 30;
+    var elem: [elem_len]u8 = "a string too long to be small!".*;
+    const elem_ptr: [*]u8 = &elem;
+    var roc_elem = RocStr.init(elem_ptr, elem_len);
+
+    var elements: [1]RocStr = .{roc_elem};
+    const list = RocListStr{
+        .list_length = 1,
+        .list_capacity_or_alloc_ptr = 1,
+        .list_elements = @as([*]RocStr, @ptrCast(&elements)),
+    };
+
+    defer {
+        roc_sep.decref();
+        roc_elem.decref();
+    }
+
+    const result = strJoinWith(list, roc_sep);
+
+    defer result.decref();
+
+    try expectEqual(roc_elem.bytes, result.bytes);
+    try expect(roc_elem.eq(result));
+}
+
+test "RocStr.joinWith: empty separator concatenates" {
+    var sep = RocStr.empty();
+
+    const elem_len = 13;
+    var elem: [elem_len]u8 = "foobarbazspam".*;
+    const elem_ptr: [*]u8 = &elem;
+    var roc_elem = RocStr.init(elem_ptr, elem_len);
+
+    const result_len = 39;
+    var xresult: [result_len]u8 = "foobarbazspamfoobarbazspamfoobarbazspam".*;
+    const result_ptr: [*]u8 = &xresult;
+    var roc_result = RocStr.init(result_ptr, result_len);
+
+    var elements: [3]RocStr = .{ roc_elem, roc_elem, roc_elem };
+    const list = RocListStr{
+        .list_length = 3,
+        .list_capacity_or_alloc_ptr = 3,
+        .list_elements = @as([*]RocStr, @ptrCast(&elements)),
+    };
+
+    defer {
+        sep.decref();
+        roc_elem.decref();
+        roc_result.decref();
+    }
+
+    const result = strJoinWith(list, sep);
+
+    defer result.decref();
+
+    try expect(roc_result.eq(result));
+}
+
 // Str.toUtf8
 pub fn strToUtf8C(arg: RocStr) callconv(.C) RocList {
     return strToBytes(arg);
@@ -2323,6 +2638,76 @@ test "caselessAsciiEquals: seamless slice" {
     try expect(are_equal);
 }
 
+// Tag ids of `[LT, EQ, GT]`, which are assigned in alphabetical order.
+const ORDER_EQ: u8 = 0;
+const ORDER_GT: u8 = 1;
+const ORDER_LT: u8 = 2;
+
+// Comparing UTF-8 bytes gives the same ordering as comparing code points.
+pub fn strCompare(self: RocStr, other: RocStr) callconv(.C) u8 {
+    if (self.bytes == other.bytes and self.length == other.length) {
+        return ORDER_EQ;
+    }
+
+    return switch (mem.order(u8, self.asSlice(), other.asSlice())) {
+        .eq => ORDER_EQ,
+        .gt => ORDER_GT,
+        .lt => ORDER_LT,
+    };
+}
+
+test "strCompare: equal" {
+    const str1 = RocStr.fromSlice("coffé coffé coffé coffé coffé coffé");
+    defer str1.decref();
+
+    const str2 = RocStr.fromSlice("coffé coffé coffé coffé coffé coffé");
+    defer str2.decref();
+
+    try expectEqual(ORDER_EQ, strCompare(str1, str2));
+}
+
+test "strCompare: prefix is less" {
+    const str1 = RocStr.fromSlice("ab");
+    defer str1.decref();
+
+    const str2 = RocStr.fromSlice("abc");
+    defer str2.decref();
+
+    try expectEqual(ORDER_LT, strCompare(str1, str2));
+    try expectEqual(ORDER_GT, strCompare(str2, str1));
+}
+
+test "strCompare: empty is less" {
+    const str1 = RocStr.empty();
+    const str2 = RocStr.fromSlice("a");
+    defer str2.decref();
+
+    try expectEqual(ORDER_LT, strCompare(str1, str2));
+}
+
+test "strCompare: code point order" {
+    const str1 = RocStr.fromSlice("z");
+    defer str1.decref();
+
+    const str2 = RocStr.fromSlice("é");
+    defer str2.decref();
+
+    try expectEqual(ORDER_LT, strCompare(str1, str2));
+}
+
+test "strCompare: seamless slice" {
+    const l = RocStr.fromSlice("coffé coffé coffé coffé coffé coffé");
+    const str1 = substringUnsafeC(l, 1, l.len() - 1);
+    defer str1.decref();
+
+    try expect(str1.isSeamlessSlice());
+
+    const str2 = RocStr.fromSlice("offé coffé coffé coffé coffé coffé");
+    defer str2.decref();
+
+    try expectEqual(ORDER_EQ, strCompare(str1, str2));
+}
+
 fn rcNone(_: ?[*]u8) callconv(.C) void {}
 
 fn decStr(ptr: ?[*]u8) callconv(.C) void {
@@ -14,18 +14,22 @@ module [
     insert,
     remove,
     update,
+    get_or_insert,
     walk,
     walk_until,
     keep_if,
     drop_if,
     to_list,
     from_list,
+    group_by,
     keys,
     values,
     insert_all,
     keep_shared,
     remove_all,
+    union_with,
     map,
+    map_values,
     join_map,
 ]
 
@@ -163,6 +167,16 @@ with_capacity = |requested|
     |> reserve(requested)
 
 ## Enlarge the dictionary for at least capacity additional elements
+##
+## This never shrinks the dictionary, so [Dict.capacity] of the result is at
+## least the capacity of the original. If the dictionary is shared, the
+## original is left as it was.
+## ```roc
+## Dict.empty({})
+## |> Dict.reserve(100)
+## |> Dict.capacity
+## |> Num.is_gte(100)
+## ```
 reserve : Dict k v, U64 -> Dict k v
 reserve = |@Dict({ buckets, data, max_bucket_capacity: original_max_bucket_capacity, max_load_factor, shifts }), requested|
     current_size = List.len(data)
@@ -250,6 +264,43 @@ from_list : List (k, v) -> Dict k v
 from_list = |data|
     List.walk(data, empty({}), |dict, (k, v)| insert(dict, k, v))
 
+## Groups the elements of a list by the key the given function computes for
+## each of them. Within each group, elements stay in their original order.
+## ```roc
+## expect
+##     Dict.group_by([1, 2, 3, 4, 5], |n| Num.is_even(n))
+##     |> Bool.is_eq(Dict.from_list([(Bool.false, [1, 3, 5]), (Bool.true, [2, 4])]))
+## ```
+##
+## ## Performance Details
+##
+## Every element costs a single key lookup, and it's appended to its group in
+## place, so building each group doesn't copy it over and over.
+group_by : List a, (a -> k) -> Dict k (List a)
+group_by = |list, to_key|
+    List.walk(
+        list,
+        empty({}),
+        |dict, elem|
+            key = to_key(elem)
+            { bucket_index, result } = find(dict, key)
+            when result is
+                Ok(_) -> append_to_group(dict, bucket_index, key, elem)
+                Err(KeyNotFound) -> insert_not_found(dict, bucket_index, key, [elem]),
+    )
+
+append_to_group : Dict k (List a), U64, k, a -> Dict k (List a)
+append_to_group = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), bucket_index, key, elem|
+    data_index = Num.to_u64(list_get_unsafe(buckets, bucket_index).data_index)
+
+    # Take the group out of `data` before appending to it. Otherwise `data` would
+    # still refer to it, and List.append would have to copy it.
+    replaced = List.replace(data, data_index, (key, []))
+    (_, group) = replaced.value
+    new_data = List.set(replaced.list, data_index, (key, List.append(group, elem)))
+
+    @Dict({ buckets, data: new_data, max_bucket_capacity, max_load_factor, shifts })
+
 ## Returns the number of values in the dictionary.
 ## ```roc
 ## expect
@@ -303,16 +354,31 @@ clear = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts })|
 ## function on each of them which receives both the key and the old value. Then return a
 ## new dictionary containing the same keys and the converted values.
 map : Dict k a, (k, a -> b) -> Dict k b
-map = |dict, transform|
-    init = with_capacity(capacity(dict))
-
-    walk(
-        dict,
-        init,
-        |answer, k, v|
-            insert(answer, k, transform(k, v)),
+map = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), transform|
+    # The keys don't change, so the existing buckets still point at the right entries
+    # and nothing needs to be rehashed.
+    @Dict(
+        {
+            buckets,
+            data: List.map(data, |(k, v)| (k, transform(k, v))),
+            max_bucket_capacity,
+            max_load_factor,
+            shifts,
+        },
     )
 
+## Like [Dict.map], except the conversion function only receives the value.
+## ```roc
+## expect
+##     Dict.single("a", 1)
+##     |> Dict.map_values(|n| n * 2)
+##     |> Dict.get("a")
+##     |> Bool.is_eq(Ok(2))
+## ```
+map_values : Dict k a, (a -> b) -> Dict k b
+map_values = |dict, transform|
+    map(dict, |_, v| transform(v))
+
 ## Like [Dict.map], except the transformation function wraps the return value
 ## in a dictionary. At the end, all the dictionaries get joined together
 ## (using [Dict.insert_all]) into one dictionary.
@@ -559,29 +625,58 @@ update = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts })
         Err(KeyNotFound) ->
             when alter(Err(Missing)) is
                 Ok(new_value) ->
-                    if List.len(data) >= max_bucket_capacity then
-                        # Need to reallocate let regular insert handle that.
-                        insert(@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), key, new_value)
-                    else
-                        # Can skip work by jumping staight to the found bucket.
-                        # That will be the location we want to insert in.
-                        hash = hash_key(key)
-                        base_dist_and_fingerprint = dist_and_fingerprint_from_hash(hash)
-                        base_bucket_index = bucket_index_from_hash(hash, shifts)
-
-                        # Due to the unrolling of loops in find along with loop optimizations,
-                        # The bucket_index is not guaranteed to be correct here.
-                        # It is only correct if we have traversed past the number of find unrolls.
-                        dist = circular_dist(base_bucket_index, bucket_index, List.len(buckets))
-                        if dist <= find_manual_unrolls then
-                            insert_helper(buckets, data, base_bucket_index, base_dist_and_fingerprint, key, new_value, max_bucket_capacity, max_load_factor, shifts)
-                        else
-                            dist_and_fingerprint = increment_dist_n(base_dist_and_fingerprint, Num.to_u32(dist))
-                            insert_helper(buckets, data, bucket_index, dist_and_fingerprint, key, new_value, max_bucket_capacity, max_load_factor, shifts)
+                    insert_not_found(@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), bucket_index, key, new_value)
 
                 Err(Missing) ->
                     @Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts })
 
+## Get the value for a specified key, inserting the given default value first if
+## the key is not in the dictionary yet. Returns the dictionary along with the
+## value which is now stored for the key. Like [Dict.update], this only has to
+## look up the key once, which is more efficient than doing both a `Dict.get`
+## and then a `Dict.insert` call.
+## ```roc
+## (dict, value) = Dict.get_or_insert(Dict.single("a", 1), "a", 2)
+## expect value == 1
+## expect dict == Dict.single("a", 1)
+##
+## (dict2, value2) = Dict.get_or_insert(Dict.empty({}), "a", 2)
+## expect value2 == 2
+## expect dict2 == Dict.single("a", 2)
+## ```
+get_or_insert : Dict k v, k, v -> (Dict k v, v)
+get_or_insert = |dict, key, default|
+    { bucket_index, result } = find(dict, key)
+    when result is
+        Ok(value) ->
+            (dict, value)
+
+        Err(KeyNotFound) ->
+            (insert_not_found(dict, bucket_index, key, default), default)
+
+# Insert a key which `find` reported as missing, starting from the bucket index it returned.
+insert_not_found : Dict k v, U64, k, v -> Dict k v
+insert_not_found = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), bucket_index, key, value|
+    if List.len(data) >= max_bucket_capacity then
+        # Need to reallocate let regular insert handle that.
+        insert(@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), key, value)
+    else
+        # Can skip work by jumping staight to the found bucket.
+        # That will be the location we want to insert in.
+        hash = hash_key(key)
+        base_dist_and_fingerprint = dist_and_fingerprint_from_hash(hash)
+        base_bucket_index = bucket_index_from_hash(hash, shifts)
+
+        # Due to the unrolling of loops in find along with loop optimizations,
+        # The bucket_index is not guaranteed to be correct here.
+        # It is only correct if we have traversed past the number of find unrolls.
+        dist = circular_dist(base_bucket_index, bucket_index, List.len(buckets))
+        if dist <= find_manual_unrolls then
+            insert_helper(buckets, data, base_bucket_index, base_dist_and_fingerprint, key, value, max_bucket_capacity, max_load_factor, shifts)
+        else
+            dist_and_fingerprint = increment_dist_n(base_dist_and_fingerprint, Num.to_u32(dist))
+            insert_helper(buckets, data, bucket_index, dist_and_fingerprint, key, value, max_bucket_capacity, max_load_factor, shifts)
+
 circular_dist = |start, end, size|
     correction =
         if start > end then
@@ -733,6 +828,47 @@ remove_all : Dict k v, Dict k v -> Dict k v
 remove_all = |xs, ys|
     walk(ys, xs, |state, k, _| remove(state, k))
 
+## Combine two dictionaries like [Dict.insert_all], except that when both
+## contain the same key, the given function decides the resulting value. It is
+## passed the value from the first dictionary, then the value from the second.
+## ```roc
+## first =
+##     Dict.single("apples", 2)
+##     |> Dict.insert("pears", 1)
+##
+## second =
+##     Dict.single("apples", 3)
+##     |> Dict.insert("plums", 5)
+##
+## expected =
+##     Dict.single("apples", 5)
+##     |> Dict.insert("pears", 1)
+##     |> Dict.insert("plums", 5)
+##
+## expect Dict.union_with(first, second, Num.add) == expected
+## ```
+##
+## ## Performance Details
+##
+## The first dictionary is grown once up front, and every key from the second
+## costs a single lookup.
+union_with : Dict k v, Dict k v, (v, v -> v) -> Dict k v
+union_with = |xs, ys, combine|
+    walk(
+        ys,
+        reserve(xs, len(ys)),
+        |dict, key, y|
+            { bucket_index, result } = find(dict, key)
+            when result is
+                Ok(x) -> replace_found(dict, bucket_index, key, combine(x, y))
+                Err(KeyNotFound) -> insert_not_found(dict, bucket_index, key, y),
+    )
+
+replace_found : Dict k v, U64, k, v -> Dict k v
+replace_found = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), bucket_index, key, value|
+    data_index = Num.to_u64(list_get_unsafe(buckets, bucket_index).data_index)
+    @Dict({ buckets, data: List.set(data, data_index, (key, value)), max_bucket_capacity, max_load_factor, shifts })
+
 # Below here is a list of generic helpers and internal data types for Dict
 Bucket : {
     dist_and_fingerprint : U32, # upper 3 byte: distance to original bucket. lower byte: fingerprint from hash
@@ -1097,6 +1233,16 @@ expect
 
     keys(dict) == [2]
 
+expect
+    (dict, value) = get_or_insert(single("foo", "bar"), "foo", "baz")
+
+    value == "bar" and dict == single("foo", "bar")
+
+expect
+    (dict, value) = get_or_insert(single("foo", "bar"), "qux", "baz")
+
+    value == "baz" and get(dict, "qux") == Ok("baz") and len(dict) == 2
+
 expect
     list =
         from_list([(1u8, 1u8), (2u8, 2u8), (3, 3)])
@@ -1774,3 +1920,32 @@ expect
         |> Dict.insert(3, 3)
 
     d1 == d2
+
+expect
+    doubled =
+        from_list([("a", 1), ("b", 2), ("c", 3)])
+        |> map_values(|n| n * 2)
+
+    (get(doubled, "b"), len(doubled)) == (Ok(4), 3)
+
+expect
+    labelled =
+        from_list([(1, "one"), (2, "two")])
+        |> map(|k, v| Str.concat(Num.to_str(k), v))
+        |> insert(3, "3three")
+
+    to_list(labelled) == [(1, "1one"), (2, "2two"), (3, "3three")]
+
+expect
+    groups = group_by(["apple", "avocado", "banana", "blueberry", "cherry"], |fruit| Str.count_utf8_bytes(fruit) > 6)
+
+    to_list(groups) == [(Bool.false, ["apple", "banana", "cherry"]), (Bool.true, ["avocado", "blueberry"])]
+
+expect
+    group_by([], |n| n % 2) == empty({})
+
+expect
+    # Enough distinct keys to make the dictionary grow while groups are being built.
+    groups = group_by(List.range({ start: At(0), end: Before(1000) }), |n| n % 100)
+
+    (len(groups), get(groups, 7)) == (100, Ok([7, 107, 207, 307, 407, 507, 607, 707, 807, 907]))
@@ -13,6 +13,7 @@ module [
     map_try!,
     len,
     with_capacity,
+    capacity,
     walk_backwards,
     concat,
     first,
@@ -23,6 +24,7 @@ module [
     keep_if,
     keep_if_try!,
     contains,
+    index_of,
     sum,
     walk,
     walk!,
@@ -35,6 +37,8 @@ module [
     for_each_try!,
     map_with_index,
     map2,
+    zip,
+    unzip,
     map3,
     map4,
     join_map,
@@ -386,6 +390,16 @@ len : List * -> U64
 ## Create a list with space for at least capacity elements
 with_capacity : U64 -> List *
 
+## Returns how many elements the list can hold before it has to grow its
+## allocation. This is never less than [List.len].
+##
+## Seamless slices share their parent's allocation, so for them this is the
+## same as [List.len].
+## ```roc
+## List.capacity(List.with_capacity(10)) >= 10
+## ```
+capacity : List * -> U64
+
 ## Enlarge the list for at least capacity additional elements
 reserve : List a, U64 -> List a
 
@@ -400,6 +414,9 @@ release_excess_capacity : List a -> List a
 ## [0, 1, 2]
 ##     |> List.concat([3, 4])
 ## ```
+## If the first list is unique and has enough spare capacity, the second list's
+## elements are copied into it without reallocating. Combined with [List.reserve],
+## this makes `concat` the way to extend a list in bulk.
 concat : List a, List a -> List a
 
 ## Returns the last element in the list, or `ListWasEmpty` if it was empty.
@@ -467,10 +484,28 @@ join = |lists|
 
     List.walk(lists, List.with_capacity(total_length), |state, list| List.concat(state, list))
 
+## Returns `Bool.true` if the list contains an element equal to the given one.
+## ```roc
+## expect List.contains([1, 2, 3], 2)
+## expect !List.contains([1, 2, 3], 4)
+## ```
+## This stops looking as soon as it finds a match. To also find out where
+## that match is, use [List.index_of].
 contains : List a, a -> Bool where a implements Eq
 contains = |list, needle|
     List.any(list, |x| x == needle)
 
+## Returns the index of the first element equal to the given one, or
+## `Err(NotFound)` if there is none.
+## ```roc
+## expect List.index_of(["a", "b", "a"], "a") == Ok(0)
+## expect List.index_of(["a", "b", "a"], "c") == Err(NotFound)
+## ```
+## Like [List.contains], this stops looking as soon as it finds a match.
+index_of : List a, a -> Result U64 [NotFound] where a implements Eq
+index_of = |list, needle|
+    List.find_first_index(list, |x| x == needle)
+
 ## Build a value using each element in the list.
 ##
 ## Starting with a given `state` value, this walks through each element in the
@@ -518,6 +553,10 @@ walk_help = |list, state, f, index, length|
         state
 
 ## Like [walk], but at each step the function also receives the index of the current element.
+## Indices start at 0.
+## ```roc
+## List.walk_with_index([5, 7, 2, 3], 0, |sum, elem, index| sum + elem * index) == 20
+## ```
 walk_with_index : List elem, state, (state, elem, U64 -> state) -> state
 walk_with_index = |list, init, func|
     walk_with_index_help(list, init, func, 0, List.len(list))
@@ -608,10 +647,19 @@ walk_from_until = |list, index, state, func|
         Continue(new) -> new
         Break(new) -> new
 
+## Adds up all the numbers in the list. An empty list sums to 0.
+##
+## For [F32] and [F64], a *NaN* anywhere in the list makes the sum *NaN*, and
+## the sum can overflow to ∞ or -∞. Integers and [Dec] panic on overflow, like [Num.add].
 sum : List (Num a) -> Num a
 sum = |list|
     List.walk(list, 0, Num.add)
 
+## Multiplies together all the numbers in the list. An empty list has a product
+## of 1, since that's the number that leaves any other unchanged when multiplied.
+##
+## Overflow follows [Num.mul]: [F32] and [F64] go to ∞ or -∞, while integers and
+## [Dec] panic. To wrap instead, use `List.walk(list, 1, Num.mul_wrap)`.
 product : List (Num a) -> Num a
 product = |list|
     List.walk(list, 1, Num.mul)
@@ -714,7 +762,20 @@ keep_if_try_help! = |list, predicate!, kept, index, length|
 ## See its documentation for details on those characteristics!
 drop_if : List a, (a -> Bool) -> List a
 drop_if = |list, predicate|
-    List.keep_if(list, |e| Bool.not(predicate(e)))
+    length = List.len(list)
+
+    drop_if_help(list, predicate, 0, 0, length)
+
+# The same in-place compaction as keep_if_help, with the branches swapped.
+drop_if_help : List a, (a -> Bool), U64, U64, U64 -> List a
+drop_if_help = |list, predicate, kept, index, length|
+    if index < length then
+        if predicate(List.get_unsafe(list, index)) then
+            drop_if_help(list, predicate, kept, Num.add_wrap(index, 1), length)
+        else
+            drop_if_help(List.swap(list, kept, index), predicate, Num.add_wrap(kept, 1), Num.add_wrap(index, 1), length)
+    else
+        List.take_first(list, kept)
 
 ## Run the given function on each element of a list, and return the
 ## number of elements for which the function returned `Bool.true`.
@@ -792,10 +853,9 @@ map = |list, mapper|
 ## and use that as the first element in the returned list.
 ## Repeat until a list runs out of elements.
 ##
-## Some languages have a function named `zip`, which does something similar to
-## calling [List.map2] passing two lists and `Pair`:
+## [List.zip] is the same as calling this with a function that builds a tuple:
 ## ```roc
-## zipped = List.map2(["a", "b", "c"], [1, 2, 3], Pair)
+## zipped = List.map2(["a", "b", "c"], [1, 2, 3], |a, b| (a, b))
 ## ```
 map2 : List a, List b, (a, b -> c) -> List c
 map2 = |list_a, list_b, mapper|
@@ -811,6 +871,31 @@ map2_help = |list_a, list_b, out, mapper, index, length|
     else
         out
 
+## Pairs up the elements of two lists. If one list is longer than the other,
+## its extra elements are dropped.
+## ```roc
+## List.zip(["a", "b", "c"], [1, 2]) == [("a", 1), ("b", 2)]
+## ```
+zip : List a, List b -> List (a, b)
+zip = |list_a, list_b|
+    map2(list_a, list_b, |a, b| (a, b))
+
+## Splits a list of pairs into a list of their first elements and a list of
+## their second elements. This undoes [List.zip].
+## ```roc
+## List.unzip([("a", 1), ("b", 2)]) == (["a", "b"], [1, 2])
+## ```
+unzip : List (a, b) -> (List a, List b)
+unzip = |pairs|
+    length = List.len(pairs)
+
+    List.walk(
+        pairs,
+        (List.with_capacity(length), List.with_capacity(length)),
+        |(firsts, seconds), (a, b)|
+            (List.append_unsafe(firsts, a), List.append_unsafe(seconds, b)),
+    )
+
 ## Run a transformation function on the first element of each list,
 ## and use that as the first element in the returned list.
 ## Repeat until a list runs out of elements.
@@ -944,18 +1029,30 @@ range = |{ start, end, step ?? 0 }|
             range_help([], inclusive_start, calc_next, is_valid)
 
         Before(before) ->
-            is_valid =
-                if step_is_positive then
-                    |i| i < before
-                else
-                    |i| i > before
+            when inclusive_start is
+                Ok(first) if step == 0 ->
+                    # the direction was picked from the original start, which `After` may have stepped past
+                    if step_is_positive == (first < before) then
+                        range_lowlevel(first, before)
+                    else
+                        []
 
-            # TODO: switch to List.with_capacity
-            range_help([], inclusive_start, calc_next, is_valid)
+                Ok(first) ->
+                    range_with_step_lowlevel(first, before, step)
+
+                Err(_) ->
+                    []
 
         Length(l) ->
             range_length_help(List.with_capacity(l), inclusive_start, l, calc_next)
 
+## Every integer from `start` towards `end`, excluding `end`; counts down if `end` is below `start`.
+range_lowlevel : Int a, Int a -> List (Int a)
+
+## Every `step`-th integer from `start` towards `end`, excluding `end`.
+## Empty if `step` is zero or points away from `end`.
+range_with_step_lowlevel : Int a, Int a, Int a -> List (Int a)
+
 range_help = |accum, i, calc_next, is_valid|
     when i is
         Ok(val) ->
@@ -1022,6 +1119,18 @@ expect
 expect
     List.range({ start: At(4), end: At(0) }) == [4, 3, 2, 1, 0]
 
+expect
+    List.range({ start: At(4u8), end: Before(0) }) == [4, 3, 2, 1]
+
+expect
+    List.range({ start: After(4), end: Before(4) }) == []
+
+expect
+    List.range({ start: At(10), end: Before(0), step: -4 }) == [10, 6, 2]
+
+expect
+    List.range({ start: At(0), end: Before(10), step: -4 }) == []
+
 ## Sort with a custom comparison function
 sort_with : List a, (a, a -> [LT, EQ, GT]) -> List a
 
@@ -1107,6 +1216,10 @@ drop_last = |list, n|
 ## To replace the element at a given index, instead of dropping it, see [List.set].
 drop_at : List elem, U64 -> List elem
 
+## Returns the smallest number in the list, or `Err ListWasEmpty` if there are none.
+##
+## For [F32] and [F64], a *NaN* anywhere in the list makes the result *NaN*, no
+## matter where in the list it appears.
 min : List (Num a) -> Result (Num a) [ListWasEmpty]
 min = |list|
     when List.first(list) is
@@ -1118,16 +1231,22 @@ min = |list|
 
 min_help : List (Num a), Num a -> Num a
 min_help = |list, initial|
-    List.walk(
+    List.walk_until(
         list,
         initial,
         |best_so_far, current|
             if current < best_so_far then
-                current
+                Continue(current)
+            else if current >= best_so_far then
+                Continue(best_so_far)
             else
-                best_so_far,
+                Break(nan_of(best_so_far, current)),
     )
 
+## Returns the largest number in the list, or `Err ListWasEmpty` if there are none.
+##
+## For [F32] and [F64], a *NaN* anywhere in the list makes the result *NaN*, no
+## matter where in the list it appears.
 max : List (Num a) -> Result (Num a) [ListWasEmpty]
 max = |list|
     when List.first(list) is
@@ -1139,20 +1258,39 @@ max = |list|
 
 max_help : List (Num a), Num a -> Num a
 max_help = |list, initial|
-    List.walk(
+    List.walk_until(
         list,
         initial,
         |best_so_far, current|
             if current > best_so_far then
-                current
+                Continue(current)
+            else if current <= best_so_far then
+                Continue(best_so_far)
             else
-                best_so_far,
+                Break(nan_of(best_so_far, current)),
     )
 
+## internal helper
+##
+## Only called when two numbers are unordered, meaning at least one is NaN. Nothing is
+## ordered against NaN, not even NaN itself, so `x >= x` is false exactly when `x` is NaN.
+nan_of : Num a, Num a -> Num a
+nan_of = |a, b|
+    if a >= a then
+        b
+    else
+        a
+
 ## Like [List.map], except the transformation function wraps the return value
 ## in a list. At the end, all the lists get joined together into one list.
 ##
 ## You may know a similar function named `concat_map` in other languages.
+##
+## ## Performance Details
+##
+## The first non-empty list returned by the function is reused as the result
+## rather than copied, and later ones are appended to it in place, growing its
+## capacity geometrically. If every returned list is empty, nothing is allocated.
 join_map : List a, (a -> List b) -> List b
 join_map = |list, mapper|
     List.walk(list, [], |state, elem| List.concat(state, mapper(elem)))
@@ -1193,6 +1331,9 @@ find_first = |list, pred|
 
 ## Returns the last element of the list satisfying a predicate function.
 ## If no satisfying element is found, an `Err NotFound` is returned.
+##
+## This walks the list from the end without reversing or copying it, and stops
+## at the first match it finds, so the elements before that are never visited.
 find_last : List elem, (elem -> Bool) -> Result elem [NotFound]
 find_last = |list, pred|
     callback = |_, elem|
@@ -1273,8 +1414,7 @@ sublist_lowlevel : List elem, U64, U64 -> List elem
 ## ```
 intersperse : List elem, elem -> List elem
 intersperse = |list, sep|
-    capacity = 2 * List.len(list)
-    init = List.with_capacity(capacity)
+    init = List.with_capacity(2 * List.len(list))
     new_list =
         List.walk(
             list,
@@ -1318,9 +1458,16 @@ ends_with = |list, suffix|
 ##
 ## The returned lists are labeled `before` and `others`. The `before` list will
 ## contain all the elements whose index in the original list was **less than**
-## than the given index, # and the `others` list will be all the others. (This
+## the given index, and the `others` list will be all the others. (This
 ## means if you give an index of 0, the `before` list will be empty and the
-## `others` list will have the same elements as the original list.)
+## `others` list will have the same elements as the original list.) An index
+## past the end of the list puts every element in `before`.
+##
+## Neither half copies any elements; both share the original list's memory.
+## ```roc
+## expect List.split_at([1, 2, 3], 1) == { before: [1], others: [2, 3] }
+## expect List.split_at([1, 2, 3], 5) == { before: [1, 2, 3], others: [] }
+## ```
 split_at : List elem, U64 -> { before : List elem, others : List elem }
 split_at = |elements, user_split_index|
     length = List.len(elements)
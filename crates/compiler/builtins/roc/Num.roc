@@ -34,13 +34,16 @@ module [
     pi,
     tau,
     abs,
+    abs_checked,
     abs_diff,
     neg,
+    neg_checked,
     add,
     sub,
     mul,
     min,
     max,
+    clamp,
     is_lt,
     is_lte,
     is_gt,
@@ -50,8 +53,15 @@ module [
     cos,
     tan,
     atan,
+    atan2,
     acos,
     asin,
+    sinh,
+    cosh,
+    tanh,
+    asinh,
+    acosh,
+    atanh,
     is_zero,
     is_even,
     is_odd,
@@ -63,6 +73,7 @@ module [
     is_finite,
     rem,
     rem_checked,
+    div_rem,
     div,
     div_checked,
     sqrt,
@@ -75,11 +86,13 @@ module [
     compare,
     pow,
     pow_int,
+    pow_int_checked,
     count_leading_zero_bits,
     count_trailing_zero_bits,
     count_one_bits,
     add_wrap,
     add_checked,
+    add_with_overflow,
     add_saturated,
     bitwise_and,
     bitwise_xor,
@@ -94,12 +107,15 @@ module [
     mul_wrap,
     mul_saturated,
     mul_checked,
+    mul_with_overflow,
+    mul_add,
     int_cast,
     div_ceil,
     div_ceil_checked,
     div_trunc,
     div_trunc_checked,
     to_str,
+    to_str_with_radix,
     is_multiple_of,
     min_i8,
     max_i8,
@@ -161,6 +177,9 @@ module [
     f32_from_bits,
     f64_from_bits,
     dec_from_bits,
+    u16_to_le_bytes,
+    u32_to_le_bytes,
+    u64_to_le_bytes,
     from_bool,
     nan_f32,
     nan_f64,
@@ -556,6 +575,24 @@ tau = 6.2831853071795864769252867665590057683943387987502
 ##
 to_str : Num * -> Str
 
+## Convert an [Int] to a [Str] in the given radix, which must be between 2 and 36.
+##
+## Digits above 9 are written as lowercase letters, and there is no prefix
+## such as `0x`. Negative numbers start with a `-`.
+## ```roc
+## Num.to_str_with_radix(255, 16) == Ok("ff")
+## Num.to_str_with_radix(-10, 2) == Ok("-1010")
+## Num.to_str_with_radix(10, 37) == Err(InvalidRadix)
+## ```
+to_str_with_radix : Int *, U8 -> Result Str [InvalidRadix]
+to_str_with_radix = |int, radix|
+    if radix < 2 or radix > 36 then
+        Err(InvalidRadix)
+    else
+        Ok(to_str_with_radix_lowlevel(int, radix))
+
+to_str_with_radix_lowlevel : Int *, U8 -> Str
+
 ## Convert an [Int] to a new [Int] of the expected type:
 ##
 ## ```roc
@@ -621,12 +658,24 @@ is_gte : Num a, Num a -> Bool
 ##
 ## This function is symmetric: `Num.is_approx_eq(a, b) == Num.is_approx_eq(b, a)`
 ##
+## To compare against a plain epsilon, so that this is `|a - b| <= epsilon`, turn off the
+## relative tolerance:
+## ```roc
+## Num.is_approx_eq(0.1 + 0.2, 0.3, { atol: 1e-9, rtol: 0 })
+## ```
+##
 ## If either argument is [*NaN*](Num#is_nan), returns `Bool.false` no matter what. (*NaN*
 ## is [defined to be unordered](https://en.wikipedia.org/wiki/NaN#Comparison_with_NaN).)
+## An infinity is only approximately equal to the same infinity.
 is_approx_eq : Frac a, Frac a, { rtol ?? Frac a, atol ?? Frac a } -> Bool
 is_approx_eq = |x, y, { rtol ?? 0.00001, atol ?? 0.00000001 }|
     eq = x <= y and x >= y
-    meets_tolerance = Num.abs_diff(x, y) <= Num.max(atol, (rtol * Num.max(Num.abs(x), Num.abs(y))))
+    # Without the finiteness check, an infinity would be within rtol of everything,
+    # since rtol times an infinity is itself infinite.
+    meets_tolerance =
+        Num.is_finite(x)
+        and Num.is_finite(y)
+        and Num.abs_diff(x, y) <= Num.max(atol, (rtol * Num.max(Num.abs(x), Num.abs(y))))
     eq or meets_tolerance
 
 ## Returns `Bool.true` if the number is `0`, and `Bool.false` otherwise.
@@ -700,6 +749,22 @@ is_finite : Frac * -> Bool
 ## Calling this on an unsigned integer (like [U32] or [U64]) never does anything.
 abs : Num a -> Num a
 
+## Returns the absolute value of the number, or `Err(Overflow)` if that can't
+## be represented in the number's type.
+## ```roc
+## Num.abs_checked(-5) == Ok(5)
+##
+## Num.abs_checked(Num.min_i8) == Err(Overflow)
+## ```
+## The only value this can fail for is the lowest value of a signed integer,
+## such as [Num.min_i8]; see [Num.abs] for why.
+abs_checked : Num a -> Result (Num a) [Overflow]
+abs_checked = |x|
+    if x < 0 then
+        sub_checked(0, x)
+    else
+        Ok(x)
+
 ## Returns the absolute difference between two numbers.
 ##
 ## ```roc
@@ -745,6 +810,20 @@ abs_diff = |a, b|
 ## (It will never crash when given a [Frac], however, because of how floating point numbers represent positive and negative numbers.)
 neg : Num a -> Num a
 
+## Negates the number, or returns `Err(Overflow)` if the negated number can't
+## be represented in the number's type.
+## ```roc
+## Num.neg_checked(5) == Ok(-5)
+##
+## Num.neg_checked(Num.min_i8) == Err(Overflow)
+##
+## Num.neg_checked(3u8) == Err(Overflow)
+## ```
+## This fails for the lowest value of a signed integer (see [Num.neg]), and for
+## any unsigned integer other than zero.
+neg_checked : Num a -> Result (Num a) [Overflow]
+neg_checked = |x| sub_checked(0, x)
+
 ## Adds two numbers of the same type.
 ##
 ## (To add an [Int] and a [Frac], first convert one so that they both have the same type. There are functions in this module that can convert both [Int] to [Frac] and the other way around.)
@@ -838,6 +917,26 @@ max = |a, b|
     else
         b
 
+## Restricts a number to the range from `low` to `high`, inclusive.
+##
+## ```roc
+## Num.clamp(15, 0, 10) == 10
+##
+## Num.clamp(-3.5, 0, 10) == 0
+## ```
+## If `low` is greater than `high`, there is no such range, and `low` is returned.
+## For [F32] and [F64], a *NaN* `num` is returned unchanged.
+clamp : Num a, Num a, Num a -> Num a
+clamp = |num, low, high|
+    if low > high then
+        low
+    else if num < low then
+        low
+    else if num > high then
+        high
+    else
+        num
+
 sin : Frac a -> Frac a
 cos : Frac a -> Frac a
 tan : Frac a -> Frac a
@@ -846,6 +945,34 @@ asin : Frac a -> Frac a
 acos : Frac a -> Frac a
 atan : Frac a -> Frac a
 
+sinh : Frac a -> Frac a
+cosh : Frac a -> Frac a
+tanh : Frac a -> Frac a
+
+asinh : Frac a -> Frac a
+
+## The inverse of [cosh]. Only defined for inputs of at least 1; for [F32] and
+## [F64], anything smaller returns *NaN*.
+acosh : Frac a -> Frac a
+
+## The inverse of [tanh]. Only defined for inputs strictly between -1 and 1; for
+## [F32] and [F64], anything else (including -1 and 1 themselves) returns *NaN*.
+atanh : Frac a -> Frac a
+
+## The angle, in radians, from the positive x axis to the point `(x, y)`.
+##
+## Unlike `Num.atan(y / x)`, this uses the signs of both arguments to pick the
+## right quadrant, so the answer ranges from -π to π, and it works when `x` is 0.
+## ```roc
+## Num.atan2(1, 1) # π/4
+##
+## Num.atan2(-1, -1) # -3π/4
+##
+## Num.atan2(0, 0) # 0
+## ```
+## Note that `y` comes first, matching `atan2` in C and most other languages.
+atan2 : Frac a, Frac a -> Frac a
+
 ## Returns an approximation of the absolute value of a [Frac]'s square root.
 ##
 ## The square root of a negative number is an irrational number, and [Frac] only
@@ -964,10 +1091,14 @@ div_trunc = |a, b|
 
 div_trunc_checked : Int a, Int a -> Result (Int a) [DivByZero]
 div_trunc_checked = |a, b|
-    if Num.is_zero(b) then
+    result = div_trunc_checked_lowlevel(a, b)
+
+    if result.b then
         Err(DivByZero)
     else
-        Ok(Num.div_trunc_unchecked(a, b))
+        Ok(result.a)
+
+div_trunc_checked_lowlevel : Int a, Int a -> { b : Bool, a : Int a }
 
 ## traps (hardware fault) when given zero as the second argument.
 div_trunc_unchecked : Int a, Int a -> Int a
@@ -993,14 +1124,51 @@ rem = |a, b|
 
 rem_checked : Int a, Int a -> Result (Int a) [DivByZero]
 rem_checked = |a, b|
-    if Num.is_zero(b) then
+    result = rem_checked_lowlevel(a, b)
+
+    if result.b then
         Err(DivByZero)
     else
-        Ok(Num.rem_unchecked(a, b))
+        Ok(result.a)
+
+rem_checked_lowlevel : Int a, Int a -> { b : Bool, a : Int a }
+
+## Divides two integers, returning both the quotient (truncated towards zero,
+## like [Num.div_trunc]) and the remainder (like [Num.rem]).
+## ```roc
+## Num.div_rem(17, 5) == { quotient: 3, remainder: 2 }
+##
+## Num.div_rem(-17, 5) == { quotient: -3, remainder: -2 }
+## ```
+## Like those functions, this crashes when the second argument is zero.
+##
+## ## Performance Details
+##
+## Both results come from the same operands, so the optimizer can compute them
+## with a single division instruction on targets that provide both at once.
+div_rem : Int a, Int a -> { quotient : Int a, remainder : Int a }
+div_rem = |a, b|
+    if Num.is_zero(b) then
+        crash("Integer division by 0!")
+    else
+        { quotient: Num.div_trunc_unchecked(a, b), remainder: Num.rem_unchecked(a, b) }
 
 ## traps (hardware fault) when given zero as the second argument.
 rem_unchecked : Int a, Int a -> Int a
 
+## Returns `Bool.true` if the first number divides evenly by the second,
+## meaning `a == b * n` for some integer `n`.
+## ```roc
+## Num.is_multiple_of(12, 4)
+##
+## Num.is_multiple_of(-12, 4)
+## ```
+## Only zero is a multiple of zero, so unlike `Num.rem(a, 0) == 0`, this never crashes:
+## ```roc
+## Num.is_multiple_of(0, 0) # Bool.true
+##
+## Num.is_multiple_of(5, 0) # Bool.false
+## ```
 is_multiple_of : Int a, Int a -> Bool
 
 ## Does a "bitwise and". Each bit of the output is 1 if the corresponding bit
@@ -1082,9 +1250,28 @@ pow : Frac a, Frac a -> Frac a
 ## ## Warning
 ##
 ## It is very easy for this function to produce an answer
-## so large it causes an overflow.
+## so large it causes an overflow. To handle that case, use [Num.pow_int_checked].
 pow_int : Int a, Int a -> Int a
 
+## Raises an integer to the power of another and checks for overflow.
+##
+## This is the same as [Num.pow_int] except if the operation overflows, instead of
+## panicking, it will return `Err Overflow`.
+## ```roc
+## Num.pow_int_checked(2i64, 10) == Ok(1024)
+## Num.pow_int_checked(2i64, 63) == Err(Overflow)
+## ```
+pow_int_checked : Int a, Int a -> Result (Int a) [Overflow]
+pow_int_checked = |base, exp|
+    result = pow_int_checked_lowlevel(base, exp)
+
+    if result.b then
+        Err(Overflow)
+    else
+        Ok(result.a)
+
+pow_int_checked_lowlevel : Int a, Int a -> { b : Bool, a : Int a }
+
 ## Counts the number of most-significant (leading in a big-Endian sense) zeroes in an integer.
 ##
 ## ```roc
@@ -1150,6 +1337,18 @@ add_checked = |a, b|
 
 add_checked_lowlevel : Num a, Num a -> { b : Bool, a : Num a }
 
+## Adds two integers, returning both the wrapped result (as [Num.add_wrap] would)
+## and whether the addition overflowed.
+## ```roc
+## Num.add_with_overflow(255u8, 1) == { value: 0, overflowed: Bool.true }
+## Num.add_with_overflow(254u8, 1) == { value: 255, overflowed: Bool.false }
+## ```
+add_with_overflow : Int a, Int a -> { value : Int a, overflowed : Bool }
+add_with_overflow = |a, b|
+    r = add_checked_lowlevel(a, b)
+
+    { value: r.a, overflowed: r.b }
+
 sub_wrap : Int range, Int range -> Int range
 
 ## Subtracts two numbers, clamping on the minimum representable number rather
@@ -1178,11 +1377,13 @@ sub_checked_lowlevel : Num a, Num a -> { b : Bool, a : Num a }
 
 mul_wrap : Int range, Int range -> Int range
 
-## Multiplies two numbers, clamping on the maximum representable number rather than
-## overflowing.
+## Multiplies two numbers, clamping on the maximum (or, for a negative result,
+## the minimum) representable number rather than overflowing.
 ##
 ## This is the same as [Num.mul] except for the saturating behavior if the
-## addition is to overflow.
+## multiplication is to overflow.
+## For example, if `x : I8` is -100 and `y : I8` is 2, `mul_saturated x y` will
+## yield -128, the minimum value of an `I8`.
 mul_saturated : Num a, Num a -> Num a
 
 ## Multiplies two numbers and checks for overflow.
@@ -1200,6 +1401,30 @@ mul_checked = |a, b|
 
 mul_checked_lowlevel : Num a, Num a -> { b : Bool, a : Num a }
 
+## Multiplies two integers, returning both the wrapped result (as [Num.mul_wrap]
+## would) and whether the multiplication overflowed.
+## ```roc
+## Num.mul_with_overflow(128u8, 2) == { value: 0, overflowed: Bool.true }
+## Num.mul_with_overflow(-64i8, 2) == { value: -128, overflowed: Bool.false }
+## ```
+mul_with_overflow : Int a, Int a -> { value : Int a, overflowed : Bool }
+mul_with_overflow = |a, b|
+    # The value from mul_checked_lowlevel saturates for 128-bit integers, so take it from mul_wrap.
+    { value: mul_wrap(a, b), overflowed: mul_checked_lowlevel(a, b).b }
+
+## Multiplies the first two numbers and adds the third, like `(a * b) + c`.
+##
+## For [F32] and [F64] this is a *fused* multiply-add: the result is rounded once
+## instead of twice, so it can be more precise than multiplying and adding
+## separately. It uses the processor's FMA instruction where there is one.
+## ```roc
+## (0.1f64 * 10) - 1 == 0
+## Num.mul_add(0.1f64, 10, -1) == 5.551115123125783e-17
+## ```
+## [Dec] has no fused form, so for [Dec] this is exactly `(a * b) + c`, and it
+## panics if either step overflows.
+mul_add : Frac a, Frac a, Frac a -> Frac a
+
 ## Returns the lowest number that can be stored in an [I8] without underflowing
 ## its available memory and crashing.
 ##
@@ -1410,11 +1635,33 @@ to_i8 : Int * -> I8
 to_i16 : Int * -> I16
 to_i32 : Int * -> I32
 to_i64 : Int * -> I64
+
+## Converts an [Int] to an [I128]. Signed numbers are sign-extended and unsigned numbers are
+## zero-extended, so the number doesn't change. The one exception is a [U128] above
+## [Num.max_i128], which wraps around to a negative number.
+## ```roc
+## Num.to_i128(-15i8) == -15
+## ```
 to_i128 : Int * -> I128
 to_u8 : Int * -> U8
+
+## Converts an [Int] to a [U16]. Negative numbers wrap around the same way they do for
+## [Num.to_u128]: the result is the number plus 2^16, so `-1` becomes [Num.max_u16].
+## [Num.to_u32] and [Num.to_u64] work the same way.
+## ```roc
+## Num.to_u16(-1i8) == Num.max_u16
+## ```
 to_u16 : Int * -> U16
 to_u32 : Int * -> U32
 to_u64 : Int * -> U64
+
+## Converts an [Int] to a [U128]. Non-negative numbers are zero-extended and keep their value.
+##
+## Negative numbers wrap around rather than crashing: the result is the number plus 2^128,
+## so `-1` becomes [Num.max_u128]. Use [Num.to_u128_checked] to get an `Err` instead.
+## ```roc
+## Num.to_u128(-1i8) == Num.max_u128
+## ```
 to_u128 : Int * -> U128
 
 ## Converts a [Num] to an [F32]. If the given number can't be precisely represented in an [F32],
@@ -1438,8 +1685,20 @@ to_u16_checked : Int * -> Result U16 [OutOfBounds]
 to_u32_checked : Int * -> Result U32 [OutOfBounds]
 to_u64_checked : Int * -> Result U64 [OutOfBounds]
 to_u128_checked : Int * -> Result U128 [OutOfBounds]
-to_f32_checked : Num * -> Result F32 [OutOfBounds]
-to_f64_checked : Num * -> Result F64 [OutOfBounds]
+
+## Converts a [Num] to an [F32].
+## If the given number can't be precisely represented in an [F32] (for example,
+## because it's an integer too large for an [F32] to represent without rounding),
+## returns `Err (Inexact rounded)`, where `rounded` is what [Num.to_f32] would give.
+## If the number is too big for an [F32] at all, returns `Err OutOfBounds`.
+to_f32_checked : Num * -> Result F32 [Inexact F32, OutOfBounds]
+
+## Converts a [Num] to an [F64].
+## If the given number can't be precisely represented in an [F64], returns
+## `Err (Inexact rounded)`. For example, `Num.to_f64_checked(9007199254740993)` returns
+## `Err (Inexact 9007199254740992)`, since 2^53 + 1 is the first integer an [F64] can't represent.
+## No number is too big for an [F64], so this never returns `Err OutOfBounds`.
+to_f64_checked : Num * -> Result F64 [Inexact F64, OutOfBounds]
 
 ## Turns a [Dec] into its [I128] representation by removing the decimal point.
 ## This is equivalent to multiplying the [Dec] by 10^18.
@@ -1506,6 +1765,43 @@ f64_from_bits : U64 -> F64
 ## decimal value and returns the corresponding [Dec].
 dec_from_bits : U128 -> Dec
 
+## Returns the bytes of a [U16], least significant byte first.
+## ```roc
+## Num.u16_to_le_bytes(0x0102) == [0x02, 0x01]
+## ```
+## To serialize a signed integer, convert it with [Num.to_u16] first; that keeps its bits.
+u16_to_le_bytes : U16 -> List U8
+u16_to_le_bytes = |n|
+    [le_byte(n, 0), le_byte(n, 1)]
+
+## Returns the bytes of a [U32], least significant byte first.
+## ```roc
+## Num.u32_to_le_bytes(0x01020304) == [0x04, 0x03, 0x02, 0x01]
+## ```
+u32_to_le_bytes : U32 -> List U8
+u32_to_le_bytes = |n|
+    [le_byte(n, 0), le_byte(n, 1), le_byte(n, 2), le_byte(n, 3)]
+
+## Returns the bytes of a [U64], least significant byte first.
+## ```roc
+## Num.u64_to_le_bytes(1) == [1, 0, 0, 0, 0, 0, 0, 0]
+## ```
+u64_to_le_bytes : U64 -> List U8
+u64_to_le_bytes = |n|
+    [
+        le_byte(n, 0),
+        le_byte(n, 1),
+        le_byte(n, 2),
+        le_byte(n, 3),
+        le_byte(n, 4),
+        le_byte(n, 5),
+        le_byte(n, 6),
+        le_byte(n, 7),
+    ]
+
+le_byte : Int a, U8 -> U8
+le_byte = |n, index| to_u8(shift_right_zf_by(n, index * 8))
+
 ## Convert a `Bool` to a `Num`
 ## ```roc
 ## expect Num.from_bool(Bool.true) == 1
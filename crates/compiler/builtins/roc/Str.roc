@@ -342,16 +342,20 @@ module [
     is_empty,
     join_with,
     split_on,
+    split_on_any,
     repeat,
     len,
     count_utf8_bytes,
     to_utf8,
     from_utf8,
+    from_utf8_range,
     from_utf16,
     from_utf32,
     from_utf8_lossy,
     from_utf16_lossy,
     from_utf32_lossy,
+    from_scalar,
+    append_scalar,
     starts_with,
     ends_with,
     trim,
@@ -387,6 +391,7 @@ module [
     with_ascii_lowercased,
     with_ascii_uppercased,
     caseless_ascii_equals,
+    compare,
 ]
 
 import Bool exposing [Bool]
@@ -520,6 +525,18 @@ join_with : List Str, Str -> Str
 ## ```
 split_on : Str, Str -> List Str
 
+## Split a string around any of several separators.
+##
+## When more than one separator matches at the same position, the longest one wins.
+## Separators next to each other produce empty strings, just like in [Str.split_on],
+## and `""` separators are ignored.
+## ```roc
+## expect Str.split_on_any("a,b;c", [",", ";"]) == ["a", "b", "c"]
+## expect Str.split_on_any("a,;b", [",", ";"]) == ["a", "", "b"]
+## expect Str.split_on_any("a->b-c", ["-", "->"]) == ["a", "b", "c"]
+## ```
+split_on_any : Str, List Str -> List Str
+
 ## Repeats a string the given number of times.
 ## ```roc
 ## expect Str.repeat("z", 3) == "zzz"
@@ -583,6 +600,26 @@ FromUtf8Result : {
 
 from_utf8_lowlevel : List U8 -> FromUtf8Result
 
+## Like [Str.from_utf8], but only decodes `len` bytes of the list, beginning at
+## the `start` index. Only those bytes are validated, and the `index` in a
+## `BadUtf8` error counts from `start`.
+##
+## Returns `Err(OutOfBounds)` if the range doesn't fit inside the list.
+## ```roc
+## expect Str.from_utf8_range([72, 105, 82, 111, 99, 33], { start: 2, len: 3 }) == Ok("Roc")
+## expect Str.from_utf8_range([72, 105], { start: 1, len: 2 }) == Err(OutOfBounds)
+## ```
+## This doesn't copy the bytes first; the range is a slice of the original list.
+from_utf8_range : List U8, { start : U64, len : U64 } -> Result Str [BadUtf8 { problem : Utf8Problem, index : U64 }, OutOfBounds]
+from_utf8_range = |bytes, { start, len }|
+    if Num.add_saturated(start, len) <= List.len(bytes) then
+        from_utf8(List.sublist(bytes, { start, len }))
+    else
+        Err(OutOfBounds)
+
+expect (Str.from_utf8_range([72, 105, 82, 111, 99, 33], { start: 2, len: 3 })) == Ok("Roc")
+expect (Str.from_utf8_range([72, 105], { start: 1, len: 2 })) == Err(OutOfBounds)
+
 ## Converts a [List] of [U8] UTF-8 [code units](https://unicode.org/glossary/#code_unit) to a string.
 ## Any grouping of invalid byte sequences are replaced with a single unicode replacement character '�'.
 ##
@@ -814,6 +851,42 @@ from_utf32_lossy = |codepoints|
 expect Str.from_utf32_lossy([82, 111, 99, 0x1f426]) == "Roc🐦"
 expect Str.from_utf32_lossy([82, 0x110000, 99]) == "R�c"
 
+## Encodes a single Unicode [scalar value](https://unicode.org/glossary/#unicode_scalar_value) as a string.
+##
+## Returns `Err(InvalidScalar)` for surrogates (`0xD800` to `0xDFFF`) and for
+## anything above `0x10FFFF`, since those aren't scalar values.
+## ```roc
+## expect Str.from_scalar(0x1f426) == Ok("🐦")
+## expect Str.from_scalar(0xd800) == Err(InvalidScalar)
+## ```
+from_scalar : U32 -> Result Str [InvalidScalar]
+from_scalar = |scalar| append_scalar("", scalar)
+
+expect Str.from_scalar(82) == Ok("R")
+expect Str.from_scalar(0x1f426) == Ok("🐦")
+expect Str.from_scalar(0xdfff) == Err(InvalidScalar)
+expect Str.from_scalar(0x110000) == Err(InvalidScalar)
+
+## Appends the UTF-8 encoding of a Unicode [scalar value](https://unicode.org/glossary/#unicode_scalar_value)
+## to a string. Like [Str.concat], this updates the string in place if nothing else refers to it.
+##
+## Returns `Err(InvalidScalar)` in the same cases as [Str.from_scalar].
+## ```roc
+## expect Str.append_scalar("Roc ", 0x1f426) == Ok("Roc 🐦")
+## ```
+append_scalar : Str, U32 -> Result Str [InvalidScalar]
+append_scalar = |string, scalar|
+    when encode_utf8([], scalar) is
+        Ok(bytes) ->
+            when from_utf8(bytes) is
+                Ok(encoded) -> Ok(concat(string, encoded))
+                Err(_) -> Err(InvalidScalar)
+
+        Err(_) -> Err(InvalidScalar)
+
+expect Str.append_scalar("Roc ", 0x1f426) == Ok("Roc 🐦")
+expect Str.append_scalar("Roc", 0xd83d) == Err(InvalidScalar)
+
 ## Check if the given [Str] starts with a value.
 ## ```roc
 ## expect Str.starts_with("ABC", "A") == Bool.true
@@ -1115,21 +1188,15 @@ expect split_first("foo", "foo") == Ok({ before: "", after: "" })
 
 first_match : Str, Str -> [Some U64, None]
 first_match = |haystack, needle|
-    haystack_length = Str.count_utf8_bytes(haystack)
-    needle_length = Str.count_utf8_bytes(needle)
-    last_possible = Num.sub_saturated(haystack_length, needle_length)
-
-    first_match_help(haystack, needle, 0, last_possible)
+    index = split_first_lowlevel(haystack, needle)
 
-first_match_help : Str, Str, U64, U64 -> [Some U64, None]
-first_match_help = |haystack, needle, index, last_possible|
-    if index <= last_possible then
-        if matches_at(haystack, index, needle) then
-            Some(index)
-        else
-            first_match_help(haystack, needle, Num.add_wrap(index, 1), last_possible)
-    else
+    if index == Num.max_u64 then
         None
+    else
+        Some(index)
+
+# Returns the byte index of the first occurrence of the needle, or Num.max_u64 if there isn't one.
+split_first_lowlevel : Str, Str -> U64
 
 ## Returns the given [Str] before the last occurrence of a delimiter, as well as
 ## the rest of the string after that occurrence.
@@ -1155,6 +1222,9 @@ split_last = |haystack, needle|
 # split_last when needle isn't in haystack
 expect Str.split_last("foo", "z") == Err(NotFound)
 
+# split_last when needle isn't in haystack, and haystack is empty
+expect Str.split_last("", "z") == Err(NotFound)
+
 # split_last when haystack ends with needle repeated
 expect Str.split_last("foo", "o") == Ok({ before: "fo", after: "" })
 
@@ -1166,65 +1236,15 @@ expect Str.split_last("foo", "foo") == Ok({ before: "", after: "" })
 
 last_match : Str, Str -> [Some U64, None]
 last_match = |haystack, needle|
-    haystack_length = Str.count_utf8_bytes(haystack)
-    needle_length = Str.count_utf8_bytes(needle)
-    last_possible_index = Num.sub_saturated(haystack_length, needle_length)
-
-    last_match_help(haystack, needle, last_possible_index)
-
-last_match_help : Str, Str, U64 -> [Some U64, None]
-last_match_help = |haystack, needle, index|
-    if matches_at(haystack, index, needle) then
-        Some(index)
-    else
-        when Num.sub_checked(index, 1) is
-            Ok(next_index) ->
-                last_match_help(haystack, needle, next_index)
-
-            Err(_) ->
-                None
-
-min = |x, y| if x < y then x else y
-
-matches_at : Str, U64, Str -> Bool
-matches_at = |haystack, haystack_index, needle|
-    haystack_length = Str.count_utf8_bytes(haystack)
-    needle_length = Str.count_utf8_bytes(needle)
-    end_index = min(Num.add_saturated(haystack_index, needle_length), haystack_length)
-
-    matches_at_help(
-        {
-            haystack,
-            haystack_index,
-            needle,
-            needle_index: 0,
-            needle_length,
-            end_index,
-        },
-    )
-
-matches_at_help = |state|
-    { haystack, haystack_index, needle, needle_index, needle_length, end_index } = state
-    is_at_end_of_haystack = haystack_index >= end_index
-
-    if is_at_end_of_haystack then
-        did_walk_entire_needle = needle_index == needle_length
+    index = split_last_lowlevel(haystack, needle)
 
-        did_walk_entire_needle
+    if index == Num.max_u64 then
+        None
     else
-        does_this_match =
-            Str.get_unsafe(haystack, haystack_index)
-            ==
-            Str.get_unsafe(needle, needle_index)
-        does_rest_match =
-            matches_at_help(
-                { state &
-                    haystack_index: Num.add_wrap(haystack_index, 1),
-                    needle_index: Num.add_wrap(needle_index, 1),
-                },
-            )
+        Some(index)
 
-        does_this_match and does_rest_match
+# Returns the byte index of the last occurrence of the needle, or Num.max_u64 if there isn't one.
+split_last_lowlevel : Str, Str -> U64
 
 ## Walks over the `UTF-8` bytes of the given [Str] and calls a function to update
 ## state for each byte. The index for that byte in the string is provided
@@ -1434,3 +1454,19 @@ caseless_ascii_equals : Str, Str -> Bool
 
 expect Str.caseless_ascii_equals("café", "CAFé")
 expect !Str.caseless_ascii_equals("café", "CAFÉ")
+
+## Compares two strings by their UTF-8 bytes, which orders them the same way as
+## comparing their [code points](https://en.wikipedia.org/wiki/Code_point) one by one.
+## A string which is a prefix of another string is ordered before it.
+## ```roc
+## expect Str.compare("ab", "abc") == LT
+## expect Str.compare("abc", "abc") == EQ
+## expect Str.compare("b", "abc") == GT
+## ```
+## This is not a locale-aware ordering; for example, all uppercase ASCII letters
+## are ordered before all lowercase ones.
+compare : Str, Str -> [LT, EQ, GT]
+
+expect Str.compare("ab", "abc") == LT
+expect Str.compare("abc", "abc") == EQ
+expect Str.compare("b", "abc") == GT
@@ -263,6 +263,13 @@ pub const NUM_TAN: IntrinsicName = float_intrinsic!("roc_builtins.num.tan");
 pub const NUM_ASIN: IntrinsicName = float_intrinsic!("roc_builtins.num.asin");
 pub const NUM_ACOS: IntrinsicName = float_intrinsic!("roc_builtins.num.acos");
 pub const NUM_ATAN: IntrinsicName = float_intrinsic!("roc_builtins.num.atan");
+pub const NUM_ATAN2: IntrinsicName = float_intrinsic!("roc_builtins.num.atan2");
+pub const NUM_SINH: IntrinsicName = float_intrinsic!("roc_builtins.num.sinh");
+pub const NUM_COSH: IntrinsicName = float_intrinsic!("roc_builtins.num.cosh");
+pub const NUM_TANH: IntrinsicName = float_intrinsic!("roc_builtins.num.tanh");
+pub const NUM_ASINH: IntrinsicName = float_intrinsic!("roc_builtins.num.asinh");
+pub const NUM_ACOSH: IntrinsicName = float_intrinsic!("roc_builtins.num.acosh");
+pub const NUM_ATANH: IntrinsicName = float_intrinsic!("roc_builtins.num.atanh");
 pub const NUM_IS_NAN: IntrinsicName = float_intrinsic!("roc_builtins.num.is_nan");
 pub const NUM_IS_INFINITE: IntrinsicName = float_intrinsic!("roc_builtins.num.is_infinite");
 pub const NUM_IS_FINITE: IntrinsicName = float_intrinsic!("roc_builtins.num.is_finite");
@@ -270,9 +277,14 @@ pub const NUM_LOG: IntrinsicName = float_intrinsic!("roc_builtins.num.log");
 pub const NUM_POW: IntrinsicName = float_intrinsic!("roc_builtins.num.pow");
 pub const NUM_FABS: IntrinsicName = float_intrinsic!("roc_builtins.num.fabs");
 pub const NUM_SQRT: IntrinsicName = float_intrinsic!("roc_builtins.num.sqrt");
+pub const NUM_MUL_ADD: IntrinsicName = float_intrinsic!("roc_builtins.num.mul_add");
 
 pub const NUM_POW_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.pow_int");
+pub const NUM_POW_INT_CHECKED: IntrinsicName = int_intrinsic!("roc_builtins.num.pow_int_checked");
 pub const NUM_DIV_CEIL: IntrinsicName = int_intrinsic!("roc_builtins.num.div_ceil");
+pub const NUM_DIV_TRUNC_CHECKED: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.div_trunc_checked");
+pub const NUM_REM_CHECKED: IntrinsicName = int_intrinsic!("roc_builtins.num.rem_checked");
 pub const NUM_CEILING_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.ceiling_f32");
 pub const NUM_CEILING_F64: IntrinsicName = int_intrinsic!("roc_builtins.num.ceiling_f64");
 pub const NUM_FLOOR_F32: IntrinsicName = int_intrinsic!("roc_builtins.num.floor_f32");
@@ -283,6 +295,16 @@ pub const INT_TO_FLOAT_CAST_F32: IntrinsicName =
     int_intrinsic!("roc_builtins.num.num_to_float_cast_f32");
 pub const INT_TO_FLOAT_CAST_F64: IntrinsicName =
     int_intrinsic!("roc_builtins.num.num_to_float_cast_f64");
+pub const NUM_TO_F32_CHECKED_INT: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.num_to_f32_checked");
+pub const NUM_TO_F32_CHECKED_FLOAT: IntrinsicName =
+    float_intrinsic!("roc_builtins.num.num_to_f32_checked");
+pub const NUM_TO_F64_CHECKED_INT: IntrinsicName =
+    int_intrinsic!("roc_builtins.num.num_to_f64_checked");
+pub const NUM_TO_F64_CHECKED_FLOAT: IntrinsicName =
+    float_intrinsic!("roc_builtins.num.num_to_f64_checked");
+pub const NUM_TO_I128: IntrinsicName = int_intrinsic!("roc_builtins.num.num_to_i128");
+pub const NUM_TO_U128: IntrinsicName = int_intrinsic!("roc_builtins.num.num_to_u128");
 
 pub const NUM_ADD_OR_PANIC_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_or_panic");
 pub const NUM_ADD_SATURATED_INT: IntrinsicName = int_intrinsic!("roc_builtins.num.add_saturated");
@@ -339,6 +361,7 @@ pub const STR_COUNT_SEGMENTS: &str = "roc_builtins.str.count_segments";
 pub const STR_CONCAT: &str = "roc_builtins.str.concat";
 pub const STR_JOIN_WITH: &str = "roc_builtins.str.joinWith";
 pub const STR_SPLIT_ON: &str = "roc_builtins.str.str_split_on";
+pub const STR_SPLIT_ON_ANY: &str = "roc_builtins.str.str_split_on_any";
 pub const STR_COUNT_UTF8_BYTES: &str = "roc_builtins.str.count_utf8_bytes";
 pub const STR_IS_EMPTY: &str = "roc_builtins.str.is_empty";
 pub const STR_CAPACITY: &str = "roc_builtins.str.capacity";
@@ -346,6 +369,8 @@ pub const STR_STARTS_WITH: &str = "roc_builtins.str.starts_with";
 pub const STR_ENDS_WITH: &str = "roc_builtins.str.ends_with";
 pub const STR_NUMBER_OF_BYTES: &str = "roc_builtins.str.number_of_bytes";
 pub const STR_FROM_INT: IntrinsicName = int_intrinsic!("roc_builtins.str.from_int");
+pub const STR_FROM_INT_WITH_RADIX: IntrinsicName =
+    int_intrinsic!("roc_builtins.str.from_int_with_radix");
 pub const STR_FROM_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.str.from_float");
 pub const STR_TO_INT: IntrinsicName = int_intrinsic!("roc_builtins.str.to_int");
 pub const STR_TO_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.str.to_float");
@@ -368,6 +393,9 @@ pub const STR_RELEASE_EXCESS_CAPACITY: &str = "roc_builtins.str.release_excess_c
 pub const STR_WITH_ASCII_LOWERCASED: &str = "roc_builtins.str.with_ascii_lowercased";
 pub const STR_WITH_ASCII_UPPERCASED: &str = "roc_builtins.str.with_ascii_uppercased";
 pub const STR_CASELESS_ASCII_EQUALS: &str = "roc_builtins.str.caseless_ascii_equals";
+pub const STR_COMPARE: &str = "roc_builtins.str.compare";
+pub const STR_SPLIT_FIRST: &str = "roc_builtins.str.split_first";
+pub const STR_SPLIT_LAST: &str = "roc_builtins.str.split_last";
 
 pub const LIST_MAP: &str = "roc_builtins.list.map";
 pub const LIST_MAP2: &str = "roc_builtins.list.map2";
@@ -392,6 +420,8 @@ pub const LIST_RELEASE_EXCESS_CAPACITY: &str = "roc_builtins.list.release_excess
 pub const LIST_CONCAT_UTF8: &str = "roc_builtins.list.concat_utf8";
 pub const LIST_INCREF: &str = "roc_builtins.list.incref";
 pub const LIST_DECREF: &str = "roc_builtins.list.decref";
+pub const LIST_RANGE: IntrinsicName = int_intrinsic!("roc_builtins.list.range");
+pub const LIST_RANGE_WITH_STEP: IntrinsicName = int_intrinsic!("roc_builtins.list.range_with_step");
 
 pub const DEC_ABS: &str = "roc_builtins.dec.abs";
 pub const DEC_ACOS: &str = "roc_builtins.dec.acos";
@@ -399,7 +429,14 @@ pub const DEC_ADD_OR_PANIC: &str = "roc_builtins.dec.add_or_panic";
 pub const DEC_ADD_SATURATED: &str = "roc_builtins.dec.add_saturated";
 pub const DEC_ADD_WITH_OVERFLOW: &str = "roc_builtins.dec.add_with_overflow";
 pub const DEC_ASIN: &str = "roc_builtins.dec.asin";
+pub const DEC_SINH: &str = "roc_builtins.dec.sinh";
+pub const DEC_COSH: &str = "roc_builtins.dec.cosh";
+pub const DEC_TANH: &str = "roc_builtins.dec.tanh";
+pub const DEC_ASINH: &str = "roc_builtins.dec.asinh";
+pub const DEC_ACOSH: &str = "roc_builtins.dec.acosh";
+pub const DEC_ATANH: &str = "roc_builtins.dec.atanh";
 pub const DEC_ATAN: &str = "roc_builtins.dec.atan";
+pub const DEC_ATAN2: &str = "roc_builtins.dec.atan2";
 pub const DEC_COS: &str = "roc_builtins.dec.cos";
 pub const DEC_DIV: &str = "roc_builtins.dec.div";
 pub const DEC_EQ: &str = "roc_builtins.dec.eq";
@@ -411,6 +448,7 @@ pub const DEC_FROM_U64: &str = "roc_builtins.dec.from_u64";
 pub const DEC_LOG: &str = "roc_builtins.dec.log";
 pub const DEC_POW: &str = "roc_builtins.dec.pow";
 pub const DEC_MUL_OR_PANIC: &str = "roc_builtins.dec.mul_or_panic";
+pub const DEC_MUL_ADD: &str = "roc_builtins.dec.mul_add";
 pub const DEC_MUL_SATURATED: &str = "roc_builtins.dec.mul_saturated";
 pub const DEC_MUL_WITH_OVERFLOW: &str = "roc_builtins.dec.mul_with_overflow";
 pub const DEC_NEGATE: &str = "roc_builtins.dec.negate";
@@ -420,6 +458,11 @@ pub const DEC_SUB_OR_PANIC: &str = "roc_builtins.dec.sub_or_panic";
 pub const DEC_SUB_SATURATED: &str = "roc_builtins.dec.sub_saturated";
 pub const DEC_SUB_WITH_OVERFLOW: &str = "roc_builtins.dec.sub_with_overflow";
 pub const DEC_TAN: &str = "roc_builtins.dec.tan";
+pub const DEC_TO_FLOAT: IntrinsicName = float_intrinsic!("roc_builtins.dec.to_float");
+pub const DEC_TO_FLOAT_CHECKED: IntrinsicName =
+    float_intrinsic!("roc_builtins.dec.to_float_checked");
+pub const DEC_TO_FLOAT_INEXACT: IntrinsicName =
+    float_intrinsic!("roc_builtins.dec.to_float_inexact");
 pub const DEC_TO_I128: &str = "roc_builtins.dec.to_i128";
 pub const DEC_FROM_I128: &str = "roc_builtins.dec.from_i128";
 pub const DEC_TO_STR: &str = "roc_builtins.dec.to_str";
@@ -27,12 +27,12 @@ macro_rules! map_symbol_to_lowlevel_and_arity {
                 Symbol::NUM_TO_I16 => Some(lowlevel_1(Symbol::NUM_TO_I16, LowLevel::NumIntCast, var_store)),
                 Symbol::NUM_TO_I32 => Some(lowlevel_1(Symbol::NUM_TO_I32, LowLevel::NumIntCast, var_store)),
                 Symbol::NUM_TO_I64 => Some(lowlevel_1(Symbol::NUM_TO_I64, LowLevel::NumIntCast, var_store)),
-                Symbol::NUM_TO_I128 => Some(lowlevel_1(Symbol::NUM_TO_I128, LowLevel::NumIntCast, var_store)),
+                Symbol::NUM_TO_I128 => Some(lowlevel_1(Symbol::NUM_TO_I128, LowLevel::NumToI128, var_store)),
                 Symbol::NUM_TO_U8 => Some(lowlevel_1(Symbol::NUM_TO_U8, LowLevel::NumIntCast, var_store)),
                 Symbol::NUM_TO_U16 => Some(lowlevel_1(Symbol::NUM_TO_U16, LowLevel::NumIntCast, var_store)),
                 Symbol::NUM_TO_U32 => Some(lowlevel_1(Symbol::NUM_TO_U32, LowLevel::NumIntCast, var_store)),
                 Symbol::NUM_TO_U64 => Some(lowlevel_1(Symbol::NUM_TO_U64, LowLevel::NumIntCast, var_store)),
-                Symbol::NUM_TO_U128 => Some(lowlevel_1(Symbol::NUM_TO_U128, LowLevel::NumIntCast, var_store)),
+                Symbol::NUM_TO_U128 => Some(lowlevel_1(Symbol::NUM_TO_U128, LowLevel::NumToU128, var_store)),
 
                 Symbol::NUM_INT_CAST => Some(lowlevel_1(Symbol::NUM_INT_CAST, LowLevel::NumIntCast, var_store)),
 
@@ -50,8 +50,8 @@ macro_rules! map_symbol_to_lowlevel_and_arity {
                 Symbol::NUM_TO_U64_CHECKED => Some(to_num_checked(Symbol::NUM_TO_U64_CHECKED, var_store, LowLevel::NumToIntChecked)),
                 Symbol::NUM_TO_U128_CHECKED => Some(to_num_checked(Symbol::NUM_TO_U128_CHECKED, var_store, LowLevel::NumToIntChecked)),
 
-                Symbol::NUM_TO_F32_CHECKED => Some(to_num_checked(Symbol::NUM_TO_F32_CHECKED, var_store, LowLevel::NumToFloatChecked)),
-                Symbol::NUM_TO_F64_CHECKED => Some(to_num_checked(Symbol::NUM_TO_F64_CHECKED, var_store, LowLevel::NumToFloatChecked)),
+                Symbol::NUM_TO_F32_CHECKED => Some(to_float_checked(Symbol::NUM_TO_F32_CHECKED, var_store)),
+                Symbol::NUM_TO_F64_CHECKED => Some(to_float_checked(Symbol::NUM_TO_F64_CHECKED, var_store)),
 
                 Symbol::NUM_IS_ZERO => Some(to_num_is_zero(Symbol::NUM_IS_ZERO, var_store)),
 
@@ -76,6 +76,8 @@ macro_rules! map_symbol_to_lowlevel_and_arity {
 
                 // these are implemented explicitly in for_symbol because they are polymorphic
                 LowLevel::NumIntCast => unreachable!(),
+                LowLevel::NumToI128 => unreachable!(),
+                LowLevel::NumToU128 => unreachable!(),
                 LowLevel::NumToFloatCast => unreachable!(),
                 LowLevel::NumToIntChecked => unreachable!(),
                 LowLevel::NumToFloatChecked => unreachable!(),
@@ -117,6 +119,9 @@ map_symbol_to_lowlevel_and_arity! {
     StrStartsWith; STR_STARTS_WITH; 2,
     StrEndsWith; STR_ENDS_WITH; 2,
     StrSplitOn; STR_SPLIT_ON; 2,
+    StrSplitOnAny; STR_SPLIT_ON_ANY; 2,
+    StrSplitFirst; STR_SPLIT_FIRST_LOWLEVEL; 2,
+    StrSplitLast; STR_SPLIT_LAST_LOWLEVEL; 2,
     StrCountUtf8Bytes; STR_COUNT_UTF8_BYTES; 1,
     StrFromUtf8; STR_FROM_UTF8_LOWLEVEL; 1,
     StrFromUtf8Lossy; STR_FROM_UTF8_LOSSY; 1,
@@ -134,6 +139,7 @@ map_symbol_to_lowlevel_and_arity! {
     StrWithAsciiLowercased; STR_WITH_ASCII_LOWERCASED; 1,
     StrWithAsciiUppercased; STR_WITH_ASCII_UPPERCASED; 1,
     StrCaselessAsciiEquals; STR_CASELESS_ASCII_EQUALS; 2,
+    StrCompare; STR_COMPARE; 2,
 
     ListLenUsize; LIST_LEN_USIZE; 1,
     ListLenU64; LIST_LEN_U64; 1,
@@ -153,6 +159,8 @@ map_symbol_to_lowlevel_and_arity! {
     ListGetCapacity; LIST_CAPACITY; 1,
     ListReleaseExcessCapacity; LIST_RELEASE_EXCESS_CAPACITY; 1,
     ListConcatUtf8; LIST_CONCAT_UTF8; 2,
+    ListRange; LIST_RANGE_LOWLEVEL; 2,
+    ListRangeWithStep; LIST_RANGE_WITH_STEP_LOWLEVEL; 3,
 
     ListGetUnsafe; DICT_LIST_GET_UNSAFE; 2,
 
@@ -167,6 +175,7 @@ map_symbol_to_lowlevel_and_arity! {
     NumMul; NUM_MUL; 2,
     NumMulWrap; NUM_MUL_WRAP; 2,
     NumMulSaturated; NUM_MUL_SATURATED; 2,
+    NumMulAdd; NUM_MUL_ADD; 3,
     NumMulChecked; NUM_MUL_CHECKED_LOWLEVEL; 2,
     NumGt; NUM_GT; 2,
     NumGte; NUM_GTE; 2,
@@ -175,8 +184,10 @@ map_symbol_to_lowlevel_and_arity! {
     NumCompare; NUM_COMPARE; 2,
     NumDivFrac; NUM_DIV_FRAC; 2,
     NumDivTruncUnchecked; NUM_DIV_TRUNC_UNCHECKED; 2,
+    NumDivTruncChecked; NUM_DIV_TRUNC_CHECKED_LOWLEVEL; 2,
     NumDivCeilUnchecked; NUM_DIV_CEIL; 2,
     NumRemUnchecked; NUM_REM_UNCHECKED; 2,
+    NumRemChecked; NUM_REM_CHECKED_LOWLEVEL; 2,
     NumIsMultipleOf; NUM_IS_MULTIPLE_OF; 2,
     NumAbs; NUM_ABS; 1,
     NumNeg; NUM_NEG; 1,
@@ -193,10 +204,18 @@ map_symbol_to_lowlevel_and_arity! {
     NumPow; NUM_POW; 2,
     NumCeiling; NUM_CEILING; 1,
     NumPowInt; NUM_POW_INT; 2,
+    NumPowIntChecked; NUM_POW_INT_CHECKED_LOWLEVEL; 2,
     NumFloor; NUM_FLOOR; 1,
     NumAtan; NUM_ATAN; 1,
+    NumAtan2; NUM_ATAN2; 2,
     NumAcos; NUM_ACOS; 1,
     NumAsin; NUM_ASIN; 1,
+    NumSinh; NUM_SINH; 1,
+    NumCosh; NUM_COSH; 1,
+    NumTanh; NUM_TANH; 1,
+    NumAsinh; NUM_ASINH; 1,
+    NumAcosh; NUM_ACOSH; 1,
+    NumAtanh; NUM_ATANH; 1,
     NumBitwiseAnd; NUM_BITWISE_AND; 2,
     NumBitwiseXor; NUM_BITWISE_XOR; 2,
     NumBitwiseOr; NUM_BITWISE_OR; 2,
@@ -204,6 +223,7 @@ map_symbol_to_lowlevel_and_arity! {
     NumShiftRightBy; NUM_SHIFT_RIGHT; 2,
     NumShiftRightZfBy; NUM_SHIFT_RIGHT_ZERO_FILL; 2,
     NumToStr; NUM_TO_STR; 1,
+    NumToStrWithRadix; NUM_TO_STR_WITH_RADIX_LOWLEVEL; 2,
     NumCountLeadingZeroBits; NUM_COUNT_LEADING_ZERO_BITS; 1,
     NumCountTrailingZeroBits; NUM_COUNT_TRAILING_ZERO_BITS; 1,
     NumCountOneBits; NUM_COUNT_ONE_BITS; 1,
@@ -571,6 +591,86 @@ fn to_num_checked(symbol: Symbol, var_store: &mut VarStore, lowlevel: LowLevel)
     )
 }
 
+fn to_float_checked(symbol: Symbol, var_store: &mut VarStore) -> Def {
+    let bool_var = var_store.fresh();
+    let num_var_1 = var_store.fresh();
+    let num_var_2 = var_store.fresh();
+    let ret_var = var_store.fresh();
+    let record_var = var_store.fresh();
+
+    // let arg_2 = RunLowLevel NumToFloatChecked arg_1
+    // if arg_2.c then
+    //   Err OutOfBounds
+    // else if arg_2.b then
+    //   Err (Inexact arg_2.a)
+    // else
+    //   Ok arg_2.a
+    //
+    // The lowlevel return value looks like { converted_val: F, inexact: bool, out_of_bounds: bool },
+    // and codegen will sort by alignment and then by name, hence "a", "b", and "c".
+
+    let access = |field: &str, field_var: Variable, var_store: &mut VarStore| RecordAccess {
+        record_var,
+        ext_var: var_store.fresh(),
+        field: field.into(),
+        field_var,
+        loc_expr: Box::new(no_region(Var(Symbol::ARG_2, var_store.fresh()))),
+    };
+
+    let out_of_bounds = access("c", var_store.fresh(), var_store);
+    let inexact = access("b", var_store.fresh(), var_store);
+    let rounded = access("a", num_var_2, var_store);
+    let converted = access("a", num_var_2, var_store);
+
+    let cont = If {
+        branch_var: ret_var,
+        cond_var: bool_var,
+        branches: vec![
+            (
+                no_region(out_of_bounds),
+                no_region(tag(
+                    "Err",
+                    vec![tag("OutOfBounds", Vec::new(), var_store)],
+                    var_store,
+                )),
+            ),
+            (
+                no_region(inexact),
+                no_region(tag(
+                    "Err",
+                    vec![tag("Inexact", vec![rounded], var_store)],
+                    var_store,
+                )),
+            ),
+        ],
+        final_else: Box::new(no_region(tag("Ok", vec![converted], var_store))),
+    };
+
+    // arg_2 = RunLowLevel NumToFloatChecked arg_1
+    let def = crate::def::Def {
+        loc_pattern: no_region(Pattern::Identifier(Symbol::ARG_2)),
+        loc_expr: no_region(RunLowLevel {
+            op: LowLevel::NumToFloatChecked,
+            args: vec![(num_var_1, Var(Symbol::ARG_1, var_store.fresh()))],
+            ret_var: record_var,
+        }),
+        expr_var: record_var,
+        pattern_vars: SendMap::default(),
+        annotation: None,
+        kind: crate::def::DefKind::Let,
+    };
+
+    let body = LetNonRec(Box::new(def), Box::new(no_region(cont)));
+
+    defn(
+        symbol,
+        vec![(num_var_1, Symbol::ARG_1)],
+        var_store,
+        body,
+        ret_var,
+    )
+}
+
 fn to_num_is_zero(symbol: Symbol, var_store: &mut VarStore) -> Def {
     let bool_var = var_store.fresh();
     let num_var = var_store.fresh();
@@ -4714,7 +4714,7 @@ impl<
         } else {
             match (source, target) {
                 // -- CASTING UP --
-                (I8 | U8, U16 | U32 | U64) => {
+                (U8, U16 | U32 | U64) => {
                     // zero  out the register
                     ASM::xor_reg64_reg64_reg64(buf, dst_reg, dst_reg, dst_reg);
 
@@ -4735,11 +4735,15 @@ impl<
                     // move the 32-bit integer
                     ASM::mov_reg_reg(buf, RegisterWidth::W32, dst_reg, src_reg);
                 }
-                (I8, I16 | I32 | I64) => {
+                // Signed numbers are sign-extended even when the target is unsigned,
+                // so -1 becomes the target's max value.
+                (I8, I16 | I32 | I64 | U16 | U32 | U64) => {
                     ASM::movsx_reg_reg(buf, RegisterWidth::W8, dst_reg, src_reg)
                 }
-                (I16, I32 | I64) => ASM::movsx_reg_reg(buf, RegisterWidth::W16, dst_reg, src_reg),
-                (I32, I64) => ASM::movsx_reg_reg(buf, RegisterWidth::W32, dst_reg, src_reg),
+                (I16, I32 | I64 | U32 | U64) => {
+                    ASM::movsx_reg_reg(buf, RegisterWidth::W16, dst_reg, src_reg)
+                }
+                (I32, I64 | U64) => ASM::movsx_reg_reg(buf, RegisterWidth::W32, dst_reg, src_reg),
                 // -- CASTING DOWN --
                 (U64 | I64, I32 | U32) => {
                     // move as a 32-bit integer (leaving any other bits behind)
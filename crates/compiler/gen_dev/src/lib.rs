@@ -1081,6 +1081,66 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::NumSinh => {
+                let intrinsic = match arg_layouts[0] {
+                    Layout::F64 => &bitcode::NUM_SINH[FloatWidth::F64],
+                    Layout::F32 => &bitcode::NUM_SINH[FloatWidth::F32],
+                    Layout::DEC => bitcode::DEC_SINH,
+                    _ => unreachable!("invalid layout for sinh"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumCosh => {
+                let intrinsic = match arg_layouts[0] {
+                    Layout::F64 => &bitcode::NUM_COSH[FloatWidth::F64],
+                    Layout::F32 => &bitcode::NUM_COSH[FloatWidth::F32],
+                    Layout::DEC => bitcode::DEC_COSH,
+                    _ => unreachable!("invalid layout for cosh"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumTanh => {
+                let intrinsic = match arg_layouts[0] {
+                    Layout::F64 => &bitcode::NUM_TANH[FloatWidth::F64],
+                    Layout::F32 => &bitcode::NUM_TANH[FloatWidth::F32],
+                    Layout::DEC => bitcode::DEC_TANH,
+                    _ => unreachable!("invalid layout for tanh"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumAsinh => {
+                let intrinsic = match arg_layouts[0] {
+                    Layout::F64 => &bitcode::NUM_ASINH[FloatWidth::F64],
+                    Layout::F32 => &bitcode::NUM_ASINH[FloatWidth::F32],
+                    Layout::DEC => bitcode::DEC_ASINH,
+                    _ => unreachable!("invalid layout for asinh"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumAcosh => {
+                let intrinsic = match arg_layouts[0] {
+                    Layout::F64 => &bitcode::NUM_ACOSH[FloatWidth::F64],
+                    Layout::F32 => &bitcode::NUM_ACOSH[FloatWidth::F32],
+                    Layout::DEC => bitcode::DEC_ACOSH,
+                    _ => unreachable!("invalid layout for acosh"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
+            LowLevel::NumAtanh => {
+                let intrinsic = match arg_layouts[0] {
+                    Layout::F64 => &bitcode::NUM_ATANH[FloatWidth::F64],
+                    Layout::F32 => &bitcode::NUM_ATANH[FloatWidth::F32],
+                    Layout::DEC => bitcode::DEC_ATANH,
+                    _ => unreachable!("invalid layout for atanh"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::NumAtan => self.build_fn_call(
                 sym,
                 bitcode::NUM_ATAN[FloatWidth::F64].to_string(),
@@ -1117,6 +1177,66 @@ trait Backend<'a> {
                 self.build_num_div_ceil(sym, &args[0], &args[1], ret_layout)
             }
             LowLevel::NumRemUnchecked => self.build_num_rem(sym, &args[0], &args[1], ret_layout),
+            LowLevel::NumDivTruncChecked => {
+                let repr = self.interner().get_repr(arg_layouts[0]);
+                let LayoutRepr::Builtin(Builtin::Int(int_width)) = repr else {
+                    unreachable!("invalid layout for NumDivTruncChecked")
+                };
+
+                self.build_fn_call(
+                    sym,
+                    bitcode::NUM_DIV_TRUNC_CHECKED[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                )
+            }
+            LowLevel::NumRemChecked => {
+                let repr = self.interner().get_repr(arg_layouts[0]);
+                let LayoutRepr::Builtin(Builtin::Int(int_width)) = repr else {
+                    unreachable!("invalid layout for NumRemChecked")
+                };
+
+                self.build_fn_call(
+                    sym,
+                    bitcode::NUM_REM_CHECKED[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                )
+            }
+            LowLevel::NumToFloatChecked => {
+                let float_width = match self.interner().get_repr(*ret_layout) {
+                    LayoutRepr::Struct(&[value_layout, ..]) => {
+                        match self.interner().get_repr(value_layout) {
+                            LayoutRepr::Builtin(Builtin::Float(float_width)) => float_width,
+                            _ => unreachable!("invalid return layout for NumToFloatChecked"),
+                        }
+                    }
+                    _ => unreachable!("invalid return layout for NumToFloatChecked"),
+                };
+
+                let intrinsic = match (self.interner().get_repr(arg_layouts[0]), float_width) {
+                    (LayoutRepr::Builtin(Builtin::Int(w)), FloatWidth::F32) => {
+                        &bitcode::NUM_TO_F32_CHECKED_INT[w]
+                    }
+                    (LayoutRepr::Builtin(Builtin::Int(w)), FloatWidth::F64) => {
+                        &bitcode::NUM_TO_F64_CHECKED_INT[w]
+                    }
+                    (LayoutRepr::Builtin(Builtin::Float(w)), FloatWidth::F32) => {
+                        &bitcode::NUM_TO_F32_CHECKED_FLOAT[w]
+                    }
+                    (LayoutRepr::Builtin(Builtin::Float(w)), FloatWidth::F64) => {
+                        &bitcode::NUM_TO_F64_CHECKED_FLOAT[w]
+                    }
+                    (LayoutRepr::Builtin(Builtin::Decimal), _) => {
+                        &bitcode::DEC_TO_FLOAT_CHECKED[float_width]
+                    }
+                    _ => unreachable!("invalid layout for NumToFloatChecked"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::NumNeg => {
                 debug_assert_eq!(
                     1,
@@ -1143,6 +1263,20 @@ trait Backend<'a> {
                     ret_layout,
                 )
             }
+            LowLevel::NumPowIntChecked => {
+                let repr = self.interner().get_repr(arg_layouts[0]);
+                let LayoutRepr::Builtin(Builtin::Int(int_width)) = repr else {
+                    unreachable!("invalid layout for NumPowIntChecked")
+                };
+
+                self.build_fn_call(
+                    sym,
+                    bitcode::NUM_POW_INT_CHECKED[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                )
+            }
             LowLevel::NumPow => {
                 let intrinsic = match self.interner().get_repr(arg_layouts[0]) {
                     LayoutRepr::Builtin(Builtin::Float(float_width)) => {
@@ -1154,6 +1288,17 @@ trait Backend<'a> {
 
                 self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
             }
+            LowLevel::NumAtan2 => {
+                let intrinsic = match self.interner().get_repr(arg_layouts[0]) {
+                    LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+                        &bitcode::NUM_ATAN2[float_width]
+                    }
+                    LayoutRepr::DEC => bitcode::DEC_ATAN2,
+                    _ => unreachable!("invalid layout for NumAtan2"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
 
             LowLevel::NumRound => {
                 let repr = self.interner().get_repr(*ret_layout);
@@ -1480,6 +1625,16 @@ trait Backend<'a> {
 
                 self.build_num_sqrt(*sym, args[0], float_width);
             }
+            LowLevel::NumMulAdd => {
+                let intrinsic = match arg_layouts[0] {
+                    Layout::F64 => &bitcode::NUM_MUL_ADD[FloatWidth::F64],
+                    Layout::F32 => &bitcode::NUM_MUL_ADD[FloatWidth::F32],
+                    Layout::DEC => bitcode::DEC_MUL_ADD,
+                    _ => unreachable!("invalid layout for mul_add"),
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::NumSin => {
                 let intrinsic = match arg_layouts[0] {
                     Layout::F64 => &bitcode::NUM_SIN[FloatWidth::F64],
@@ -1518,6 +1673,13 @@ trait Backend<'a> {
                 );
                 self.build_list_len_u64(sym, &args[0])
             }
+            LowLevel::ListGetCapacity => self.build_fn_call(
+                sym,
+                bitcode::LIST_CAPACITY.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::ListLenUsize => {
                 debug_assert_eq!(
                     1,
@@ -1614,6 +1776,13 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrSplitOnAny => self.build_fn_call(
+                sym,
+                bitcode::STR_SPLIT_ON_ANY.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::StrStartsWith => self.build_fn_call(
                 sym,
                 bitcode::STR_STARTS_WITH.to_string(),
@@ -1740,6 +1909,27 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrCompare => self.build_fn_call(
+                sym,
+                bitcode::STR_COMPARE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrSplitFirst => self.build_fn_call(
+                sym,
+                bitcode::STR_SPLIT_FIRST.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrSplitLast => self.build_fn_call(
+                sym,
+                bitcode::STR_SPLIT_LAST.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::StrToNum => {
                 let number_layout = match self.interner().get_repr(*ret_layout) {
                     LayoutRepr::Struct(field_layouts) => field_layouts[0], // TODO: why is it sometimes a struct?
@@ -1765,6 +1955,19 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::ListRange | LowLevel::ListRangeWithStep => {
+                let repr = self.interner().get_repr(arg_layouts[0]);
+                let LayoutRepr::Builtin(Builtin::Int(int_width)) = repr else {
+                    unreachable!("invalid layout for {:?}", lowlevel)
+                };
+
+                let intrinsic = match lowlevel {
+                    LowLevel::ListRange => &bitcode::LIST_RANGE[int_width],
+                    _ => &bitcode::LIST_RANGE_WITH_STEP[int_width],
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::PtrCast => {
                 debug_assert_eq!(
                     1,
@@ -1880,6 +2083,20 @@ trait Backend<'a> {
 
                 self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
             }
+            LowLevel::NumToStrWithRadix => {
+                let repr = self.interner().get_repr(arg_layouts[0]);
+                let LayoutRepr::Builtin(Builtin::Int(int_width)) = repr else {
+                    unreachable!("invalid layout for NumToStrWithRadix")
+                };
+
+                self.build_fn_call(
+                    sym,
+                    bitcode::STR_FROM_INT_WITH_RADIX[int_width].to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                )
+            }
             LowLevel::StrIsEmpty => {
                 let intrinsic = bitcode::STR_IS_EMPTY.to_string();
                 self.build_fn_call(sym, intrinsic, args, arg_layouts, ret_layout);
@@ -1897,6 +2114,19 @@ trait Backend<'a> {
 
                 self.build_num_int_cast(sym, &args[0], source_width, target_width)
             }
+            LowLevel::NumToI128 | LowLevel::NumToU128 => {
+                let source_width = match self.interner().get_repr(arg_layouts[0]) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => width,
+                    _ => unreachable!(),
+                };
+
+                let intrinsic = match lowlevel {
+                    LowLevel::NumToI128 => &bitcode::NUM_TO_I128[source_width],
+                    _ => &bitcode::NUM_TO_U128[source_width],
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
             LowLevel::NumIsMultipleOf => {
                 let int_width = arg_layouts[0].try_int_width().unwrap();
                 let intrinsic = bitcode::NUM_IS_MULTIPLE_OF[int_width].to_string();
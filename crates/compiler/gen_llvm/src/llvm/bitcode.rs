@@ -1318,7 +1318,7 @@ pub(crate) fn call_bitcode_fn_returning_record<'ctx>(
     layout: InLayout<'_>,
     layout_interner: &STLayoutInterner<'_>,
     bitcode_return_type_name: &str,
-    arg: BasicValueEnum<'ctx>,
+    args: &[BasicValueEnum<'ctx>],
     fn_name: &str,
 ) -> BasicValueEnum<'ctx> {
     let zig_return_alloca;
@@ -1332,10 +1332,15 @@ pub(crate) fn call_bitcode_fn_returning_record<'ctx>(
             .unwrap();
         zig_return_alloca =
             create_entry_block_alloca(env, bitcode_return_type, "zig_return_alloca");
-        call_void_bitcode_fn(env, &[zig_return_alloca.into(), arg], fn_name);
+        let fixed_args: Vec<BasicValueEnum<'ctx>> = [zig_return_alloca.into()]
+            .iter()
+            .chain(args)
+            .copied()
+            .collect();
+        call_void_bitcode_fn(env, &fixed_args, fn_name);
     } else {
         // direct return
-        let zig_result = call_bitcode_fn(env, &[arg], fn_name);
+        let zig_result = call_bitcode_fn(env, args, fn_name);
         zig_return_alloca =
             create_entry_block_alloca(env, zig_result.get_type(), "zig_return_alloca");
         env.builder.new_build_store(zig_return_alloca, zig_result);
@@ -66,6 +66,39 @@ pub(crate) fn call_str_from_utf_bitcode_fn<'a, 'ctx>(
     )
 }
 
+/// Str.split_on_any : Str, List Str -> List Str
+pub(crate) fn str_split_on_any<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    string: BasicValueEnum<'ctx>,
+    delimiters: BasicValueEnum<'ctx>,
+) -> BasicValueEnum<'ctx> {
+    let mut other_arguments: Vec<BasicValueEnum<'ctx>> = Vec::with_capacity_in(2, env.arena);
+
+    // The string goes through call_str_bitcode_fn, but the list has to be passed by hand.
+    use roc_target::Architecture::*;
+    match env.target.architecture() {
+        Aarch32 | X86_32 => {
+            let (a, b) = pass_list_or_string_to_zig_32bit(env, delimiters.into_struct_value());
+            other_arguments.push(a.into());
+            other_arguments.push(b.into());
+        }
+        Aarch64 | X86_64 => {
+            other_arguments.push(pass_list_to_zig_64bit(env, delimiters).into());
+        }
+        Wasm32 => {
+            other_arguments.push(pass_list_to_zig_wasm(env, delimiters).into());
+        }
+    };
+
+    call_str_bitcode_fn(
+        env,
+        &[string],
+        &other_arguments,
+        BitcodeReturns::List,
+        bitcode::STR_SPLIT_ON_ANY,
+    )
+}
+
 /// Dec.to_str : Dec -> Str
 
 /// Str.equal : Str, Str -> Bool
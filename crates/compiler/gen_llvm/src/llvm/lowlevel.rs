@@ -39,7 +39,7 @@ use crate::llvm::{
         list_prepend, list_release_excess_capacity, list_replace_unsafe, list_reserve,
         list_sort_with, list_sublist, list_swap, list_with_capacity, pass_update_mode,
     },
-    build_str::call_str_from_utf_bitcode_fn,
+    build_str::{call_str_from_utf_bitcode_fn, str_split_on_any},
     compare::{generic_eq, generic_neq},
     convert::{
         self, argument_type_from_layout, basic_type_from_layout, zig_num_parse_result_type,
@@ -452,6 +452,12 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 bitcode::STR_SPLIT_ON,
             )
         }
+        StrSplitOnAny => {
+            // Str.split_on_any : Str, List Str -> List Str
+            arguments!(string, delimiters);
+
+            str_split_on_any(env, string, delimiters)
+        }
         StrIsEmpty => {
             // Str.isEmpty : Str -> Str
             arguments!(string);
@@ -648,6 +654,42 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 bitcode::STR_CASELESS_ASCII_EQUALS,
             )
         }
+        StrCompare => {
+            // Str.compare : Str, Str -> [LT, EQ, GT]
+            arguments!(string1, string2);
+
+            call_str_bitcode_fn(
+                env,
+                &[string1, string2],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_COMPARE,
+            )
+        }
+        StrSplitFirst => {
+            // Str.split_first_lowlevel : Str, Str -> U64
+            arguments!(string, delimiter);
+
+            call_str_bitcode_fn(
+                env,
+                &[string, delimiter],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_SPLIT_FIRST,
+            )
+        }
+        StrSplitLast => {
+            // Str.split_last_lowlevel : Str, Str -> U64
+            arguments!(string, delimiter);
+
+            call_str_bitcode_fn(
+                env,
+                &[string, delimiter],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_SPLIT_LAST,
+            )
+        }
         ListConcat => {
             debug_assert_eq!(args.len(), 2);
 
@@ -862,6 +904,38 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 }
             }
         }
+        ListRange => {
+            // List.range_lowlevel : Int a, Int a -> List (Int a)
+            arguments_with_layouts!((start, start_layout), (end, _end_layout));
+
+            let int_width = intwidth_from_layout(start_layout);
+
+            call_list_bitcode_fn(
+                env,
+                &[],
+                &[start, end],
+                BitcodeReturns::List,
+                &bitcode::LIST_RANGE[int_width],
+            )
+        }
+        ListRangeWithStep => {
+            // List.range_with_step_lowlevel : Int a, Int a, Int a -> List (Int a)
+            arguments_with_layouts!(
+                (start, start_layout),
+                (end, _end_layout),
+                (step, _step_layout)
+            );
+
+            let int_width = intwidth_from_layout(start_layout);
+
+            call_list_bitcode_fn(
+                env,
+                &[],
+                &[start, end, step],
+                BitcodeReturns::List,
+                &bitcode::LIST_RANGE_WITH_STEP[int_width],
+            )
+        }
         ListConcatUtf8 => {
             // List.concatUtf8: List U8, Str -> List U8
             arguments!(list, string);
@@ -919,6 +993,21 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 _ => unreachable!(),
             }
         }
+        NumToStrWithRadix => {
+            // Num.to_str_with_radix_lowlevel : Int a, U8 -> Str
+            arguments_with_layouts!((int, int_layout), (radix, _radix_layout));
+
+            match layout_interner.get_repr(int_layout) {
+                LayoutRepr::Builtin(Builtin::Int(int_width)) => call_str_bitcode_fn(
+                    env,
+                    &[],
+                    &[int, radix],
+                    BitcodeReturns::Str,
+                    &bitcode::STR_FROM_INT_WITH_RADIX[int_width],
+                ),
+                other => unreachable!("NumToStrWithRadix is not defined for {:?}", other),
+            }
+        }
         NumAbs
         | NumNeg
         | NumRound
@@ -936,6 +1025,12 @@ pub(crate) fn run_low_level<'a, 'ctx>(
         | NumAtan
         | NumAcos
         | NumAsin
+        | NumSinh
+        | NumCosh
+        | NumTanh
+        | NumAsinh
+        | NumAcosh
+        | NumAtanh
         | NumToIntChecked
         | NumCountLeadingZeroBits
         | NumCountTrailingZeroBits
@@ -984,6 +1079,26 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 }
             }
         }
+        NumMulAdd => {
+            // Num.mul_add : Frac a, Frac a, Frac a -> Frac a
+            arguments!(a, b, c);
+
+            match layout_interner.get_repr(layout) {
+                LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+                    call_bitcode_fn(env, &[a, b, c], &bitcode::NUM_MUL_ADD[float_width])
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    let product =
+                        build_dec_binop(env, layout_interner, parent, a, b, layout, NumMul);
+
+                    build_dec_binop(env, layout_interner, parent, product, c, layout, NumAdd)
+                }
+                _ => unreachable!(
+                    "Compiler bug: tried to run NumMulAdd on layout {:?}",
+                    layout
+                ),
+            }
+        }
         NumCompare => {
             arguments_with_layouts!((lhs_arg, lhs_layout), (rhs_arg, rhs_layout));
 
@@ -1087,8 +1202,9 @@ pub(crate) fn run_low_level<'a, 'ctx>(
 
         NumAdd | NumSub | NumMul | NumLt | NumLte | NumGt | NumGte | NumRemUnchecked
         | NumIsMultipleOf | NumAddWrap | NumAddChecked | NumAddSaturated | NumDivFrac
-        | NumDivTruncUnchecked | NumDivCeilUnchecked | NumPow | NumPowInt | NumSubWrap
-        | NumSubChecked | NumSubSaturated | NumMulWrap | NumMulSaturated | NumMulChecked => {
+        | NumDivTruncUnchecked | NumDivTruncChecked | NumDivCeilUnchecked | NumRemChecked
+        | NumPow | NumPowInt | NumSubWrap | NumSubChecked | NumSubSaturated | NumMulWrap
+        | NumMulSaturated | NumMulChecked | NumAtan2 => {
             arguments_with_layouts!((lhs_arg, lhs_layout), (rhs_arg, rhs_layout));
 
             build_num_binop(
@@ -1158,25 +1274,46 @@ pub(crate) fn run_low_level<'a, 'ctx>(
             let from_signed = intwidth_from_layout(arg_layout).is_signed();
             let extend = intwidth_from_layout(layout).stack_size()
                 > intwidth_from_layout(arg_layout).stack_size();
+            // Extend based on the source's signedness, the same way NumToU128 does, so negative
+            // numbers going to a wider unsigned type wrap around (-1 becomes the max) instead of
+            // being zero-extended.
             //Examples given with sizes of 32, 16, and 8
-            let result = match (from_signed, to_signed, extend) {
-                //I16 -> I32
-                (true, true, true) => {
+            let result = match (from_signed, extend) {
+                //I16 -> X32
+                (true, true) => {
                     env.builder
                         .build_int_s_extend(arg.into_int_value(), to, "inc_cast")
                 }
                 //U16 -> X32
-                (false, _, true) => {
+                (false, true) => {
                     env.builder
                         .build_int_z_extend(arg.into_int_value(), to, "inc_cast")
-                },
-                //I16 -> U32
-                (true,false,true)
-                //Any case where it is not an extension, also perhaps warn here?
-                | (_, _, false) => {
-                    Ok(env.builder
-                    .new_build_int_cast_sign_flag(arg.into_int_value(), to, to_signed, "inc_cast"))
                 }
+                //Any case where it is not an extension, also perhaps warn here?
+                (_, false) => Ok(env.builder.new_build_int_cast_sign_flag(
+                    arg.into_int_value(),
+                    to,
+                    to_signed,
+                    "inc_cast",
+                )),
+            };
+
+            let Ok(value) = result else { todo!() };
+            value.into()
+        }
+        NumToI128 | NumToU128 => {
+            arguments_with_layouts!((arg, arg_layout));
+
+            // Extend based on the source's signedness, so negative numbers going to a U128
+            // wrap around (-1 becomes the max U128) instead of being zero-extended.
+            let to = env.context.i128_type();
+            let arg = arg.into_int_value();
+            let result = if arg.get_type().get_bit_width() == 128 {
+                Ok(arg)
+            } else if intwidth_from_layout(arg_layout).is_signed() {
+                env.builder.build_int_s_extend(arg, to, "extend_to_128")
+            } else {
+                env.builder.build_int_z_extend(arg, to, "extend_to_128")
             };
 
             let Ok(value) = result else { todo!() };
@@ -1228,9 +1365,181 @@ pub(crate) fn run_low_level<'a, 'ctx>(
             }
         }
         NumToFloatChecked => {
-            // NOTE: There's a NumToIntChecked implementation above,
-            // which could be useful to look at when implementing this.
-            todo!("implement checked float conversion");
+            // return_layout : Result F [Inexact F, OutOfBounds]* ~ { result: F, inexact: bool, out_of_bounds: bool }
+            arguments_with_layouts!((arg, arg_layout));
+
+            let float_width = match layout_interner.get_repr(layout) {
+                LayoutRepr::Struct(&[value_layout, ..]) => {
+                    match layout_interner.get_repr(value_layout) {
+                        LayoutRepr::Builtin(Builtin::Float(float_width)) => float_width,
+                        other => internal_error!("Tried to do a checked float cast to {:?}", other),
+                    }
+                }
+                other => internal_error!("Tried to do a checked float cast to {:?}", other),
+            };
+
+            let return_type =
+                basic_type_from_layout(env, layout_interner, layout_interner.get_repr(layout))
+                    .into_struct_type();
+            let float_type = return_type
+                .get_field_type_at_index(0)
+                .unwrap()
+                .into_float_type();
+            let bd = env.builder;
+
+            let is_infinite = |value: FloatValue<'ctx>, name: &str| {
+                use inkwell::FloatPredicate;
+
+                let float_type = value.get_type();
+
+                bd.new_build_or(
+                    bd.new_build_float_compare(
+                        FloatPredicate::OEQ,
+                        value,
+                        float_type.const_float(f64::INFINITY),
+                        "is_pos_infinity",
+                    ),
+                    bd.new_build_float_compare(
+                        FloatPredicate::OEQ,
+                        value,
+                        float_type.const_float(f64::NEG_INFINITY),
+                        "is_neg_infinity",
+                    ),
+                    name,
+                )
+            };
+
+            let (converted, exact, out_of_bounds) = match layout_interner.get_repr(arg_layout) {
+                LayoutRepr::Builtin(Builtin::Int(width)) => {
+                    use inkwell::FloatPredicate;
+
+                    let int_val = arg.into_int_value();
+                    let int_type = int_val.get_type();
+                    let signed = width.is_signed();
+
+                    let converted = if signed {
+                        bd.new_build_signed_int_to_float(int_val, float_type, "signed_int_to_float")
+                    } else {
+                        bd.new_build_unsigned_int_to_float(
+                            int_val,
+                            float_type,
+                            "unsigned_int_to_float",
+                        )
+                    };
+
+                    // Only a U128 going to an F32 can overflow.
+                    let out_of_bounds = is_infinite(converted, "out_of_bounds");
+
+                    // Converting an out-of-range float back to an int is poison, so check
+                    // the range first. The bounds are powers of two, so they're exact
+                    // (or infinite, for a U128 going to an F32).
+                    let magnitude_bits = width.stack_size() * 8 - signed as u32;
+                    let upper = 2f64.powi(magnitude_bits as i32);
+                    let lower = if signed { -upper } else { 0.0 };
+
+                    let in_range = bd.new_build_and(
+                        bd.new_build_float_compare(
+                            FloatPredicate::OGE,
+                            converted,
+                            float_type.const_float(lower),
+                            "above_min",
+                        ),
+                        bd.new_build_float_compare(
+                            FloatPredicate::OLT,
+                            converted,
+                            float_type.const_float(upper),
+                            "below_max",
+                        ),
+                        "in_range",
+                    );
+                    let in_range_float = bd
+                        .new_build_select(in_range, converted, float_type.const_zero(), "clamped")
+                        .into_float_value();
+
+                    let round_trip = if signed {
+                        bd.build_float_to_signed_int(in_range_float, int_type, "round_trip")
+                    } else {
+                        bd.build_float_to_unsigned_int(in_range_float, int_type, "round_trip")
+                    }
+                    .unwrap();
+
+                    let same =
+                        bd.new_build_int_compare(IntPredicate::EQ, round_trip, int_val, "same");
+
+                    (
+                        converted,
+                        bd.new_build_and(in_range, same, "exact"),
+                        out_of_bounds,
+                    )
+                }
+                LayoutRepr::Builtin(Builtin::Float(_)) => {
+                    use inkwell::FloatPredicate;
+
+                    let float_val = arg.into_float_value();
+                    let converted =
+                        bd.new_build_float_cast(float_val, float_type, "cast_float_to_float");
+                    let round_trip =
+                        bd.new_build_float_cast(converted, float_val.get_type(), "round_trip");
+
+                    // Unordered, so that NaN (which converts to NaN) counts as exact.
+                    let exact = bd.new_build_float_compare(
+                        FloatPredicate::UEQ,
+                        round_trip,
+                        float_val,
+                        "exact",
+                    );
+
+                    // An F64 too big for an F32 becomes infinity, but infinity itself is fine.
+                    let out_of_bounds = bd.new_build_and(
+                        is_infinite(converted, "converted_infinite"),
+                        bd.new_build_not(is_infinite(float_val, "arg_infinite"), "arg_finite"),
+                        "out_of_bounds",
+                    );
+
+                    (converted, exact, out_of_bounds)
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    // A Dec is never too big for a float, but it usually has to round.
+                    let converted = dec_unary_op(env, &bitcode::DEC_TO_FLOAT[float_width], arg)
+                        .into_float_value();
+                    let inexact =
+                        dec_unary_op(env, &bitcode::DEC_TO_FLOAT_INEXACT[float_width], arg)
+                            .into_int_value();
+
+                    (
+                        converted,
+                        bd.new_build_not(inexact, "exact"),
+                        env.context.bool_type().const_zero(),
+                    )
+                }
+                other => {
+                    unreachable!("Tried to do a checked float cast from {:?}", other);
+                }
+            };
+
+            // Overflowing isn't also reported as rounding.
+            let inexact = bd.new_build_and(
+                bd.new_build_not(exact, "not_exact"),
+                bd.new_build_not(out_of_bounds, "in_bounds"),
+                "inexact",
+            );
+
+            let r = return_type.const_zero();
+            let r = bd
+                .build_insert_value(r, converted, 0, "converted_float")
+                .unwrap();
+            let r = bd.build_insert_value(r, inexact, 1, "inexact").unwrap();
+            let r = bd
+                .build_insert_value(r, out_of_bounds, 2, "out_of_bounds")
+                .unwrap();
+
+            use_roc_value(
+                env,
+                layout_interner,
+                layout_interner.get_repr(layout),
+                r.into_struct_value().into(),
+                "num_to_float_checked",
+            )
         }
         NumWithoutDecimalPoint | NumWithDecimalPoint => {
             // Dec uses an I128 under the hood, so no conversion is needed.
@@ -1245,7 +1554,7 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 layout,
                 layout_interner,
                 "num.F32Parts",
-                arg,
+                &[arg],
                 fn_name,
             )
         }
@@ -1257,10 +1566,27 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 layout,
                 layout_interner,
                 "num.F64Parts",
-                arg,
+                &[arg],
                 fn_name,
             )
         }
+        NumPowIntChecked => {
+            arguments_with_layouts!((base, base_layout), (exp, _exp_layout));
+
+            let int_width = match layout_interner.get_repr(base_layout) {
+                LayoutRepr::Builtin(Builtin::Int(int_width)) => int_width,
+                other => unreachable!("NumPowIntChecked is not defined for {:?}", other),
+            };
+
+            call_bitcode_fn_returning_record(
+                env,
+                layout,
+                layout_interner,
+                &format!("utils.WithOverflow({})", int_width.type_name()),
+                &[base, exp],
+                &bitcode::NUM_POW_INT_CHECKED[int_width],
+            )
+        }
         NumF32FromParts => {
             arguments!(arg);
             let fn_name = bitcode::NUM_F32_FROM_PARTS;
@@ -1695,6 +2021,51 @@ fn build_int_binop<'ctx>(
             &[lhs.into(), rhs.into()],
             &bitcode::NUM_DIV_CEIL[int_width],
         ),
+        NumDivTruncChecked | NumRemChecked => {
+            let int_type = lhs.get_type();
+
+            // divide by 1 instead of 0 so the instruction can't trap; the flag
+            // tells the caller to discard the result
+            let is_zero =
+                bd.new_build_int_compare(EQ, rhs, int_type.const_zero(), "divisor_is_zero");
+            let divisor = bd
+                .new_build_select(is_zero, int_type.const_int(1, false), rhs, "safe_divisor")
+                .into_int_value();
+
+            let result = match (op, int_width.is_signed()) {
+                (NumDivTruncChecked, true) => bd.new_build_int_signed_div(lhs, divisor, "div_int"),
+                (NumDivTruncChecked, false) => {
+                    bd.new_build_int_unsigned_div(lhs, divisor, "div_uint")
+                }
+                (_, true) => bd.new_build_int_signed_rem(lhs, divisor, "rem_int"),
+                (_, false) => bd.new_build_int_unsigned_rem(lhs, divisor, "rem_uint"),
+            };
+
+            let struct_type = env
+                .context
+                .struct_type(&[int_type.into(), env.context.bool_type().into()], false);
+
+            let struct_value = {
+                let v1 = struct_type.const_zero();
+                let v2 = bd.build_insert_value(v1, result, 0, "set_result").unwrap();
+                let v3 = bd
+                    .build_insert_value(v2, is_zero, 1, "set_divisor_is_zero")
+                    .unwrap();
+
+                v3.into_struct_value()
+            };
+
+            let layout = Layout::from_int_width(int_width);
+            let layout_repr = LayoutRepr::Struct(env.arena.alloc([layout, Layout::BOOL]));
+
+            use_roc_value(
+                env,
+                layout_interner,
+                layout_repr,
+                struct_value.into(),
+                "num_div_checked",
+            )
+        }
         NumBitwiseAnd => bd.new_build_and(lhs, rhs, "int_bitwise_and").into(),
         NumBitwiseXor => bd.new_build_xor(lhs, rhs, "int_bitwise_xor").into(),
         NumBitwiseOr => bd.new_build_or(lhs, rhs, "int_bitwise_or").into(),
@@ -1884,6 +2255,11 @@ fn build_float_binop<'ctx>(
             &[lhs.into(), rhs.into()],
             &bitcode::NUM_POW[float_width],
         ),
+        NumAtan2 => call_bitcode_fn(
+            env,
+            &[lhs.into(), rhs.into()],
+            &bitcode::NUM_ATAN2[float_width],
+        ),
         _ => {
             unreachable!("Unrecognized float binary operation: {:?}", op);
         }
@@ -2248,6 +2624,12 @@ fn build_dec_unary_op<'a, 'ctx>(
         NumNeg => dec_unary_op(env, bitcode::DEC_NEGATE, arg),
         NumAcos => dec_unary_op(env, bitcode::DEC_ACOS, arg),
         NumAsin => dec_unary_op(env, bitcode::DEC_ASIN, arg),
+        NumSinh => dec_unary_op(env, bitcode::DEC_SINH, arg),
+        NumCosh => dec_unary_op(env, bitcode::DEC_COSH, arg),
+        NumTanh => dec_unary_op(env, bitcode::DEC_TANH, arg),
+        NumAsinh => dec_unary_op(env, bitcode::DEC_ASINH, arg),
+        NumAcosh => dec_unary_op(env, bitcode::DEC_ACOSH, arg),
+        NumAtanh => dec_unary_op(env, bitcode::DEC_ATANH, arg),
         NumAtan => dec_unary_op(env, bitcode::DEC_ATAN, arg),
         NumCos => dec_unary_op(env, bitcode::DEC_COS, arg),
         NumSin => dec_unary_op(env, bitcode::DEC_SIN, arg),
@@ -2334,6 +2716,7 @@ fn build_dec_binop<'a, 'ctx>(
             &bitcode::NUM_GREATER_THAN_OR_EQUAL[IntWidth::I128],
         ),
         NumPow => dec_binary_op(env, bitcode::DEC_POW, lhs, rhs),
+        NumAtan2 => dec_binary_op(env, bitcode::DEC_ATAN2, lhs, rhs),
         _ => {
             unreachable!("Unrecognized dec binary operation: {:?}", op);
         }
@@ -2783,6 +3166,14 @@ fn build_float_unary_op<'a, 'ctx>(
         NumAcos => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_ACOS[float_width]),
         NumAsin => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_ASIN[float_width]),
 
+        // hyperbolic
+        NumSinh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_SINH[float_width]),
+        NumCosh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_COSH[float_width]),
+        NumTanh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_TANH[float_width]),
+        NumAsinh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_ASINH[float_width]),
+        NumAcosh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_ACOSH[float_width]),
+        NumAtanh => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_ATANH[float_width]),
+
         _ => {
             unreachable!("Unrecognized int unary operation: {:?}", op);
         }
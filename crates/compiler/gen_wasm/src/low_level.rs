@@ -201,6 +201,7 @@ impl<'a> LowLevelCall<'a> {
             StrStartsWith => self.load_args_and_call_zig(backend, bitcode::STR_STARTS_WITH),
             StrEndsWith => self.load_args_and_call_zig(backend, bitcode::STR_ENDS_WITH),
             StrSplitOn => self.load_args_and_call_zig(backend, bitcode::STR_SPLIT_ON),
+            StrSplitOnAny => self.load_args_and_call_zig(backend, bitcode::STR_SPLIT_ON_ANY),
             StrCountUtf8Bytes => {
                 self.load_args_and_call_zig(backend, bitcode::STR_COUNT_UTF8_BYTES)
             }
@@ -268,6 +269,9 @@ impl<'a> LowLevelCall<'a> {
             StrCaselessAsciiEquals => {
                 self.load_args_and_call_zig(backend, bitcode::STR_CASELESS_ASCII_EQUALS)
             }
+            StrCompare => self.load_args_and_call_zig(backend, bitcode::STR_COMPARE),
+            StrSplitFirst => self.load_args_and_call_zig(backend, bitcode::STR_SPLIT_FIRST),
+            StrSplitLast => self.load_args_and_call_zig(backend, bitcode::STR_SPLIT_LAST),
 
             // List
             ListLenU64 => {
@@ -606,6 +610,22 @@ impl<'a> LowLevelCall<'a> {
             }
             ListConcatUtf8 => self.load_args_and_call_zig(backend, bitcode::LIST_CONCAT_UTF8),
 
+            ListRange | ListRangeWithStep => {
+                // List.range_lowlevel : Int a, Int a -> List (Int a)
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                let width = match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => width,
+                    x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
+                };
+
+                let intrinsic = match self.lowlevel {
+                    ListRange => &bitcode::LIST_RANGE[width],
+                    _ => &bitcode::LIST_RANGE_WITH_STEP[width],
+                };
+
+                self.load_args_and_call_zig(backend, intrinsic)
+            }
+
             ListReserve => {
                 // List.reserve : List elem, U64 -> List elem
 
@@ -984,6 +1004,14 @@ impl<'a> LowLevelCall<'a> {
             },
 
             NumToStr => self.num_to_str(backend),
+            NumToStrWithRadix => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => self
+                        .load_args_and_call_zig(backend, &bitcode::STR_FROM_INT_WITH_RADIX[width]),
+                    x => internal_error!("NumToStrWithRadix is not defined for {:?}", x),
+                }
+            }
             NumAddChecked => {
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
                 match backend.layout_interner.get_repr(arg_layout) {
@@ -1424,6 +1452,15 @@ impl<'a> LowLevelCall<'a> {
                     x => todo!("{:?} for {:?}", self.lowlevel, x),
                 }
             }
+            NumDivTruncChecked => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => {
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_DIV_TRUNC_CHECKED[width])
+                    }
+                    x => internal_error!("NumDivTruncChecked is not defined for {:?}", x),
+                }
+            }
             NumDivCeilUnchecked => match self.ret_layout_raw {
                 LayoutRepr::Builtin(Builtin::Int(width)) => {
                     self.load_args_and_call_zig(backend, &bitcode::NUM_DIV_CEIL[width])
@@ -1439,6 +1476,15 @@ impl<'a> LowLevelCall<'a> {
                     _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
                 }
             }
+            NumRemChecked => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => {
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_REM_CHECKED[width])
+                    }
+                    x => internal_error!("NumRemChecked is not defined for {:?}", x),
+                }
+            }
             NumIsMultipleOf => {
                 // this builds the following construct
                 //    if (rhs != 0 && rhs != -1) {
@@ -1534,6 +1580,15 @@ impl<'a> LowLevelCall<'a> {
                         code_builder.end();
                         code_builder.get_local(tmp);
                     }
+                    I128 => {
+                        let intrinsic = if is_signed {
+                            &bitcode::NUM_IS_MULTIPLE_OF[IntWidth::I128]
+                        } else {
+                            &bitcode::NUM_IS_MULTIPLE_OF[IntWidth::U128]
+                        };
+
+                        self.load_args_and_call_zig(backend, intrinsic);
+                    }
 
                     _ => panic_ret_type(),
                 }
@@ -1636,6 +1691,15 @@ impl<'a> LowLevelCall<'a> {
                     _ => todo!("{:?} for {:?}", self.lowlevel, self.ret_layout),
                 }
             }
+            NumMulAdd => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_MUL_ADD[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_MUL_ADD);
+                }
+                _ => panic_ret_type(),
+            },
             NumSin => match self.ret_layout_raw {
                 LayoutRepr::Builtin(Builtin::Float(width)) => {
                     self.load_args_and_call_zig(backend, &bitcode::NUM_SIN[width]);
@@ -1843,6 +1907,15 @@ impl<'a> LowLevelCall<'a> {
                 }
                 _ => panic_ret_type(),
             },
+            NumPowIntChecked => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => {
+                        self.load_args_and_call_zig(backend, &bitcode::NUM_POW_INT_CHECKED[width])
+                    }
+                    x => internal_error!("NumPowIntChecked is not defined for {:?}", x),
+                }
+            }
 
             NumIsNan => num_is_nan(backend, self.arguments[0]),
             NumIsInfinite => num_is_infinite(backend, self.arguments[0]),
@@ -1857,6 +1930,15 @@ impl<'a> LowLevelCall<'a> {
                 }
                 _ => panic_ret_type(),
             },
+            NumAtan2 => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_ATAN2[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_ATAN2);
+                }
+                _ => panic_ret_type(),
+            },
             NumAcos => match self.ret_layout_raw {
                 LayoutRepr::Builtin(Builtin::Float(width)) => {
                     self.load_args_and_call_zig(backend, &bitcode::NUM_ACOS[width]);
@@ -1875,6 +1957,60 @@ impl<'a> LowLevelCall<'a> {
                 }
                 _ => panic_ret_type(),
             },
+            NumSinh => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_SINH[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_SINH);
+                }
+                _ => panic_ret_type(),
+            },
+            NumCosh => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_COSH[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_COSH);
+                }
+                _ => panic_ret_type(),
+            },
+            NumTanh => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_TANH[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_TANH);
+                }
+                _ => panic_ret_type(),
+            },
+            NumAsinh => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_ASINH[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_ASINH);
+                }
+                _ => panic_ret_type(),
+            },
+            NumAcosh => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_ACOSH[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_ACOSH);
+                }
+                _ => panic_ret_type(),
+            },
+            NumAtanh => match self.ret_layout_raw {
+                LayoutRepr::Builtin(Builtin::Float(width)) => {
+                    self.load_args_and_call_zig(backend, &bitcode::NUM_ATANH[width]);
+                }
+                LayoutRepr::Builtin(Builtin::Decimal) => {
+                    self.load_args_and_call_zig(backend, bitcode::DEC_ATANH);
+                }
+                _ => panic_ret_type(),
+            },
             NumBitwiseAnd => {
                 self.load_args(backend);
                 match CodeGenNumType::from(self.ret_layout) {
@@ -2076,6 +2212,20 @@ impl<'a> LowLevelCall<'a> {
                     _ => todo!("{:?}: {:?} -> {:?}", self.lowlevel, arg_type, ret_type),
                 }
             }
+            NumToI128 | NumToU128 => {
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+                let arg_width = match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(w)) => w,
+                    x => internal_error!("{:?} is not defined for {:?}", self.lowlevel, x),
+                };
+
+                let fns = match self.lowlevel {
+                    NumToI128 => &bitcode::NUM_TO_I128,
+                    _ => &bitcode::NUM_TO_U128,
+                };
+
+                self.load_args_and_call_zig(backend, &fns[arg_width])
+            }
             NumToFloatCast => {
                 self.load_args(backend);
                 let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
@@ -2165,7 +2315,50 @@ impl<'a> LowLevelCall<'a> {
                 }
             }
             NumToFloatChecked => {
-                todo!("implement toF32Checked and toF64Checked");
+                let arg_layout = backend.storage.symbol_layouts[&self.arguments[0]];
+
+                let ret_width = match self.ret_layout_raw {
+                    LayoutRepr::Struct(&[ret, ..]) => match backend.layout_interner.get_repr(ret) {
+                        LayoutRepr::Builtin(Builtin::Float(ret_width)) => ret_width,
+                        _ => internal_error!(
+                            "NumToFloatChecked is not defined for signature {:?} -> {:?}",
+                            arg_layout,
+                            self.ret_layout
+                        ),
+                    },
+                    _ => internal_error!(
+                        "NumToFloatChecked is not defined for signature {:?} -> {:?}",
+                        arg_layout,
+                        self.ret_layout
+                    ),
+                };
+
+                let (int_fns, float_fns) = match ret_width {
+                    FloatWidth::F32 => (
+                        &bitcode::NUM_TO_F32_CHECKED_INT,
+                        &bitcode::NUM_TO_F32_CHECKED_FLOAT,
+                    ),
+                    FloatWidth::F64 => (
+                        &bitcode::NUM_TO_F64_CHECKED_INT,
+                        &bitcode::NUM_TO_F64_CHECKED_FLOAT,
+                    ),
+                };
+
+                match backend.layout_interner.get_repr(arg_layout) {
+                    LayoutRepr::Builtin(Builtin::Int(arg_width)) => {
+                        self.load_args_and_call_zig(backend, &int_fns[arg_width])
+                    }
+                    LayoutRepr::Builtin(Builtin::Float(arg_width)) => {
+                        self.load_args_and_call_zig(backend, &float_fns[arg_width])
+                    }
+                    LayoutRepr::Builtin(Builtin::Decimal) => self
+                        .load_args_and_call_zig(backend, &bitcode::DEC_TO_FLOAT_CHECKED[ret_width]),
+                    _ => internal_error!(
+                        "NumToFloatChecked is not defined for signature {:?} -> {:?}",
+                        arg_layout,
+                        self.ret_layout
+                    ),
+                }
             }
             NumWithoutDecimalPoint => self.load_args_and_call_zig(backend, bitcode::DEC_TO_I128),
             NumWithDecimalPoint => self.load_args_and_call_zig(backend, bitcode::DEC_FROM_I128),
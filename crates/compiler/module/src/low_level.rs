@@ -11,6 +11,9 @@ pub enum LowLevel {
     StrStartsWith,
     StrEndsWith,
     StrSplitOn,
+    StrSplitOnAny,
+    StrSplitFirst,
+    StrSplitLast,
     StrCountUtf8Bytes,
     StrFromInt,
     StrFromUtf8,
@@ -30,6 +33,7 @@ pub enum LowLevel {
     StrWithAsciiLowercased,
     StrWithAsciiUppercased,
     StrCaselessAsciiEquals,
+    StrCompare,
     ListLenUsize,
     ListLenU64,
     ListWithCapacity,
@@ -48,6 +52,8 @@ pub enum LowLevel {
     ListIsUnique,
     ListClone,
     ListConcatUtf8,
+    ListRange,
+    ListRangeWithStep,
     ListIncref,
     ListDecref,
     NumAdd,
@@ -61,6 +67,7 @@ pub enum LowLevel {
     NumMul,
     NumMulWrap,
     NumMulSaturated,
+    NumMulAdd,
     NumMulChecked,
     NumGt,
     NumGte,
@@ -69,8 +76,10 @@ pub enum LowLevel {
     NumCompare,
     NumDivFrac,
     NumDivTruncUnchecked,
+    NumDivTruncChecked,
     NumDivCeilUnchecked,
     NumRemUnchecked,
+    NumRemChecked,
     NumIsMultipleOf,
     NumAbs,
     NumNeg,
@@ -84,13 +93,21 @@ pub enum LowLevel {
     NumPow,
     NumCeiling,
     NumPowInt,
+    NumPowIntChecked,
     NumFloor,
     NumIsNan,
     NumIsInfinite,
     NumIsFinite,
     NumAtan,
+    NumAtan2,
     NumAcos,
     NumAsin,
+    NumSinh,
+    NumCosh,
+    NumTanh,
+    NumAsinh,
+    NumAcosh,
+    NumAtanh,
     NumBitwiseAnd,
     NumBitwiseXor,
     NumBitwiseOr,
@@ -98,10 +115,13 @@ pub enum LowLevel {
     NumShiftRightBy,
     NumShiftRightZfBy,
     NumIntCast,
+    NumToI128,
+    NumToU128,
     NumToFloatCast,
     NumToIntChecked,
     NumToFloatChecked,
     NumToStr,
+    NumToStrWithRadix,
     NumCountLeadingZeroBits,
     NumCountTrailingZeroBits,
     NumCountOneBits,
@@ -125,6 +145,10 @@ pub enum LowLevel {
     PtrStore,
     PtrLoad,
     PtrClearTagId,
+    // These work on pointers, for the refcounting procs that mono generates. There's
+    // deliberately no value-level RefCountInc/RefCountDec: hosts adjust refcounts
+    // through roc_std's Clone and Drop, and inc_dec assumes Roc code can't free a
+    // value it still refers to.
     RefCountIncRcPtr,
     RefCountDecRcPtr,
     RefCountIncDataPtr,
@@ -221,6 +245,8 @@ macro_rules! map_symbol_to_lowlevel {
 
                 // these functions return polymorphic values
                 LowLevel::NumIntCast => unreachable!(),
+                LowLevel::NumToI128 => unreachable!(),
+                LowLevel::NumToU128 => unreachable!(),
                 LowLevel::NumToFloatCast => unreachable!(),
                 LowLevel::NumToIntChecked => unreachable!(),
                 LowLevel::NumToFloatChecked => unreachable!(),
@@ -262,6 +288,9 @@ map_symbol_to_lowlevel! {
     StrStartsWith <= STR_STARTS_WITH;
     StrEndsWith <= STR_ENDS_WITH;
     StrSplitOn <= STR_SPLIT_ON;
+    StrSplitOnAny <= STR_SPLIT_ON_ANY;
+    StrSplitFirst <= STR_SPLIT_FIRST_LOWLEVEL;
+    StrSplitLast <= STR_SPLIT_LAST_LOWLEVEL;
     StrCountUtf8Bytes <= STR_COUNT_UTF8_BYTES;
     StrFromUtf8 <= STR_FROM_UTF8_LOWLEVEL;
     StrFromUtf8Lossy <= STR_FROM_UTF8_LOSSY;
@@ -279,6 +308,7 @@ map_symbol_to_lowlevel! {
     StrWithAsciiLowercased <= STR_WITH_ASCII_LOWERCASED;
     StrWithAsciiUppercased <= STR_WITH_ASCII_UPPERCASED;
     StrCaselessAsciiEquals <= STR_CASELESS_ASCII_EQUALS;
+    StrCompare <= STR_COMPARE;
     ListLenU64 <= LIST_LEN_U64;
     ListLenUsize <= LIST_LEN_USIZE;
     ListGetCapacity <= LIST_CAPACITY;
@@ -296,6 +326,8 @@ map_symbol_to_lowlevel! {
     ListDropAt <= LIST_DROP_AT;
     ListSwap <= LIST_SWAP;
     ListConcatUtf8 <= LIST_CONCAT_UTF8;
+    ListRange <= LIST_RANGE_LOWLEVEL;
+    ListRangeWithStep <= LIST_RANGE_WITH_STEP_LOWLEVEL;
     NumAdd <= NUM_ADD;
     NumAddWrap <= NUM_ADD_WRAP;
     NumAddChecked <= NUM_ADD_CHECKED_LOWLEVEL;
@@ -307,6 +339,7 @@ map_symbol_to_lowlevel! {
     NumMul <= NUM_MUL;
     NumMulWrap <= NUM_MUL_WRAP;
     NumMulSaturated <= NUM_MUL_SATURATED;
+    NumMulAdd <= NUM_MUL_ADD;
     NumMulChecked <= NUM_MUL_CHECKED_LOWLEVEL;
     NumGt <= NUM_GT;
     NumGte <= NUM_GTE;
@@ -316,7 +349,9 @@ map_symbol_to_lowlevel! {
     NumDivFrac <= NUM_DIV_FRAC;
     NumDivCeilUnchecked <= NUM_DIV_CEIL;
     NumDivTruncUnchecked <= NUM_DIV_TRUNC_UNCHECKED;
+    NumDivTruncChecked <= NUM_DIV_TRUNC_CHECKED_LOWLEVEL;
     NumRemUnchecked <= NUM_REM_UNCHECKED;
+    NumRemChecked <= NUM_REM_CHECKED_LOWLEVEL;
     NumIsMultipleOf <= NUM_IS_MULTIPLE_OF;
     NumAbs <= NUM_ABS;
     NumNeg <= NUM_NEG;
@@ -333,10 +368,18 @@ map_symbol_to_lowlevel! {
     NumPow <= NUM_POW;
     NumCeiling <= NUM_CEILING;
     NumPowInt <= NUM_POW_INT;
+    NumPowIntChecked <= NUM_POW_INT_CHECKED_LOWLEVEL;
     NumFloor <= NUM_FLOOR;
     NumAtan <= NUM_ATAN;
+    NumAtan2 <= NUM_ATAN2;
     NumAcos <= NUM_ACOS;
     NumAsin <= NUM_ASIN;
+    NumSinh <= NUM_SINH;
+    NumCosh <= NUM_COSH;
+    NumTanh <= NUM_TANH;
+    NumAsinh <= NUM_ASINH;
+    NumAcosh <= NUM_ACOSH;
+    NumAtanh <= NUM_ATANH;
     NumBitwiseAnd <= NUM_BITWISE_AND;
     NumBitwiseXor <= NUM_BITWISE_XOR;
     NumBitwiseOr <= NUM_BITWISE_OR;
@@ -344,6 +387,7 @@ map_symbol_to_lowlevel! {
     NumShiftRightBy <= NUM_SHIFT_RIGHT;
     NumShiftRightZfBy <= NUM_SHIFT_RIGHT_ZERO_FILL;
     NumToStr <= NUM_TO_STR;
+    NumToStrWithRadix <= NUM_TO_STR_WITH_RADIX_LOWLEVEL;
     NumCountLeadingZeroBits <= NUM_COUNT_LEADING_ZERO_BITS;
     NumCountTrailingZeroBits <= NUM_COUNT_TRAILING_ZERO_BITS;
     NumCountOneBits <= NUM_COUNT_ONE_BITS;
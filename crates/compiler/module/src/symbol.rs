@@ -1360,6 +1360,29 @@ define_builtins! {
         173 NUM_F32_FROM_BITS: "f32_from_bits"
         174 NUM_F64_FROM_BITS: "f64_from_bits"
         175 NUM_DEC_FROM_BITS: "dec_from_bits"
+        176 NUM_DIV_TRUNC_CHECKED_LOWLEVEL: "div_trunc_checked_lowlevel"
+        177 NUM_REM_CHECKED_LOWLEVEL: "rem_checked_lowlevel"
+        178 NUM_MUL_ADD: "mul_add"
+        179 NUM_POW_INT_CHECKED: "pow_int_checked"
+        180 NUM_POW_INT_CHECKED_LOWLEVEL: "pow_int_checked_lowlevel"
+        181 NUM_CLAMP: "clamp"
+        182 NUM_ATAN2: "atan2"
+        183 NUM_DIV_REM: "div_rem"
+        184 NUM_ABS_CHECKED: "abs_checked"
+        185 NUM_NEG_CHECKED: "neg_checked"
+        186 NUM_TO_STR_WITH_RADIX: "to_str_with_radix"
+        187 NUM_TO_STR_WITH_RADIX_LOWLEVEL: "to_str_with_radix_lowlevel"
+        188 NUM_SINH: "sinh"
+        189 NUM_COSH: "cosh"
+        190 NUM_TANH: "tanh"
+        191 NUM_ASINH: "asinh"
+        192 NUM_ACOSH: "acosh"
+        193 NUM_ATANH: "atanh"
+        194 NUM_U16_TO_LE_BYTES: "u16_to_le_bytes"
+        195 NUM_U32_TO_LE_BYTES: "u32_to_le_bytes"
+        196 NUM_U64_TO_LE_BYTES: "u64_to_le_bytes"
+        197 NUM_ADD_WITH_OVERFLOW: "add_with_overflow"
+        198 NUM_MUL_WITH_OVERFLOW: "mul_with_overflow"
     }
     4 BOOL: "Bool" => {
         0 BOOL_BOOL: "Bool" exposed_type=true // the Bool.Bool type alias
@@ -1433,6 +1456,12 @@ define_builtins! {
         55 STR_FROM_UTF16_LOSSY: "from_utf16_lossy"
         56 STR_FROM_UTF32: "from_utf32"
         57 STR_FROM_UTF32_LOSSY: "from_utf32_lossy"
+        58 STR_COMPARE: "compare"
+        59 STR_SPLIT_FIRST_LOWLEVEL: "split_first_lowlevel"
+        60 STR_SPLIT_LAST_LOWLEVEL: "split_last_lowlevel"
+        61 STR_SPLIT_ON_ANY: "split_on_any"
+        62 STR_FROM_SCALAR: "from_scalar"
+        63 STR_APPEND_SCALAR: "append_scalar"
     }
     6 LIST: "List" => {
         0 LIST_LIST: "List" exposed_apply_type=true // the List.List type alias
@@ -1534,6 +1563,11 @@ define_builtins! {
         96 LIST_MAP_TRY_FX: "map_try!"
         97 LIST_KEEP_IF_TRY_FX: "keep_if_try!"
         98 LIST_JOIN_MAP_FX: "join_map!"
+        99 LIST_RANGE_LOWLEVEL: "range_lowlevel"
+        100 LIST_RANGE_WITH_STEP_LOWLEVEL: "range_with_step_lowlevel"
+        101 LIST_ZIP: "zip"
+        102 LIST_UNZIP: "unzip"
+        103 LIST_INDEX_OF: "index_of"
     }
     7 RESULT: "Result" => {
         0 RESULT_RESULT: "Result" exposed_type=true // the Result.Result type alias
@@ -1584,6 +1618,10 @@ define_builtins! {
         28 DICT_DROP_IF: "drop_if"
         29 DICT_RESERVE: "reserve"
         30 DICT_RELEASE_EXCESS_CAPACITY: "release_excess_capacity"
+        31 DICT_GET_OR_INSERT: "get_or_insert"
+        32 DICT_MAP_VALUES: "map_values"
+        33 DICT_GROUP_BY: "group_by"
+        34 DICT_UNION_WITH: "union_with"
     }
     9 SET: "Set" => {
         0 SET_SET: "Set" exposed_type=true // the Set.Set type alias
@@ -1538,6 +1538,7 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         Unreachable => RC::Uknown,
         ListLenU64 | ListLenUsize | StrIsEmpty | StrCountUtf8Bytes | ListGetCapacity
         | ListWithCapacity | StrWithCapacity => RC::NoRc,
+        ListRange | ListRangeWithStep => RC::NoRc,
         ListReplaceUnsafe => RC::Rc,
         StrGetUnsafe | ListGetUnsafe => RC::NoRc,
         ListConcat => RC::Rc,
@@ -1548,7 +1549,8 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         StrTrim => RC::Rc,
         StrTrimStart => RC::Rc,
         StrTrimEnd => RC::Rc,
-        StrSplitOn => RC::NoRc,
+        StrSplitOn | StrSplitOnAny => RC::NoRc,
+        StrSplitFirst | StrSplitLast => RC::NoRc,
         StrToNum => RC::NoRc,
         ListPrepend => RC::Rc,
         StrJoinWith => RC::NoRc,
@@ -1556,6 +1558,7 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         StrWithAsciiLowercased => RC::Rc,
         StrWithAsciiUppercased => RC::Rc,
         StrCaselessAsciiEquals => RC::NoRc,
+        StrCompare => RC::NoRc,
 
         ListAppendUnsafe
         | ListReserve
@@ -1572,9 +1575,10 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         NumAdd | NumAddWrap | NumAddChecked | NumAddSaturated | NumSub | NumSubWrap
         | NumSubChecked | NumSubSaturated | NumMul | NumMulWrap | NumMulSaturated
         | NumMulChecked | NumGt | NumGte | NumLt | NumLte | NumCompare | NumDivFrac
-        | NumDivTruncUnchecked | NumDivCeilUnchecked | NumRemUnchecked | NumIsMultipleOf
-        | NumPow | NumPowInt | NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr | NumShiftLeftBy
-        | NumShiftRightBy | NumShiftRightZfBy => RC::NoRc,
+        | NumDivTruncUnchecked | NumDivTruncChecked | NumDivCeilUnchecked | NumRemUnchecked
+        | NumRemChecked | NumIsMultipleOf | NumPow | NumPowInt | NumPowIntChecked | NumAtan2
+        | NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr | NumShiftLeftBy | NumShiftRightBy
+        | NumShiftRightZfBy | NumMulAdd | NumToStrWithRadix => RC::NoRc,
 
         NumToStr
         | NumAbs
@@ -1595,7 +1599,15 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         | NumAtan
         | NumAcos
         | NumAsin
+        | NumSinh
+        | NumCosh
+        | NumTanh
+        | NumAsinh
+        | NumAcosh
+        | NumAtanh
         | NumIntCast
+        | NumToI128
+        | NumToU128
         | NumToIntChecked
         | NumToFloatCast
         | NumToFloatChecked
@@ -1262,6 +1262,8 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
         DictPseudoSeed => &[IRRELEVANT],
         ListLenU64 | ListLenUsize | StrIsEmpty | StrCountUtf8Bytes | ListGetCapacity => &[BORROWED],
         ListWithCapacity | StrWithCapacity => &[IRRELEVANT],
+        ListRange => &[IRRELEVANT, IRRELEVANT],
+        ListRangeWithStep => &[IRRELEVANT, IRRELEVANT, IRRELEVANT],
         ListReplaceUnsafe => &[OWNED, IRRELEVANT, IRRELEVANT],
         StrGetUnsafe | ListGetUnsafe => &[BORROWED, IRRELEVANT],
         ListConcat => &[OWNED, OWNED],
@@ -1272,7 +1274,8 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
         StrTrim => &[OWNED],
         StrTrimStart => &[OWNED],
         StrTrimEnd => &[OWNED],
-        StrSplitOn => &[BORROWED, BORROWED],
+        StrSplitOn | StrSplitOnAny => &[BORROWED, BORROWED],
+        StrSplitFirst | StrSplitLast => &[BORROWED, BORROWED],
         StrToNum => &[BORROWED],
         ListPrepend => &[OWNED, OWNED],
         StrJoinWith => &[BORROWED, BORROWED],
@@ -1289,15 +1292,19 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
         StrWithAsciiLowercased => &[OWNED],
         StrWithAsciiUppercased => &[OWNED],
         StrCaselessAsciiEquals => &[BORROWED, BORROWED],
+        StrCompare => &[BORROWED, BORROWED],
 
         Eq | NotEq => &[BORROWED, BORROWED],
 
         NumAdd | NumAddWrap | NumAddChecked | NumAddSaturated | NumSub | NumSubWrap
         | NumSubChecked | NumSubSaturated | NumMul | NumMulWrap | NumMulSaturated
         | NumMulChecked | NumGt | NumGte | NumLt | NumLte | NumCompare | NumDivFrac
-        | NumDivTruncUnchecked | NumDivCeilUnchecked | NumRemUnchecked | NumIsMultipleOf
-        | NumPow | NumPowInt | NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr | NumShiftLeftBy
-        | NumShiftRightBy | NumShiftRightZfBy => &[IRRELEVANT, IRRELEVANT],
+        | NumDivTruncUnchecked | NumDivTruncChecked | NumDivCeilUnchecked | NumRemUnchecked
+        | NumRemChecked | NumIsMultipleOf | NumPow | NumPowInt | NumPowIntChecked | NumAtan2
+        | NumBitwiseAnd | NumBitwiseXor | NumBitwiseOr | NumShiftLeftBy | NumShiftRightBy
+        | NumShiftRightZfBy | NumToStrWithRadix => &[IRRELEVANT, IRRELEVANT],
+
+        NumMulAdd => &[IRRELEVANT, IRRELEVANT, IRRELEVANT],
 
         NumToStr
         | NumAbs
@@ -1318,7 +1325,15 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
         | NumAtan
         | NumAcos
         | NumAsin
+        | NumSinh
+        | NumCosh
+        | NumTanh
+        | NumAsinh
+        | NumAcosh
+        | NumAtanh
         | NumIntCast
+        | NumToI128
+        | NumToU128
         | NumToIntChecked
         | NumToFloatCast
         | NumToFloatChecked
@@ -206,6 +206,34 @@ fn values() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn to_list_pairs_keys_with_values() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            my_dict : Dict.Dict I64 I64
+            my_dict =
+                Dict.empty {}
+                    |> Dict.insert 0 0
+                    |> Dict.insert 1 10
+                    |> Dict.insert 2 20
+                    |> Dict.insert 3 30
+                    |> Dict.remove 1
+
+            pairs = Dict.to_list my_dict
+
+            if List.len pairs == Dict.len my_dict then
+                pairs
+            else
+                []
+            "
+        ),
+        RocList::from_slice(&[(0, 0), (3, 30), (2, 20)]),
+        RocList<(i64, i64)>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn from_list_with_fold_simple() {
@@ -550,3 +578,141 @@ fn walk_sum_keys() {
         i64
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn get_or_insert_present() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            dict : Dict.Dict I64 I64
+            dict =
+                Dict.empty {}
+                    |> Dict.insert 1 100
+                    |> Dict.insert 2 200
+
+            (updated, value) = Dict.get_or_insert dict 2 0
+
+            (value, Dict.len updated)
+            "
+        ),
+        (200, 2),
+        (i64, u64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn get_or_insert_absent() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            dict : Dict.Dict I64 I64
+            dict =
+                Dict.empty {}
+                    |> Dict.insert 1 100
+                    |> Dict.insert 2 200
+
+            (updated, value) = Dict.get_or_insert dict 3 300
+            stored = Dict.get updated 3 |> Result.with_default 0
+
+            (value, stored, Dict.len updated)
+            "
+        ),
+        (300, 300, 3),
+        (i64, i64, u64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn map_values_doubles() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict Str I64
+            dict =
+                Dict.empty {}
+                    |> Dict.insert "a" 100
+                    |> Dict.insert "b" 200
+                    |> Dict.insert "c" 300
+
+            doubled = Dict.map_values dict \n -> n * 2
+
+            (Dict.get doubled "b" |> Result.with_default 0, Dict.values doubled)
+            "#
+        ),
+        (400, RocList::from_slice(&[200, 400, 600])),
+        (i64, RocList<i64>)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn group_by_keeps_element_order() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            groups = Dict.group_by [1, 2, 3, 4, 5, 6, 7] \n -> n % 3
+
+            (Dict.len groups, Dict.get groups 1 |> Result.with_default [])
+            "#
+        ),
+        (3, RocList::from_slice(&[1, 4, 7])),
+        (u64, RocList<i64>)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn union_with_sums_conflicting_values() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            first : Dict.Dict Str I64
+            first =
+                Dict.single "apples" 2
+                    |> Dict.insert "pears" 1
+
+            second : Dict.Dict Str I64
+            second =
+                Dict.single "apples" 3
+                    |> Dict.insert "plums" 5
+
+            merged = Dict.union_with first second Num.add
+
+            (Dict.len merged, Dict.values merged)
+            "#
+        ),
+        (3, RocList::from_slice(&[5, 1, 5])),
+        (u64, RocList<i64>)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn reserve_never_shrinks_capacity() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            dict : Dict.Dict I64 I64
+            dict =
+                Dict.with_capacity 20
+                    |> Dict.insert 1 1
+                    |> Dict.insert 2 2
+
+            small = Dict.reserve dict 1
+            large = Dict.reserve dict 100
+
+            # `dict` is still used here, so both reserves worked on a shared dict.
+            (
+                Dict.capacity small >= Dict.capacity dict,
+                Dict.capacity large >= 102,
+                Dict.len large == 2 and Dict.capacity dict >= 20,
+            )
+            "#
+        ),
+        (true, true, true),
+        (bool, bool, bool)
+    );
+}
@@ -817,6 +817,26 @@ fn list_drop_if_geq3() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_drop_if_even_shares_predicate_with_keep_if() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            is_even = \n -> n % 2 == 0
+            numbers = [1, 2, 3, 4, 5, 6, 7]
+
+            (List.drop_if numbers is_even, List.keep_if numbers is_even)
+            "
+        ),
+        (
+            RocList::from_slice(&[1, 3, 5, 7]),
+            RocList::from_slice(&[2, 4, 6])
+        ),
+        (RocList<i64>, RocList<i64>)
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_drop_if_string_eq() {
@@ -1161,6 +1181,26 @@ fn list_walk_until_sum() {
     assert_evals_to!(r"List.walk_until [1, 2] 0 \a,b -> Continue (a + b)", 3, i64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_walk_with_index_weighted_sum() {
+    assert_evals_to!(
+        r"List.walk_with_index [5u64, 7, 2, 3] 0 \state, elem, index -> state + elem * index",
+        20,
+        u64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_walk_with_index_empty() {
+    assert_evals_to!(
+        r"List.walk_with_index [] 42u64 \state, elem, index -> state + elem * index",
+        42,
+        u64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_walk_with_index_until_sum() {
@@ -1714,6 +1754,47 @@ fn list_map2_different_lengths() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_zip_truncates_to_shorter() {
+    assert_evals_to!(
+        "List.zip [1, 2, 3] [30, 20]",
+        RocList::from_slice(&[(1, 30), (2, 20)]),
+        RocList<(i64, i64)>
+    );
+    assert_evals_to!(
+        "List.zip [1] [30, 20, 10]",
+        RocList::from_slice(&[(1, 30)]),
+        RocList<(i64, i64)>
+    );
+    assert_evals_to!(
+        "List.zip [] [30, 20, 10]",
+        RocList::<(i64, i64)>::from_slice(&[]),
+        RocList<(i64, i64)>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_unzip_undoes_zip() {
+    assert_evals_to!(
+        "List.unzip (List.zip [1, 2, 3] [30, 20, 10])",
+        (
+            RocList::from_slice(&[1, 2, 3]),
+            RocList::from_slice(&[30, 20, 10])
+        ),
+        (RocList<i64>, RocList<i64>)
+    );
+    assert_evals_to!(
+        "List.unzip []",
+        (
+            RocList::<i64>::from_slice(&[]),
+            RocList::<i64>::from_slice(&[])
+        ),
+        (RocList<i64>, RocList<i64>)
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_join_empty_list() {
@@ -2505,6 +2586,63 @@ fn set_shared_list_oob() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn update_unique_int_list() {
+    assert_evals_to!(
+        "List.update [12, 9, 7, 1, 5] 2 \\x -> x * 3",
+        RocList::from_slice(&[12, 9, 21, 1, 5]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn update_list_oob() {
+    assert_evals_to!(
+        "List.update [12, 9, 7] 3 \\x -> x * 3",
+        RocList::from_slice(&[12, 9, 7]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn update_shared_int_list() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            shared = [2, 4]
+
+            # This should not mutate the original
+            x =
+                when List.get (List.update shared 1 \n -> n + 10) 1 is
+                    Ok num -> num
+                    Err _ -> 0
+
+            y =
+                when List.get shared 1 is
+                    Ok num -> num
+                    Err _ -> 0
+
+            { x, y }
+            "
+        ),
+        (14, 4),
+        (i64, i64)
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn update_nested_list() {
+    assert_evals_to!(
+        "List.update [[1], [2, 3]] 1 \\inner -> List.append inner 4",
+        RocList::from_slice(&[RocList::from_slice(&[1]), RocList::from_slice(&[2, 3, 4])]),
+        RocList<RocList<i64>>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn get_unique_int_list() {
@@ -2950,6 +3088,31 @@ fn list_contains_str() {
     assert_evals_to!(indoc!(r#"List.contains [] "spam""#), false, bool);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_index_of() {
+    assert_evals_to!(
+        "List.index_of [4, 7, 9, 7] 7",
+        RocResult::ok(1),
+        RocResult<u64, ()>
+    );
+    assert_evals_to!(
+        "List.index_of [4, 7, 9, 7] 5",
+        RocResult::err(()),
+        RocResult<u64, ()>
+    );
+    assert_evals_to!(
+        r#"List.index_of ["foo", "bar", "bar"] "bar""#,
+        RocResult::ok(1),
+        RocResult<u64, ()>
+    );
+    assert_evals_to!(
+        r#"List.index_of [] "spam""#,
+        RocResult::err(()),
+        RocResult<u64, ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_manual_range() {
@@ -3021,12 +3184,48 @@ fn list_max() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_min_max_floats() {
+    assert_evals_to!(
+        "List.min [1.5f64, -2.25, 0]",
+        RocResult::ok(-2.25),
+        RocResult<f64, ()>
+    );
+    assert_evals_to!(
+        "List.max [1.5f64, -2.25, 0]",
+        RocResult::ok(1.5),
+        RocResult<f64, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_min_max_propagate_nan() {
+    assert_evals_to!(
+        "List.min [1f64, Num.nan_f64, 3] |> Result.map_ok Num.is_nan",
+        RocResult::ok(true),
+        RocResult<bool, ()>
+    );
+    assert_evals_to!(
+        "List.max [1f64, 3, Num.nan_f64] |> Result.map_ok Num.is_nan",
+        RocResult::ok(true),
+        RocResult<bool, ()>
+    );
+    assert_evals_to!(
+        "List.max [Num.nan_f64, 1f64, 3] |> Result.map_ok Num.is_nan",
+        RocResult::ok(true),
+        RocResult<bool, ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_sum() {
     assert_evals_to!("List.sum []", 0, i64);
     assert_evals_to!("List.sum [1, 2, 3]", 6, i64);
     assert_evals_to!("List.sum [1.1f64, 2.2, 3.3]", 6.6, f64);
+    assert_evals_to!("List.sum [1.5f64, -2.25, 0]", -0.75, f64);
 }
 
 #[test]
@@ -3041,6 +3240,14 @@ fn list_product() {
     assert_evals_to!("List.product []", 1, i64);
     assert_evals_to!("List.product [1, 2, 3]", 6, i64);
     assert_evals_to!("List.product [1.1f64, 2.2, 3.3]", 1.1 * 2.2 * 3.3, f64);
+    assert_evals_to!("List.product [2.5dec, 4]", RocDec::from(10), RocDec);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+#[should_panic(expected = r#"Roc failed with message: "Integer multiplication overflowed!"#)]
+fn list_product_overflow() {
+    assert_evals_to!("List.product [16u8, 16]", 0, u8);
 }
 
 #[test]
@@ -3327,6 +3534,35 @@ fn list_join_map_empty() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_join_map_all_empty() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            List.join_map [1, 2, 3] (\_ -> [])
+            |> List.concat [4i64]
+            "
+        ),
+        RocList::from_slice(&[4]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_join_map_skips_leading_empties() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            List.join_map [0, 2, 0, 3] (\n -> List.repeat n n)
+            "
+        ),
+        RocList::from_slice(&[2, 2, 3, 3, 3]),
+        RocList<i64>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_find() {
@@ -3355,6 +3591,23 @@ fn list_find() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_find_last_stops_at_the_last_match() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            # The predicate crashes if find_last scans past the match it should stop at.
+            when List.find_last [0, 1, 2, 3, 4, 5] (\n -> if n < 4 then crash "scanned too far" else Num.is_even n) is
+                Ok v -> v
+                Err _ -> 99
+            "#
+        ),
+        4,
+        i64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn list_find_not_found() {
@@ -3717,6 +3970,34 @@ fn with_capacity() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_capacity() {
+    assert_evals_to!("List.capacity (List.with_capacity 10)", 10, u64);
+    assert_evals_to!("List.capacity []", 0, u64);
+
+    // Three appends onto room for one forces the list to grow at least once.
+    assert_evals_to!(
+        indoc!(
+            r"
+            l : List U64
+            l =
+                List.with_capacity 1
+                |> List.append 1
+                |> List.append 2
+                |> List.append 3
+
+            List.capacity l >= List.len l and List.len l == 3
+            "
+        ),
+        true,
+        bool
+    );
+
+    // A seamless slice doesn't own its allocation, so it has no spare room.
+    assert_evals_to!("List.capacity (List.drop_first [1u8, 2, 3, 4] 1)", 3, u64);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn with_capacity_append() {
@@ -3737,6 +4018,24 @@ fn with_capacity_append() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn with_capacity_concat_extends_in_place() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            List.with_capacity 10
+                |> List.append 0u64
+                |> List.concat [1u64, 2, 3]
+                |> List.concat [4u64, 5]
+            "
+        ),
+        (10, RocList::from_slice(&[0, 1, 2, 3, 4, 5])),
+        RocList<u64>,
+        |value: RocList<u64>| (value.capacity(), value)
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn reserve() {
@@ -4044,6 +4343,46 @@ fn list_range_length_overflow() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_range_descending_before() {
+    assert_evals_to!(
+        "List.range { start: At 5u8, end: Before 1 }",
+        RocList::from_slice(&[5u8, 4, 3, 2]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_range_negative_step_before() {
+    assert_evals_to!(
+        "List.range { start: At 9i64, end: Before -3, step: -4 }",
+        RocList::from_slice(&[9i64, 5, 1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_range_wrong_signed_step_is_empty() {
+    assert_evals_to!(
+        "List.range { start: At 0i32, end: Before 10, step: -1 }",
+        RocList::<i32>::default(),
+        RocList<i32>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn list_range_step_stops_at_type_max() {
+    assert_evals_to!(
+        "List.range { start: At 250u8, end: Before 255, step: 2 }",
+        RocList::from_slice(&[250u8, 252, 254]),
+        RocList<u8>
+    );
+}
+
 mod pattern_match {
     #[allow(unused_imports)]
     use crate::helpers::with_larger_debug_stack;
@@ -11,7 +11,7 @@ use crate::helpers::wasm::assert_evals_to;
 #[allow(unused_imports)]
 use indoc::indoc;
 #[allow(unused_imports)]
-use roc_std::{RocDec, RocOrder, RocResult, I128, U128};
+use roc_std::{RocDec, RocList, RocOrder, RocResult, I128, U128};
 
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
@@ -491,6 +491,30 @@ fn f64_sqrt_checked_negative() {
     assert_evals_to!("Num.sqrt_checked -1f64", RocResult::err(()), RocResult<f64, ()>);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn f64_mul_add_rounds_once() {
+    // 0.1 * 10 rounds to exactly 1, so multiplying and adding separately gives 0.
+    assert_evals_to!("(0.1f64 * 10) - 1", 0.0, f64);
+    assert_evals_to!("Num.mul_add 0.1f64 10 -1", 5.551115123125783e-17, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn f32_mul_add() {
+    assert_evals_to!("Num.mul_add 1.5f32 2 0.25", 3.25, f32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn dec_mul_add() {
+    assert_evals_to!(
+        "Num.mul_add 1.5dec 2.5 -0.25",
+        RocDec::from_str("3.5").unwrap(),
+        RocDec
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn f64_log() {
@@ -1201,6 +1225,21 @@ fn gen_rem_div_by_zero_i64() {
     assert_evals_to!("Num.rem 42 0", 100, i64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_div_rem_i64() {
+    assert_evals_to!("Num.div_rem 17 5", (3, 2), (i64, i64));
+    assert_evals_to!("Num.div_rem -17 5", (-3, -2), (i64, i64));
+    assert_evals_to!("Num.div_rem 200u8 7", (28, 4), (u8, u8));
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+#[should_panic(expected = r#"User crash with message: "Integer division by 0!"#)]
+fn gen_div_rem_by_zero_i64() {
+    assert_evals_to!("Num.div_rem 42 0", (0, 0), (i64, i64));
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_rem_checked_i64() {
@@ -1221,6 +1260,46 @@ fn gen_rem_checked_div_by_zero_i64() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_div_checked_by_zero_u8() {
+    assert_evals_to!(
+        "Num.div_trunc_checked 200u8 0",
+        RocResult::err(()),
+        RocResult<u8, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_div_checked_truncates_i128() {
+    assert_evals_to!(
+        "Num.div_trunc_checked -7i128 2",
+        RocResult::ok(-3),
+        RocResult<i128, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_rem_checked_by_zero_u32() {
+    assert_evals_to!(
+        "Num.rem_checked 8u32 0",
+        RocResult::err(()),
+        RocResult<u32, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn gen_rem_checked_negative_i32() {
+    assert_evals_to!(
+        "Num.rem_checked -8i32 3",
+        RocResult::ok(-2),
+        RocResult<i32, ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_is_positive_i64() {
@@ -1531,6 +1610,39 @@ fn gen_is_approx_eq() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn is_approx_eq_with_epsilon() {
+    let within = |a: &str, b: &str| format!("Num.is_approx_eq {a} {b} {{ atol: 0.001, rtol: 0 }}");
+
+    assert_evals_to!(&within("1.0f64", "1.0009"), true, bool);
+    assert_evals_to!(&within("1.0f64", "1.0011"), false, bool);
+    assert_evals_to!(&within("-5.0f32", "-5.0005"), true, bool);
+    assert_evals_to!(&within("Num.nan_f64", "Num.nan_f64"), false, bool);
+    assert_evals_to!(&within("Num.nan_f64", "1.0"), false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn is_approx_eq_infinities() {
+    assert_evals_to!(
+        "Num.is_approx_eq Num.infinity_f64 Num.infinity_f64 {}",
+        true,
+        bool
+    );
+    assert_evals_to!(
+        "Num.is_approx_eq Num.infinity_f64 (-Num.infinity_f64) {}",
+        false,
+        bool
+    );
+    assert_evals_to!(
+        "Num.is_approx_eq Num.infinity_f64 Num.max_f64 {}",
+        false,
+        bool
+    );
+    assert_evals_to!("Num.is_approx_eq 1f32 Num.infinity_f32 {}", false, bool);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_order_of_arithmetic_ops() {
@@ -1673,6 +1785,28 @@ fn neg_min_int_overflow() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn abs_checked() {
+    assert_evals_to!("Num.abs_checked -5i64", RocResult::ok(5), RocResult<i64, ()>);
+    assert_evals_to!("Num.abs_checked 7i64", RocResult::ok(7), RocResult<i64, ()>);
+    assert_evals_to!("Num.abs_checked -128i8", RocResult::err(()), RocResult<i8, ()>);
+    assert_evals_to!(
+        "Num.abs_checked Num.min_i64",
+        RocResult::err(()),
+        RocResult<i64, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn neg_checked() {
+    assert_evals_to!("Num.neg_checked 5i64", RocResult::ok(-5), RocResult<i64, ()>);
+    assert_evals_to!("Num.neg_checked 127i8", RocResult::ok(-127), RocResult<i8, ()>);
+    assert_evals_to!("Num.neg_checked -128i8", RocResult::err(()), RocResult<i8, ()>);
+    assert_evals_to!("Num.neg_checked 3u8", RocResult::err(()), RocResult<u8, ()>);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_wrap_int_neg() {
@@ -1937,12 +2071,92 @@ fn pow_int_overflow() {
     assert_evals_to!("Num.pow_int 2u8 8", 0, u8);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn pow_int_checked_ok() {
+    assert_evals_to!(
+        "Num.pow_int_checked 2i64 10",
+        RocResult::ok(1024),
+        RocResult<i64, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn pow_int_checked_overflow() {
+    assert_evals_to!(
+        "Num.pow_int_checked 2i64 63",
+        RocResult::err(()),
+        RocResult<i64, ()>
+    );
+    assert_evals_to!(
+        "Num.pow_int_checked 2u8 8",
+        RocResult::err(()),
+        RocResult<u8, ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn atan() {
     assert_evals_to!("Num.atan 10f64", 1.4711276743037347, f64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn hyperbolic() {
+    assert_evals_to!("Num.sinh 0f64", 0.0, f64);
+    assert_evals_to!("Num.cosh 0f64", 1.0, f64);
+    assert_evals_to!("Num.tanh 0f64", 0.0, f64);
+    assert_evals_to!("Num.sinh 1f64", 1.0f64.sinh(), f64);
+    assert_evals_to!("Num.cosh 1f64", 1.0f64.cosh(), f64);
+    assert_evals_to!("Num.tanh 1f32", 1.0f32.tanh(), f32);
+    assert_evals_to!("Num.cosh 0f32", 1.0, f32);
+    assert_evals_to!("Num.sinh 0dec", RocDec::from_str("0.0").unwrap(), RocDec);
+    assert_evals_to!("Num.cosh 0dec", RocDec::from_str("1.0").unwrap(), RocDec);
+    assert_evals_to!("Num.tanh 0dec", RocDec::from_str("0.0").unwrap(), RocDec);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn inverse_hyperbolic() {
+    assert_evals_to!("Num.asinh 0f64", 0.0, f64);
+    assert_evals_to!("Num.acosh 1f64", 0.0, f64);
+    assert_evals_to!("Num.atanh 0f64", 0.0, f64);
+    assert_evals_to!("Num.asinh 1f64", 1.0f64.asinh(), f64);
+    assert_evals_to!("Num.acosh 2f64", 2.0f64.acosh(), f64);
+    assert_evals_to!("Num.atanh 0.5f32", 0.5f32.atanh(), f32);
+    assert_evals_to!("Num.acosh 1f32", 0.0, f32);
+    assert_evals_to!("Num.asinh 0dec", RocDec::from_str("0.0").unwrap(), RocDec);
+    assert_evals_to!("Num.acosh 1dec", RocDec::from_str("0.0").unwrap(), RocDec);
+    assert_evals_to!("Num.atanh 0dec", RocDec::from_str("0.0").unwrap(), RocDec);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn inverse_hyperbolic_out_of_domain() {
+    assert_evals_to!("Num.is_nan (Num.acosh 0.5f64)", true, bool);
+    assert_evals_to!("Num.is_nan (Num.acosh 0.5f32)", true, bool);
+    assert_evals_to!("Num.is_nan (Num.atanh 1f64)", true, bool);
+    assert_evals_to!("Num.is_nan (Num.atanh -1f64)", true, bool);
+    assert_evals_to!("Num.is_nan (Num.atanh 2f32)", true, bool);
+    assert_evals_to!("Num.is_nan (Num.atanh 0.999f64)", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn atan2_quadrants() {
+    use std::f64::consts::{FRAC_PI_4, PI};
+
+    assert_evals_to!("Num.atan2 1f64 1", FRAC_PI_4, f64);
+    assert_evals_to!("Num.atan2 1f64 -1", 3.0 * FRAC_PI_4, f64);
+    assert_evals_to!("Num.atan2 -1f64 -1", -3.0 * FRAC_PI_4, f64);
+    assert_evals_to!("Num.atan2 -1f64 1", -FRAC_PI_4, f64);
+    assert_evals_to!("Num.atan2 0f64 -1", PI, f64);
+    assert_evals_to!("Num.atan2 0f64 0", 0.0, f64);
+    assert_evals_to!("Num.atan2 1f32 1", std::f32::consts::FRAC_PI_4, f32);
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 #[should_panic(expected = r#"Roc failed with message: "Integer addition overflowed!"#)]
@@ -2333,6 +2547,40 @@ fn int_mul_checked() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn add_with_overflow() {
+    assert_evals_to!("(Num.add_with_overflow 254u8 1).value", 255, u8);
+    assert_evals_to!("(Num.add_with_overflow 254u8 1).overflowed", false, bool);
+    assert_evals_to!("(Num.add_with_overflow 255u8 1).value", 0, u8);
+    assert_evals_to!("(Num.add_with_overflow 255u8 1).overflowed", true, bool);
+    assert_evals_to!("(Num.add_with_overflow Num.max_i64 1).value", i64::MIN, i64);
+    assert_evals_to!(
+        "(Num.add_with_overflow Num.max_i64 1).overflowed",
+        true,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn mul_with_overflow() {
+    assert_evals_to!("(Num.mul_with_overflow -64i8 2).value", -128, i8);
+    assert_evals_to!("(Num.mul_with_overflow -64i8 2).overflowed", false, bool);
+    assert_evals_to!("(Num.mul_with_overflow 64i8 2).value", -128, i8);
+    assert_evals_to!("(Num.mul_with_overflow 64i8 2).overflowed", true, bool);
+    assert_evals_to!(
+        "(Num.mul_with_overflow Num.max_i128 2).value",
+        I128::from(-2),
+        I128
+    );
+    assert_evals_to!(
+        "(Num.mul_with_overflow Num.max_i128 2).overflowed",
+        true,
+        bool
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn float_mul_checked() {
@@ -2721,6 +2969,9 @@ num_conversion_tests! {
     "Num.to_i128", i128, (
         to_i128_same_width, "15u128", 15, ["gen-dev"]
         to_i128_extend, "15i8", 15
+        to_i128_sign_extend_i8, "-15i8", -15, ["gen-wasm", "gen-dev"]
+        to_i128_sign_extend_i64, "-5_000_000_000i64", -5000000000, ["gen-wasm", "gen-dev"]
+        to_i128_zero_extend_u64, "18_446_744_073_709_551_615u64", 18446744073709551615, ["gen-wasm", "gen-dev"]
     )
     "Num.to_u8", u8, (
         to_u8_same_width, "15i8", 15, ["gen-wasm", "gen-dev"]
@@ -2730,18 +2981,23 @@ num_conversion_tests! {
     "Num.to_u16", u16, (
         to_u16_same_width, "15i16", 15, ["gen-wasm", "gen-dev"]
         to_u16_extend, "15i8", 15, ["gen-wasm", "gen-dev"]
+        to_u16_wraps_negative_i8, "-1i8", u16::MAX, ["gen-wasm", "gen-dev"]
         to_u16_truncate, "115i32", 115, ["gen-wasm", "gen-dev"]
         to_u16_truncate_wraps, "600000000i32", 17920, ["gen-wasm", "gen-dev"]
     )
     "Num.to_u32", u32, (
         to_u32_same_width, "15i32", 15, ["gen-wasm", "gen-dev"]
         to_u32_extend, "15i8", 15, ["gen-wasm", "gen-dev"]
+        to_u32_wraps_negative_i8, "-1i8", u32::MAX, ["gen-wasm", "gen-dev"]
+        to_u32_wraps_negative_i16, "-15i16", u32::MAX - 14, ["gen-wasm", "gen-dev"]
         to_u32_truncate, "115i64", 115, ["gen-wasm", "gen-dev"]
         to_u32_truncate_wraps, "5000000000000000000i64", 1156841472, ["gen-wasm", "gen-dev"]
     )
     "Num.to_u64", u64, (
         to_u64_same_width, "15i64", 15, ["gen-wasm", "gen-dev"]
         to_u64_extend, "15i8", 15, ["gen-wasm", "gen-dev"]
+        to_u64_wraps_negative_i8, "-1i8", u64::MAX, ["gen-wasm", "gen-dev"]
+        to_u64_wraps_negative_i32, "-15i32", u64::MAX - 14, ["gen-wasm", "gen-dev"]
         to_u64_truncate, "115i128", 115
         to_u64_truncate_wraps, "10_000_000_000_000_000_000_000i128", 1864712049423024128
     )
@@ -2749,6 +3005,8 @@ num_conversion_tests! {
         to_u128_same_width, "15i128", 15, ["gen-dev"]
         to_u128_extend, "15i8", 15
         to_u128_big, "11562537357600483583u64", 11562537357600483583, ["gen-dev"]
+        to_u128_wraps_negative_i8, "-1i8", u128::MAX, ["gen-wasm", "gen-dev"]
+        to_u128_wraps_negative_i64, "-15i64", u128::MAX - 14, ["gen-wasm", "gen-dev"]
     )
     "Num.to_f32", f32, (
         to_f32_from_i8, "15i8", 15.0, ["gen-wasm", "gen-dev"]
@@ -2899,6 +3157,45 @@ to_int_checked_tests! {
     )
 }
 
+macro_rules! to_float_checked_tests {
+    ($($fn:expr, $typ:ty, ($($test_name:ident, $input:expr, $output:expr)*))*) => {$($(
+        #[test]
+        #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+        fn $test_name() {
+            // The status is 0 for Ok, 1 for Inexact, and 2 for OutOfBounds.
+            let input = format!(
+                "when {} {} is\n    Ok n -> {{ value: n, status: 0u8 }}\n    Err (Inexact n) -> {{ value: n, status: 1 }}\n    Err OutOfBounds -> {{ value: 0, status: 2 }}",
+                $fn, $input
+            );
+            assert_evals_to!(&input, $output, ($typ, u8))
+        }
+    )*)*}
+}
+
+to_float_checked_tests! {
+    "Num.to_f64_checked", f64, (
+        to_f64_checked_exact_i64,       "9007199254740992i64",  (9007199254740992.0, 0)
+        to_f64_checked_inexact_i64,     "9007199254740993i64",  (9007199254740992.0, 1)
+        to_f64_checked_i64_max,         "9223372036854775807i64", (9223372036854775808.0, 1)
+        to_f64_checked_i64_min,         "-9223372036854775808i64", (-9223372036854775808.0, 0)
+        to_f64_checked_u128_max,        "340282366920938463463374607431768211455u128", (340282366920938463463374607431768211456.0, 1)
+        to_f64_checked_from_f32,        "0.1f32",               (0.10000000149011612, 0)
+        to_f64_checked_exact_dec,       "1.5dec",               (1.5, 0)
+        to_f64_checked_inexact_dec,     "0.1dec",               (0.1, 1)
+    )
+    "Num.to_f32_checked", f32, (
+        to_f32_checked_exact_u8,        "255u8",                (255.0, 0)
+        to_f32_checked_inexact_i32,     "16777217i32",          (16777216.0, 1)
+        to_f32_checked_u128_max,        "340282366920938463463374607431768211455u128", (0.0, 2)
+        to_f32_checked_exact_f64,       "0.5f64",               (0.5, 0)
+        to_f32_checked_inexact_f64,     "0.1f64",               (0.1, 1)
+        to_f32_checked_f64_max,         "Num.max_f64",          (0.0, 2)
+        to_f32_checked_f64_infinity,    "Num.infinity_f64",     (f32::INFINITY, 0)
+        to_f32_checked_exact_dec,       "-0.25dec",             (-0.25, 0)
+        to_f32_checked_inexact_dec,     "16777217dec",          (16777216.0, 1)
+    )
+}
+
 fn wrap_with_default(test_roc_code: &str) -> String {
     format!("Result.with_default ({}) 123454321", test_roc_code)
 }
@@ -3009,6 +3306,20 @@ fn is_multiple_of_unsigned() {
     assert_evals_to!("Num.is_multiple_of 0xFCu8 0xFE", false, bool);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn is_multiple_of_zero_divisor_wide() {
+    assert_evals_to!("Num.is_multiple_of 0i128 0", true, bool);
+    assert_evals_to!("Num.is_multiple_of 12i128 0", false, bool);
+    assert_evals_to!("Num.is_multiple_of 0u128 0", true, bool);
+    assert_evals_to!("Num.is_multiple_of 12u128 0", false, bool);
+    assert_evals_to!(
+        "Num.is_multiple_of -170141183460469231731687303715884105728i128 -1",
+        true,
+        bool
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn when_on_i32() {
@@ -3313,6 +3624,50 @@ fn num_to_str_dec() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_with_radix() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r"Num.to_str_with_radix 48879i64 16",
+        RocResult::ok(RocStr::from("beef")),
+        RocResult<RocStr, ()>
+    );
+    assert_evals_to!(
+        r"Num.to_str_with_radix 10u8 2",
+        RocResult::ok(RocStr::from("1010")),
+        RocResult<RocStr, ()>
+    );
+    assert_evals_to!(
+        r"Num.to_str_with_radix Num.min_i8 2",
+        RocResult::ok(RocStr::from("-10000000")),
+        RocResult<RocStr, ()>
+    );
+    assert_evals_to!(
+        r"Num.to_str_with_radix 0i64 36",
+        RocResult::ok(RocStr::from("0")),
+        RocResult<RocStr, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn num_to_str_with_invalid_radix() {
+    use roc_std::RocStr;
+
+    assert_evals_to!(
+        r"Num.to_str_with_radix 10i64 1",
+        RocResult::err(()),
+        RocResult<RocStr, ()>
+    );
+    assert_evals_to!(
+        r"Num.to_str_with_radix 10i64 37",
+        RocResult::err(()),
+        RocResult<RocStr, ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn u8_addition_greater_than_i8() {
@@ -3988,6 +4343,22 @@ fn num_max() {
     assert_evals_to!(r"Num.max Num.max_i64 Num.min_i64", i64::MAX, i64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn num_clamp() {
+    assert_evals_to!(r"Num.clamp -5 0 10", 0, i64);
+    assert_evals_to!(r"Num.clamp 5 0 10", 5, i64);
+    assert_evals_to!(r"Num.clamp 15 0 10", 10, i64);
+    assert_evals_to!(r"Num.clamp 5 10 0", 10, i64);
+    assert_evals_to!(r"Num.clamp 200u8 10 100", 100, u8);
+    assert_evals_to!(r"Num.clamp -3.5f64 0 10", 0.0, f64);
+    assert_evals_to!(
+        r"Num.clamp 2.5dec 0 10",
+        RocDec::from_str("2.5").unwrap(),
+        RocDec
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn with_decimal_point() {
@@ -4208,6 +4579,46 @@ fn dec_from_bits() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn int_to_le_bytes() {
+    assert_evals_to!(
+        r"Num.u16_to_le_bytes 0x0102",
+        RocList::from_slice(&[0x02u8, 0x01]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        r"Num.u32_to_le_bytes 0x01020304",
+        RocList::from_slice(&[0x04u8, 0x03, 0x02, 0x01]),
+        RocList<u8>
+    );
+    assert_evals_to!(
+        r"Num.u64_to_le_bytes Num.max_u64",
+        RocList::from_slice(&[0xFFu8; 8]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn u32_to_le_bytes_round_trip() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            from_le_bytes : List U8 -> U32
+            from_le_bytes = \bytes ->
+                List.walk_backwards bytes 0 \acc, byte ->
+                    Num.bitwise_or (Num.shift_left_by acc 8) (Num.to_u32 byte)
+
+            List.map [0, 1, 0xDEADBEEF, Num.max_u32] \x ->
+                from_le_bytes (Num.u32_to_le_bytes x) == x
+            "
+        ),
+        RocList::from_slice(&[true; 4]),
+        RocList<bool>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn from_bool_true() {
@@ -14,7 +14,7 @@ use crate::helpers::wasm::assert_evals_to;
 #[allow(unused_imports)]
 use indoc::indoc;
 #[allow(unused_imports)]
-use roc_std::{RocList, RocResult, RocStr, I128, U128};
+use roc_std::{RocList, RocOrder, RocResult, RocStr, I128, U128};
 
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
@@ -261,6 +261,61 @@ fn str_split_on_small_str_big_delimiter() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_split_on_any_adjacent_delimiters() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                    Str.split_on_any ",a;,b" [",", ";"]
+                "#
+        ),
+        RocList::from_slice(&[
+            RocStr::from(""),
+            RocStr::from("a"),
+            RocStr::from(""),
+            RocStr::from("b")
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_split_on_any_longest_delimiter_wins() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                    Str.split_on_any "first->second-third->" ["-", "->"]
+                "#
+        ),
+        RocList::from_slice(&[
+            RocStr::from("first"),
+            RocStr::from("second"),
+            RocStr::from("third"),
+            RocStr::from("")
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_split_on_any_big_str_ignores_empty_delimiters() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                    Str.split_on_any "01234567789abcdefghi 01234567789abcdefghi" ["", " "]
+                "#
+        ),
+        RocList::from_slice(&[
+            RocStr::from("01234567789abcdefghi"),
+            RocStr::from("01234567789abcdefghi")
+        ]),
+        RocList<RocStr>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn str_split_on_small_str_20_char_delimiter() {
@@ -809,6 +864,113 @@ fn str_from_utf8_fail_invalid_start_byte() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_from_utf8_range_window() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                    # the invalid bytes on either side are outside the window
+                    bytes = [0xFF, 97, 0xE2, 0x88, 0x86, 98, 0x80]
+
+                    when Str.from_utf8_range bytes { start: 1, len: 5 } is
+                        Ok val -> val
+                        Err _ -> ""
+                "#
+        ),
+        roc_std::RocStr::from("a∆b"),
+        roc_std::RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_from_utf8_range_invalid_index_is_relative() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                    when Str.from_utf8_range [97, 98, 99, 100, 0x80, 101] { start: 2, len: 4 } is
+                        Err (BadUtf8 {problem: InvalidStartByte, index: byte_index}) ->
+                            if byte_index == 2 then
+                                "a"
+                            else
+                                "b"
+                        _ -> ""
+                "#
+        ),
+        roc_std::RocStr::from("a"),
+        roc_std::RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_from_utf8_range_out_of_bounds() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                    when Str.from_utf8_range [97, 98, 99] { start: 2, len: 2 } is
+                        Err OutOfBounds -> "a"
+                        _ -> ""
+                "#
+        ),
+        roc_std::RocStr::from("a"),
+        roc_std::RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_from_scalar() {
+    assert_evals_to!(
+        "Str.from_scalar 0x1F426",
+        RocResult::ok(RocStr::from("🐦")),
+        RocResult<RocStr, ()>
+    );
+    assert_evals_to!(
+        "Str.from_scalar 0x20AC",
+        RocResult::ok(RocStr::from("€")),
+        RocResult<RocStr, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_from_scalar_rejects_surrogates() {
+    assert_evals_to!(
+        "Str.from_scalar 0xD800",
+        RocResult::err(()),
+        RocResult<RocStr, ()>
+    );
+    assert_evals_to!(
+        "Str.from_scalar 0xDFFF",
+        RocResult::err(()),
+        RocResult<RocStr, ()>
+    );
+    assert_evals_to!(
+        "Str.from_scalar 0x110000",
+        RocResult::err(()),
+        RocResult<RocStr, ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_append_scalar_multi_byte() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                    # long enough to be heap-allocated, so this appends in place
+                    when Str.append_scalar "a string that does not fit inline " 0x1F426 is
+                        Ok str -> str
+                        Err _ -> ""
+                "#
+        ),
+        RocStr::from("a string that does not fit inline 🐦"),
+        RocStr
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn str_from_utf8_fail_unexpected_end_of_sequence() {
@@ -1168,6 +1330,32 @@ fn str_join_comma_single() {
     assert_evals_to!(r#"Str.join_with ["1"] ", " "#, RocStr::from("1"), RocStr);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_join_single_big() {
+    assert_evals_to!(
+        r#"Str.join_with ["a string too long to be small!"] ", " "#,
+        RocStr::from("a string too long to be small!"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_join_empty_list() {
+    assert_evals_to!(r#"Str.join_with [] ", " "#, RocStr::from(""), RocStr);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_join_empty_separator() {
+    assert_evals_to!(
+        r#"Str.join_with ["10000000", "2000000", "30000000"] "" "#,
+        RocStr::from("10000000200000030000000"),
+        RocStr
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn str_to_utf8() {
@@ -1951,6 +2139,26 @@ fn str_split_on_last_not_found() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_split_first_empty_haystack() {
+    assert_evals_to!(
+        r#"Str.split_first "" "/""#,
+        RocResult::err(()),
+        RocResult<(RocStr, RocStr), ()>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_split_last_overlapping_delimiter() {
+    assert_evals_to!(
+        r#"Str.split_last "aaa" "aa""#,
+        RocResult::ok((RocStr::from(""), RocStr::from("a"))),
+        RocResult<(RocStr, RocStr), ()>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn str_split_on_overlapping_substring_1() {
@@ -2334,6 +2542,45 @@ fn str_contains_self() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_contains_overlapping() {
+    // A partial match ("abab" before the "a") must not hide the real one.
+    assert_evals_to!(
+        r#"
+        Str.contains "abababc" "ababc"
+        "#,
+        true,
+        bool
+    );
+
+    assert_evals_to!(
+        r#"
+        Str.contains "aaaa" "aaaaa"
+        "#,
+        false,
+        bool
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn str_contains_long_needle() {
+    // Long inputs take a different search path than short ones.
+    assert_evals_to!(
+        indoc!(
+            r#"
+            haystack = Str.repeat "abcdefghij" 20 |> Str.concat "the needle is right here" |> Str.concat (Str.repeat "klmnopqrst" 20)
+            needle = Str.concat (Str.repeat "abcdefghij" 3) "the needle is right here"
+
+            (Str.contains haystack needle, Str.contains haystack (Str.concat needle "!"))
+            "#
+        ),
+        (true, false),
+        (bool, bool)
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn str_drop_prefix() {
@@ -2368,6 +2615,22 @@ fn str_drop_prefix() {
         RocStr::from("BarThisIsDefinitelyAReallyLongAndNotaShortString"),
         RocStr
     );
+
+    assert_evals_to!(
+        r#"
+        Str.drop_prefix "foo" "foo"
+        "#,
+        RocStr::from(""),
+        RocStr
+    );
+
+    assert_evals_to!(
+        r#"
+        Str.drop_prefix "ThisIsDefinitelyAReallyLongAndNotaShortString" "ThisIsDefinitelyAReallyLongAndNotaShortString"
+        "#,
+        RocStr::from(""),
+        RocStr
+    );
 }
 
 #[test]
@@ -2404,6 +2667,22 @@ fn str_drop_suffix() {
         RocStr::from("BarThisIsDefinitelyAReallyLongAndNotaShortString"),
         RocStr
     );
+
+    assert_evals_to!(
+        r#"
+        Str.drop_suffix "foo" "foo"
+        "#,
+        RocStr::from(""),
+        RocStr
+    );
+
+    assert_evals_to!(
+        r#"
+        Str.drop_suffix "ThisIsDefinitelyAReallyLongAndNotaShortString" "ThisIsDefinitelyAReallyLongAndNotaShortString"
+        "#,
+        RocStr::from(""),
+        RocStr
+    );
 }
 
 #[test]
@@ -2455,3 +2734,42 @@ fn caseless_ascii_equals() {
         bool
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn str_compare() {
+    assert_evals_to!(r#"Str.compare("abc", "abc")"#, RocOrder::Eq, RocOrder);
+    assert_evals_to!(r#"Str.compare("ab", "abc")"#, RocOrder::Lt, RocOrder);
+    assert_evals_to!(r#"Str.compare("abc", "ab")"#, RocOrder::Gt, RocOrder);
+    assert_evals_to!(r#"Str.compare("", "a")"#, RocOrder::Lt, RocOrder);
+    assert_evals_to!(r#"Str.compare("z", "é")"#, RocOrder::Lt, RocOrder);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn str_compare_big_str() {
+    assert_evals_to!(
+        r#"
+        Str.compare("coffé coffé coffé coffé coffé coffé", "coffé coffé coffé coffé coffé coffé")
+        "#,
+        RocOrder::Eq,
+        RocOrder
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn str_compare_sort_with() {
+    assert_evals_to!(
+        r#"
+        List.sort_with(["banana", "apple", "app", "cherry"], Str.compare)
+        "#,
+        RocList::from_slice(&[
+            RocStr::from("app"),
+            RocStr::from("apple"),
+            RocStr::from("banana"),
+            RocStr::from("cherry"),
+        ]),
+        RocList<RocStr>
+    );
+}
@@ -28,6 +28,9 @@ Shape : [
     ## and the TypeId is the TypeId of StrConsList itself.
     RecursivePointer TypeId,
     Function RocFn,
+    ## A type the platform asked to keep opaque, so hosts only get its name,
+    ## size, and alignment (from Types), and never see its fields.
+    Opaque Str,
     # A zero-sized type, such as an empty record or a single-tag union with no payload
     Unit,
     Unsized,
@@ -123,7 +126,8 @@ RocStructFields : [
 
 RocSingleTagPayload : [
     HasClosure (List { name : Str, id : TypeId }),
-    HasNoClosure (List { id : TypeId }),
+    ## The payload's fields, named by the positional naming `roc glue` was given
+    HasNoClosure (List { name : Str, id : TypeId }),
 ]
 
 RocFn : {
@@ -1,4 +1,4 @@
-module [Types, shape, size, alignment, target, walk_shapes, entry_points]
+module [Types, shape, size, alignment, target, walk_shapes, walk_declarations, entry_points, is_root, nested_in, explicit_padding, payload_accessors]
 
 import Shape exposing [Shape]
 import TypeId exposing [TypeId, type_id_from_u64, type_id_to_u64]
@@ -24,6 +24,29 @@ Types := {
 
     ## Names and types of the entry points of the program (e.g. main_for_host)
     entrypoints : List Tuple1,
+
+    ## The tag unions that each payload struct (e.g. `MyUnion_Variant`) is a payload of.
+    ## Structurally identical payloads are merged, so several unions can share one.
+    payload_parents : List Tuple2,
+
+    ## The types a host would name: entry points, and types named by a Roc alias
+    roots : List TypeId,
+
+    ## Whether payload structs should be declared inside their union (see `nested_in`)
+    nest_payload_types : Bool,
+
+    ## Whether struct padding should be declared as explicit fields (see `explicit_padding`)
+    explicit_padding : Bool,
+
+    ## Whether tag unions should get accessors for their payloads (see `payload_accessors`)
+    payload_accessors : Bool,
+
+    ## Whether types should be declared in `sorted_by_name` order (see `walk_declarations`)
+    sort_by_name : Bool,
+
+    ## Every TypeId after its dependencies, and otherwise alphabetically by name.
+    ## This is only filled in when `sort_by_name` is set.
+    sorted_by_name : List TypeId,
     target : Target,
 }
     implements [Inspect, Encoding]
@@ -34,6 +57,35 @@ target = \@Types(types) -> types.target
 entry_points : Types -> List Tuple1
 entry_points = \@Types({ entrypoints }) -> entrypoints
 
+## Whether this is a type a host would name: an entry point, or a type named by a Roc
+## alias. The rest (e.g. the `MyUnion_Variant` payload structs of tag unions) are helpers
+## that only exist to describe a root, so a spec might make them private.
+is_root : Types, TypeId -> Bool
+is_root = \@Types(types), id -> List.contains(types.roots, id)
+
+## The tag union that this payload struct should be declared inside of, if the glue
+## was asked to nest payloads (e.g. `roc glue --nest-payloads`). Payloads which
+## several unions share aren't nested in any of them.
+nested_in : Types, TypeId -> Result TypeId [NotNested]
+nested_in = \@Types(types), id ->
+    if types.nest_payload_types then
+        when List.find_first(types.payload_parents, \T(payload, _) -> payload == id) is
+            Ok(T(_, [parent])) -> Ok(parent)
+            _ -> Err(NotNested)
+    else
+        Err(NotNested)
+
+## Whether the glue was asked to declare the padding between a struct's fields,
+## and after its last one, as explicit fields (e.g. `roc glue --explicit-padding`),
+## so the struct's layout doesn't depend on the host compiler's.
+explicit_padding : Types -> Bool
+explicit_padding = \@Types(types) -> types.explicit_padding
+
+## Whether the glue was asked to generate an accessor for each tag's payload
+## (e.g. `roc glue --payload-accessors`), which has nothing to return for other tags.
+payload_accessors : Types -> Bool
+payload_accessors = \@Types(types) -> types.payload_accessors
+
 walk_shapes : Types, state, (state, Shape, TypeId -> state) -> state
 walk_shapes = \@Types({ types: shapes }), original_state, update ->
     List.walk_with_index(shapes, original_state, \state, elem, index ->
@@ -41,6 +93,18 @@ walk_shapes = \@Types({ types: shapes }), original_state, update ->
 
         update(state, elem, id))
 
+## Like `walk_shapes`, except that if the glue was asked to sort declarations by name
+## (e.g. `roc glue --sort-by-name`), every type comes after the types it depends on,
+## and otherwise in alphabetical order. Adding or removing an unrelated type then
+## doesn't reorder the rest of the output.
+walk_declarations : Types, state, (state, Shape, TypeId -> state) -> state
+walk_declarations = \@Types(types), original_state, update ->
+    if types.sort_by_name then
+        List.walk(types.sorted_by_name, original_state, \state, id ->
+            update(state, shape(@Types(types), id), id))
+    else
+        walk_shapes(@Types(types), original_state, update)
+
 shape : Types, TypeId -> Shape
 shape = \@Types(types), id ->
     when List.get(types.types, type_id_to_u64(id)) is
@@ -59,6 +123,8 @@ alignment = \@Types(types), id ->
 
             crash("TypeId #${id_str} was not found in Types. This should never happen, and means there was a bug in `roc glue`. If you have time, please open an issue at <https://github.com/roc-lang/roc/issues>")
 
+## The size in bytes, which already includes any trailing padding, so it's also
+## the distance between consecutive elements of a `List` of this type.
 size : Types, TypeId -> U32
 size = \@Types(types), id ->
     when List.get(types.sizes, type_id_to_u64(id)) is
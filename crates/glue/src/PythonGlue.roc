@@ -0,0 +1,238 @@
+app [make_glue] { pf: platform "../platform/main.roc" }
+
+import pf.Types exposing [Types]
+import pf.File exposing [File]
+import pf.TypeId exposing [TypeId]
+
+## Generates a `ctypes` module per architecture, plus an `__init__.py` which
+## re-exports the one matching the interpreter it's imported into.
+make_glue : List Types -> Result (List File) Str
+make_glue = \types_by_arch ->
+    init_content =
+        List.walk(types_by_arch, init_header, \content, types ->
+            arch_str = arch_name((Types.target(types)).architecture)
+
+            Str.concat(
+                content,
+                """
+
+                if _arch == "${arch_str}":
+                    from .${arch_str} import *
+
+                """,
+            ))
+
+    types_by_arch
+    |> List.map(convert_types_to_file)
+    |> List.append({ name: "roc_app/__init__.py", content: init_content })
+    |> Ok
+
+convert_types_to_file : Types -> File
+convert_types_to_file = \types ->
+    arch_str = arch_name((Types.target(types)).architecture)
+
+    # Every class is declared before any `_fields_` are assigned, so structs can
+    # refer to each other no matter which order they were added to Types in.
+    declarations =
+        Types.walk_shapes(types, file_header, \buf, shape, id ->
+            when shape is
+                Struct({ name, fields: HasNoClosure(_) }) ->
+                    declare_struct(buf, name, Types.alignment(types, id))
+
+                TagUnionPayload({ name, fields: HasNoClosure(_) }) ->
+                    declare_struct(buf, name, Types.alignment(types, id))
+
+                TagUnion(Enumeration({ name, tags })) ->
+                    generate_enumeration(buf, name, tags)
+
+                _ ->
+                    buf)
+
+    content =
+        Types.walk_shapes(types, declarations, \buf, shape, id ->
+            when shape is
+                Struct({ name, fields: HasNoClosure(fields) }) ->
+                    generate_fields(buf, types, id, name, fields)
+
+                TagUnionPayload({ name, fields: HasNoClosure(fields) }) ->
+                    generate_fields(buf, types, id, name, fields)
+
+                _ ->
+                    buf)
+
+    { name: "roc_app/${arch_str}.py", content }
+
+## Padding is spelled out in `_fields_`, so ctypes' natural layout never needs to
+## add any of its own, and keeping it means `ctypes.alignment` matches Roc's. Fields
+## with no ctypes equivalent are arrays of an integer as aligned as they are, and
+## `_align_` covers what's more aligned than any integer (Python 3.13+ only).
+declare_struct : Str, Str, U32 -> Str
+declare_struct = \buf, name, alignment ->
+    align_str = Num.to_str(alignment)
+
+    Str.concat(
+        buf,
+        """
+
+
+        class ${name}(ctypes.Structure):
+            _align_ = ${align_str}
+
+        """,
+    )
+
+generate_enumeration : Str, Str, List Str -> Str
+generate_enumeration = \buf, name, tags ->
+    List.walk_with_index(tags, Str.concat(buf, "\n\nclass ${name}(enum.IntEnum):\n"), \accum, tag, index ->
+        index_str = Num.to_str(index)
+
+        Str.concat(accum, "${indent}${escape_tag(tag)} = ${index_str}\n"))
+
+## Lays the fields out at the same offsets Roc uses, padding explicitly up to
+## each field's alignment, and then up to the size of the whole struct.
+generate_fields : Str, Types, TypeId, Str, List { name : Str, id : TypeId } -> Str
+generate_fields = \buf, types, id, name, fields ->
+    { lines, offset } =
+        List.walk(fields, { lines: "", offset: 0 }, \state, field ->
+            if Types.size(types, field.id) == 0 then
+                state
+            else
+                field_offset = align_up(state.offset, Types.alignment(types, field.id))
+                field_type = ctypes_type(types, field.id)
+
+                {
+                    lines: state.lines
+                    |> add_padding(state.offset, field_offset)
+                    |> Str.concat("${indent}(\"${field.name}\", ${field_type}),\n"),
+                    offset: field_offset + Types.size(types, field.id),
+                })
+
+    all_lines = add_padding(lines, offset, Types.size(types, id))
+
+    Str.concat(buf, "\n${name}._fields_ = [\n${all_lines}]\n")
+
+add_padding : Str, U32, U32 -> Str
+add_padding = \lines, from, to ->
+    if to > from then
+        from_str = Num.to_str(from)
+        len_str = Num.to_str(to - from)
+
+        Str.concat(lines, "${indent}(\"_padding_${from_str}\", ctypes.c_uint8 * ${len_str}),\n")
+    else
+        lines
+
+ctypes_type : Types, TypeId -> Str
+ctypes_type = \types, id ->
+    when Types.shape(types, id) is
+        Num(I8) -> "ctypes.c_int8"
+        Num(U8) -> "ctypes.c_uint8"
+        Num(I16) -> "ctypes.c_int16"
+        Num(U16) -> "ctypes.c_uint16"
+        Num(I32) -> "ctypes.c_int32"
+        Num(U32) -> "ctypes.c_uint32"
+        Num(I64) -> "ctypes.c_int64"
+        Num(U64) -> "ctypes.c_uint64"
+        Num(F32) -> "ctypes.c_float"
+        Num(F64) -> "ctypes.c_double"
+        # ctypes has no 128-bit integers; Dec is an I128 scaled by 10^18.
+        Num(I128) | Num(U128) | Num(Dec) -> opaque_type(types, id)
+        Bool -> "ctypes.c_bool"
+        RocStr -> "RocStr"
+        RocList(_) -> "RocList"
+        RocBox(_) -> "ctypes.c_void_p"
+        RecursivePointer(_) -> "ctypes.c_void_p"
+        Struct({ name, fields: HasNoClosure(_) }) -> name
+        TagUnionPayload({ name, fields: HasNoClosure(_) }) -> name
+        TagUnion(Enumeration({ size })) -> "ctypes.c_uint${Num.to_str(size * 8)}"
+        # Anything else is passed through opaquely for now, so at least the
+        # fields around it still line up.
+        _ -> opaque_type(types, id)
+
+## A value ctypes has no type for, as an array of the widest unsigned integer its
+## alignment allows, so the struct holding it is still aligned the way Roc expects.
+opaque_type : Types, TypeId -> Str
+opaque_type = \types, id ->
+    { element, width } =
+        when Types.alignment(types, id) is
+            1 -> { element: "ctypes.c_uint8", width: 1 }
+            2 -> { element: "ctypes.c_uint16", width: 2 }
+            4 -> { element: "ctypes.c_uint32", width: 4 }
+            _ -> { element: "ctypes.c_uint64", width: 8 }
+
+    count = Num.to_str(Types.size(types, id) // width)
+
+    "${element} * ${count}"
+
+## Roc tags are capitalized, so these are the only ones which clash with Python keywords.
+escape_tag : Str -> Str
+escape_tag = \tag ->
+    when tag is
+        "None" | "True" | "False" -> Str.concat(tag, "_")
+        _ -> tag
+
+align_up : U32, U32 -> U32
+align_up = \offset, alignment ->
+    if alignment <= 1 then
+        offset
+    else
+        Num.div_ceil(offset, alignment) * alignment
+
+arch_name = \arch ->
+    when arch is
+        Aarch32 ->
+            "arm"
+
+        Aarch64 ->
+            "aarch64"
+
+        Wasm32 ->
+            "wasm32"
+
+        X86x32 ->
+            "x86"
+
+        X86x64 ->
+            "x86_64"
+
+indent = "    "
+
+init_header =
+    """
+    # ⚠️ GENERATED CODE ⚠️ - this entire file was generated by the `roc glue` CLI command
+
+    import platform
+
+    _arch = platform.machine().lower()
+    _arch = {"amd64": "x86_64", "arm64": "aarch64", "i386": "x86", "i686": "x86"}.get(_arch, _arch)
+    if _arch.startswith("armv"):
+        _arch = "arm"
+
+    """
+
+file_header =
+    """
+    # ⚠️ GENERATED CODE ⚠️ - this entire file was generated by the `roc glue` CLI command
+
+    import ctypes
+    import enum
+
+
+    class RocStr(ctypes.Structure):
+        # A negative capacity_or_alloc_ptr means a small string, whose bytes are
+        # stored inline in this struct rather than behind `bytes`.
+        _fields_ = [
+            ("bytes", ctypes.c_void_p),
+            ("length", ctypes.c_size_t),
+            ("capacity_or_alloc_ptr", ctypes.c_ssize_t),
+        ]
+
+
+    class RocList(ctypes.Structure):
+        # The element type isn't recorded here; cast `elements` to a pointer to it.
+        _fields_ = [
+            ("elements", ctypes.c_void_p),
+            ("length", ctypes.c_size_t),
+            ("capacity_or_alloc_ptr", ctypes.c_size_t),
+        ]
+
+    """
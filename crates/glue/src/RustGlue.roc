@@ -51,13 +51,18 @@ static_files = [
 convert_types_to_file : Types -> File
 convert_types_to_file = \types ->
     content =
-        Types.walk_shapes(types, file_header, \buf, type, id ->
+        Types.walk_declarations(types, file_header, \state, type, id ->
+            buf = generate_nested_payloads(state, types, id)
+
             when type is
                 Struct({ name, fields }) ->
                     generate_struct(buf, types, id, name, fields, Public)
 
                 TagUnionPayload({ name, fields }) ->
-                    generate_struct(buf, types, id, name, name_tag_union_payload_fields(fields), Public)
+                    when Types.nested_in(types, id) is
+                        # This gets declared by generate_nested_payloads, along with its union.
+                        Ok(_) -> buf
+                        Err(NotNested) -> generate_struct(buf, types, id, name, name_tag_union_payload_fields(fields), Public)
 
                 TagUnion(Enumeration({ name, tags, size })) ->
                     generate_enumeration(buf, types, type, name, tags, size)
@@ -98,6 +103,9 @@ convert_types_to_file = \types ->
                     # so no extra work needs to happen.
                     buf
 
+                Opaque(name) ->
+                    generate_opaque_struct(buf, types, id, name)
+
                 Unit
                 | Unsized
                 | EmptyTagUnion
@@ -121,6 +129,68 @@ convert_types_to_file = \types ->
         content: content |> generate_entry_points(types),
     }
 
+## With `roc glue --nest-payloads`, a tag union's payload structs are declared in a
+## module named after it, so `MyUnion_Variant` becomes `my_union::Variant`.
+generate_nested_payloads : Str, Types, TypeId -> Str
+generate_nested_payloads = \buf, types, union_id ->
+    nested =
+        Types.walk_shapes(types, "", \accum, type, id ->
+            when (type, nested_payload_path(types, id)) is
+                (TagUnionPayload({ fields }), Ok({ parent, name })) if parent == union_id ->
+                    generate_struct(accum, types, id, name, name_tag_union_payload_fields(fields), Public)
+
+                _ -> accum)
+
+    if Str.is_empty(nested) then
+        buf
+    else
+        module = nested_payload_module(types, union_id)
+
+        "${buf}pub mod ${module} {\n${indent}use super::*;\n\n${nested}}\n\n"
+
+## Where a payload struct is declared when it's nested in its union: in the union's
+## module, under its tag's name (that is, its own name minus the `MyUnion_` prefix).
+nested_payload_path : Types, TypeId -> Result { parent : TypeId, name : Str } [NotNested]
+nested_payload_path = \types, id ->
+    when (Types.nested_in(types, id), Types.shape(types, id)) is
+        (Ok(parent), TagUnionPayload({ name })) ->
+            union_name = tag_union_name(types, parent)
+
+            Ok({ parent, name: Str.drop_prefix(name, "${union_name}_") })
+
+        _ -> Err(NotNested)
+
+## Rust modules are snake_case, so the payloads of `MyUnion` go in `my_union`.
+nested_payload_module : Types, TypeId -> Str
+nested_payload_module = \types, union_id ->
+    union_name = tag_union_name(types, union_id)
+
+    Str.to_utf8(union_name)
+    |> List.walk([], \bytes, byte ->
+        if byte >= 'A' and byte <= 'Z' then
+            lower = byte + 32
+
+            when List.last(bytes) is
+                Ok(prev) if prev != '_' -> List.concat(bytes, ['_', lower])
+                _ -> List.append(bytes, lower)
+        else
+            List.append(bytes, byte))
+    |> Str.from_utf8
+    |> Result.with_default(union_name)
+    |> escape_kw
+
+tag_union_name : Types, TypeId -> Str
+tag_union_name = \types, id ->
+    when Types.shape(types, id) is
+        TagUnion(NonRecursive({ name })) -> name
+        TagUnion(Recursive({ name })) -> name
+        TagUnion(Enumeration({ name })) -> name
+        TagUnion(NullableWrapped({ name })) -> name
+        TagUnion(NullableUnwrapped({ name })) -> name
+        TagUnion(NonNullableUnwrapped({ name })) -> name
+        TagUnion(SingleTagStruct({ name })) -> name
+        _ -> crash("Only tag unions have payloads to nest, but tag_union_name was given something else.")
+
 generate_entry_points : Str, Types -> Str
 generate_entry_points = \buf, types ->
     List.walk(Types.entry_points(types), buf, \accum, T(name, id) -> generate_entry_point(accum, types, name, id))
@@ -296,6 +366,35 @@ generate_function = \buf, types, roc_fn ->
     """
     |> generate_roc_refcounted(types, Function(roc_fn), name)
 
+## The platform marked this type opaque, so the host only gets to know its
+## size and alignment.
+generate_opaque_struct : Str, Types, TypeId, Str -> Str
+generate_opaque_struct = \buf, types, id, name ->
+    escaped_name = escape_kw(name)
+    size = Num.to_str(Types.size(types, id))
+    align = Num.to_str(Types.alignment(types, id))
+
+    """
+    ${buf}
+
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+    #[repr(C, align(${align}))]
+    pub struct ${escaped_name}([u8; ${size}]);
+
+    const _SIZE_CHECK_${escaped_name}: () = assert!(core::mem::size_of::<${escaped_name}>() == ${size});
+    const _ALIGN_CHECK_${escaped_name}: () = assert!(core::mem::align_of::<${escaped_name}>() == ${align});
+
+    impl Default for ${escaped_name} {
+        fn default() -> Self {
+            Self([0; ${size}])
+        }
+    }
+
+    roc_refcounted_noop_impl!(${escaped_name});
+
+
+    """
+
 generate_struct : Str, Types, TypeId, _, _, _ -> Str
 generate_struct = \buf, types, id, name, struct_fields, visibility ->
     escaped_name = escape_kw(name)
@@ -316,11 +415,41 @@ generate_struct = \buf, types, id, name, struct_fields, visibility ->
 
     struct_type = Types.shape(types, id)
 
+    # Roc sorts fields by descending alignment, so repr(C) should reproduce its
+    # layout exactly; make the host compiler confirm that rather than trusting it.
+    # (Tag union payloads are recorded with the size of their whole union.)
+    layout_checks =
+        when (struct_type, struct_fields) is
+            (Struct(_), HasNoClosure(_)) ->
+                size_of_self = Num.to_str(Types.size(types, id))
+                align_of_self = Num.to_str(Types.alignment(types, id))
+
+                """
+                const _SIZE_CHECK_${escaped_name}: () = assert!(core::mem::size_of::<${escaped_name}>() == ${size_of_self});
+                const _ALIGN_CHECK_${escaped_name}: () = assert!(core::mem::align_of::<${escaped_name}>() == ${align_of_self});
+
+
+                """
+
+            _ ->
+                ""
+
+    # Payloads are recorded with the size of their whole union, so only plain structs
+    # know where their trailing padding ends.
+    field_lines =
+        when (struct_type, struct_fields) is
+            (Struct(_), HasNoClosure(fields)) if Types.explicit_padding(types) ->
+                generate_padded_struct_fields("", types, id, fields)
+
+            _ ->
+                generate_struct_fields("", types, Public, struct_fields)
+
     buf
     |> generate_derive_str(types, struct_type, IncludeDebug)
     |> Str.concat("#[repr(${repr})]\n${pub}struct ${escaped_name} {\n")
-    |> generate_struct_fields(types, Public, struct_fields)
+    |> Str.concat(field_lines)
     |> Str.concat("}\n\n")
+    |> Str.concat(layout_checks)
     |> generate_roc_refcounted(types, struct_type, escaped_name)
 
 generate_struct_fields = \buf, types, visibility, struct_fields ->
@@ -331,6 +460,43 @@ generate_struct_fields = \buf, types, visibility, struct_fields ->
         HasClosure(fields) ->
             List.walk(fields, buf, generate_struct_field_without_closure(types, visibility))
 
+## With `roc glue --explicit-padding`, the gaps Roc leaves between fields, and after
+## the last one, are declared as `_padN: [u8; N]` fields. That way the layout is
+## spelled out, rather than relying on the host compiler to pad the same way.
+generate_padded_struct_fields : Str, Types, TypeId, List { name : Str, id : TypeId } -> Str
+generate_padded_struct_fields = \buf, types, id, fields ->
+    field_line = generate_struct_field_without_closure(types, Public)
+
+    end_of_fields =
+        List.walk(fields, { buf, offset: 0, pads: 0 }, \state, field ->
+            if Types.size(types, field.id) == 0 then
+                { state & buf: field_line(state.buf, field) }
+            else
+                field_offset = next_multiple_of(state.offset, Types.alignment(types, field.id))
+                padded = pad_to(state, field_offset)
+
+                {
+                    buf: field_line(padded.buf, field),
+                    offset: field_offset + Types.size(types, field.id),
+                    pads: padded.pads,
+                })
+
+    (pad_to(end_of_fields, Types.size(types, id))).buf
+
+pad_to : { buf : Str, offset : U32, pads : U32 }, U32 -> { buf : Str, offset : U32, pads : U32 }
+pad_to = \state, offset ->
+    if offset > state.offset then
+        index = Num.to_str(state.pads)
+        len = Num.to_str(offset - state.offset)
+
+        {
+            buf: Str.concat(state.buf, "${indent}pub _pad${index}: [u8; ${len}],\n"),
+            offset,
+            pads: state.pads + 1,
+        }
+    else
+        state
+
 generate_struct_field_without_closure = \types, visibility ->
     \accum, { name: field_name, id } ->
         type_str = type_name(types, id)
@@ -344,17 +510,36 @@ generate_struct_field_without_closure = \types, visibility ->
         Str.concat(accum, "${indent}${pub} ${escaped_field_name}: ${type_str},\n")
 
 name_tag_union_payload_fields = \payload_fields ->
-    # Tag union payloads have numbered fields, so we prefix them
-    # with an "f" because Rust doesn't allow struct fields to be numbers.
     when payload_fields is
         HasNoClosure(fields) ->
-            renamed_fields = List.map(fields, \{ name, id } -> { name: "f${name}", id })
+            renamed_fields = List.map(fields, \{ name, id } -> { name: positional_field_ident(name), id })
             HasNoClosure(renamed_fields)
 
         HasClosure(fields) ->
-            renamed_fields = List.map(fields, \{ name, id, accessors } -> { name: "f${name}", id, accessors })
+            renamed_fields = List.map(fields, \{ name, id, accessors } -> { name: positional_field_ident(name), id, accessors })
             HasClosure(renamed_fields)
 
+## Tag union payload fields are named by position, and with the default naming
+## that's a bare number, so we prefix those with an "f" because Rust doesn't
+## allow struct fields to be numbers. Other namings (e.g. `_0`) are kept as-is.
+positional_field_ident : Str -> Str
+positional_field_ident = \name ->
+    when Str.to_utf8(name) is
+        [first, ..] if first >= '0' and first <= '9' -> "f${name}"
+        _ -> name
+
+## The fields of a tag's payload struct, named the way `generate_struct` declares them.
+payload_struct_fields : Types, TypeId -> List { name : Str, id : TypeId }
+payload_struct_fields = \types, payload ->
+    when Types.shape(types, payload) is
+        TagUnionPayload({ fields }) ->
+            when name_tag_union_payload_fields(fields) is
+                HasNoClosure(xs) -> List.map(xs, \{ name, id } -> { name, id })
+                HasClosure(xs) -> List.map(xs, \{ name, id } -> { name, id })
+
+        _ ->
+            []
+
 generate_enumeration = \buf, types, enum_type, name, tags, tag_bytes ->
     escaped_name = escape_kw(name)
 
@@ -818,34 +1003,22 @@ generate_non_nullable_unwrapped = \buf, types, name, tag_name, payload, discrimi
     escaped_name = escape_kw(name)
     discriminant_name = "discriminant_${escaped_name}"
 
-    payload_fields =
-        when Types.shape(types, payload) is
-            TagUnionPayload({ fields }) ->
-                when fields is
-                    HasNoClosure(xs) -> List.map(xs, .id)
-                    HasClosure(xs) -> List.map(xs, .id)
+    payload_fields = payload_struct_fields(types, payload)
 
-            _ ->
-                []
-
-    payload_field_names =
-        comma_separated("", payload_fields, \_, i ->
-            n = Num.to_str(i)
-            "f${n}")
+    payload_field_names = comma_separated("", payload_fields, \{ name: field }, _ -> field)
 
     constructor_arguments =
-        comma_separated("", payload_fields, \id, i ->
-            n = Num.to_str(i)
+        comma_separated("", payload_fields, \{ name: field, id }, _ ->
             type = type_name(types, id)
-            "f${n}: ${type}")
+            "${field}: ${type}")
 
     debug_fields =
         payload_fields
-        |> List.map_with_index(\_, i ->
-            n = Num.to_str(i)
-            ".field(&node.f${n})")
+        |> List.map(\{ name: field } -> ".field(&node.${field})")
         |> Str.join_with("")
 
+    payload_type = type_name(types, payload)
+
     buf1 = buf |> generate_discriminant(types, discriminant_name, [tag_name], discriminant_size)
 
     union_type = TagUnion(NonNullableUnwrapped({ name, tag_name, payload }))
@@ -855,11 +1028,11 @@ generate_non_nullable_unwrapped = \buf, types, name, tag_name, payload, discrimi
 
     #[repr(transparent)]
     #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
-    pub struct ${escaped_name}(roc_std::RocBox<${name}_${tag_name}>);
+    pub struct ${escaped_name}(roc_std::RocBox<${payload_type}>);
 
     impl ${escaped_name} {
         pub fn ${tag_name}(${constructor_arguments}) -> Self {
-            let payload = ${name}_${tag_name} { ${payload_field_names} };
+            let payload = ${payload_type} { ${payload_field_names} };
 
             Self(roc_std::RocBox::new(payload))
         }
@@ -902,21 +1075,11 @@ generate_recursive_tag_union = \buf, types, id, tag_union_name, tags, discrimina
     is_function = \{ name: tag_name, payload: opt_payload }, index ->
         payload_fields =
             when opt_payload is
-                Some(payload) ->
-                    when Types.shape(types, payload) is
-                        TagUnionPayload({ fields }) ->
-                            when fields is
-                                HasNoClosure(xs) -> List.map(xs, .id)
-                                HasClosure(xs) -> List.map(xs, .id)
-
-                        _ ->
-                            []
-
-                None ->
-                    []
+                Some(payload) -> payload_struct_fields(types, payload)
+                None -> []
 
         field_getters =
-            List.walk(payload_fields, { i: 0, accum: "" }, \{ i, accum }, field_type_id ->
+            List.walk(payload_fields, { i: 0, accum: "" }, \{ i, accum }, { id: field_type_id } ->
                 field_type_name = type_name(types, field_type_id)
                 field_index = Num.to_str(i)
 
@@ -941,16 +1104,17 @@ generate_recursive_tag_union = \buf, types, id, tag_union_name, tags, discrimina
                 })
             |> .accum
 
-        payload_field_names =
-            comma_separated("", payload_fields, \_, i ->
-                n = Num.to_str(i)
-                "f${n}")
+        payload_field_names = comma_separated("", payload_fields, \{ name: field }, _ -> field)
 
         constructor_arguments =
-            comma_separated("", payload_fields, \payload_id, i ->
-                n = Num.to_str(i)
+            comma_separated("", payload_fields, \{ name: field, id: payload_id }, _ ->
                 type = type_name(types, payload_id)
-                "f${n}: ${type}")
+                "${field}: ${type}")
+
+        payload_type =
+            when opt_payload is
+                Some(payload) -> type_name(types, payload)
+                None -> "()"
 
         fix_manually_drop =
             when opt_payload is
@@ -984,7 +1148,7 @@ generate_recursive_tag_union = \buf, types, id, tag_union_name, tags, discrimina
                 pub fn ${tag_name}(${constructor_arguments}) -> Self {
                     let tag_id = discriminant_${escaped_name}::${tag_name};
 
-                    let payload = ${escaped_name}_${tag_name} { ${payload_field_names} } ;
+                    let payload = ${payload_type} { ${payload_field_names} } ;
 
                     let union_payload = union_${escaped_name} { ${tag_name}: ${fix_manually_drop} };
 
@@ -994,7 +1158,7 @@ generate_recursive_tag_union = \buf, types, id, tag_union_name, tags, discrimina
                 }
             ${field_getters}
 
-                pub fn get_${tag_name}(mut self) -> ${escaped_name}_${tag_name} {
+                pub fn get_${tag_name}(mut self) -> ${payload_type} {
                     debug_assert!(self.is_${tag_name}());
 
                     unsafe { core::mem::ManuallyDrop::take(&mut self.ptr_read_union().${tag_name}) }
@@ -1084,24 +1248,12 @@ generate_recursive_tag_union = \buf, types, id, tag_union_name, tags, discrimina
         else
             payload_fields =
                 when opt_payload is
-                    Some(payload) ->
-                        when Types.shape(types, payload) is
-                            TagUnionPayload({ fields }) ->
-                                when fields is
-                                    HasNoClosure(xs) -> List.map(xs, .id)
-                                    HasClosure(xs) -> List.map(xs, .id)
-
-                            _ ->
-                                []
-
-                    None ->
-                        []
+                    Some(payload) -> payload_struct_fields(types, payload)
+                    None -> []
 
             debug_fields =
                 payload_fields
-                |> List.map_with_index(\_, i ->
-                    n = Num.to_str(i)
-                    ".field(&payload_union.${tag_name}.f${n})")
+                |> List.map(\{ name: field } -> ".field(&payload_union.${tag_name}.${field})")
                 |> Str.join_with("")
 
             """
@@ -1403,34 +1555,37 @@ comma_separated = \buf, items, step ->
 
 generate_nullable_unwrapped : Str, Types, TypeId, Str, Str, Str, TypeId, [FirstTagIsNull, SecondTagIsNull] -> Str
 generate_nullable_unwrapped = \buf, types, tag_unionid, name, null_tag, non_null_tag, non_null_payload, which_tag_is_null ->
-    payload_fields =
-        when Types.shape(types, non_null_payload) is
-            TagUnionPayload({ fields }) ->
-                when fields is
-                    HasNoClosure(xs) -> List.map(xs, .id)
-                    HasClosure(xs) -> List.map(xs, .id)
+    payload_fields = payload_struct_fields(types, non_null_payload)
 
-            _ ->
-                []
-
-    payload_field_names =
-        comma_separated("", payload_fields, \_, i ->
-            n = Num.to_str(i)
-            "f${n}")
+    payload_field_names = comma_separated("", payload_fields, \{ name: field }, _ -> field)
 
     constructor_arguments =
-        comma_separated("", payload_fields, \id, i ->
-            n = Num.to_str(i)
+        comma_separated("", payload_fields, \{ name: field, id }, _ ->
             type = type_name(types, id)
-            "f${n}: ${type}")
+            "${field}: ${type}")
 
     debug_fields =
         payload_fields
-        |> List.map_with_index(\_, i ->
-            n = Num.to_str(i)
-            ".field(&node.f${n})")
+        |> List.map(\{ name: field } -> ".field(&node.${field})")
         |> Str.join_with("")
 
+    payload_type = type_name(types, non_null_payload)
+
+    payload_accessor =
+        if Types.payload_accessors(types) then
+            """
+
+                pub fn get_${non_null_tag}(&self) -> Option<&${payload_type}> {
+                    if self.is_${null_tag}() {
+                        None
+                    } else {
+                        Some(unsafe { &*self.0 })
+                    }
+                }
+            """
+        else
+            ""
+
     union_type = TagUnion(NullableUnwrapped({ name, null_tag, non_null_tag, non_null_payload, which_tag_is_null }))
     discriminant =
         when which_tag_is_null is
@@ -1460,7 +1615,7 @@ generate_nullable_unwrapped = \buf, types, tag_unionid, name, null_tag, non_null
 
     #[derive(PartialOrd, Ord)]
     #[repr(C)]
-    pub struct ${name}(*mut ${name}_${non_null_tag});
+    pub struct ${name}(*mut ${payload_type});
 
     ${discriminant}
 
@@ -1473,7 +1628,7 @@ generate_nullable_unwrapped = \buf, types, tag_unionid, name, null_tag, non_null
         }
 
         pub fn ${non_null_tag}(${constructor_arguments}) -> Self {
-            let payload = ${name}_${non_null_tag} { ${payload_field_names} };
+            let payload = ${payload_type} { ${payload_field_names} };
 
             let ptr = unsafe { roc_std::RocBox::leak(roc_std::RocBox::new(payload)) };
 
@@ -1495,6 +1650,7 @@ generate_nullable_unwrapped = \buf, types, tag_unionid, name, null_tag, non_null
         pub fn is_${non_null_tag}(&self) -> bool {
             !self.0.is_null()
         }
+    ${payload_accessor}
     }
 
     impl core::fmt::Debug for ${name} {
@@ -1516,7 +1672,7 @@ generate_nullable_unwrapped = \buf, types, tag_unionid, name, null_tag, non_null
                 use std::ops::Deref;
 
                 let node_ref = core::mem::ManuallyDrop::new(unsafe { std::ptr::read(self.0) });
-                let payload : ${name}_${non_null_tag} = (node_ref.deref()).clone();
+                let payload : ${payload_type} = (node_ref.deref()).clone();
 
                 let ptr = unsafe { roc_std::RocBox::leak(roc_std::RocBox::new(payload)) };
 
@@ -1575,10 +1731,7 @@ generate_single_tag_struct = \buf, types, name, tag_name, payload ->
     when payload is
         HasNoClosure(fields) ->
             as_struct_fields =
-                List.map_with_index(fields, \{ id }, index ->
-                    index_str = Num.to_str(index)
-
-                    { name: "f${index_str}", id })
+                List.map(fields, \{ name: field_name, id } -> { name: positional_field_ident(field_name), id })
                 |> HasNoClosure
             as_struct_type =
                 Struct({
@@ -1615,18 +1768,12 @@ generate_multi_element_single_tag_struct = \buf, types, name, tag_name, payload_
             payload_fields
             |> List.map(\{ id } ->
                 type_name(types, id))
-        args =
-            field_types
-            |> List.map_with_index(\field_type_name, index ->
-                index_str = Num.to_str(index)
-
-                "f${index_str}: ${field_type_name}")
         fields =
             payload_fields
-            |> List.map_with_index(\_, index ->
-                index_str = Num.to_str(index)
-
-                "f${index_str}")
+            |> List.map(\{ name } -> positional_field_ident(name))
+        args =
+            List.map2(fields, field_types, \field, field_type_name ->
+                "${field}: ${field_type_name}")
 
         field_accesses =
             fields
@@ -1715,10 +1862,10 @@ generate_multi_element_single_tag_struct = \buf, types, name, tag_name, payload_
     )
     |> \b ->
         payload_fields
-        |> List.map_with_index(\_, index ->
-            index_str = Num.to_str(index)
+        |> List.map(\{ name: field_name } ->
+            field = positional_field_ident(field_name)
 
-            "${indent}${indent}${indent}${indent}.field(&self.f${index_str})\n")
+            "${indent}${indent}${indent}${indent}.field(&self.${field})\n")
         |> List.walk(b, Str.concat)
     |> Str.concat(
         """
@@ -1807,7 +1954,7 @@ can_support_partial_eq_ord = \types, type ->
             can_support_partial_eq_ord(types, runtime_representation)
 
         Unsized -> Bool.false
-        Unit | EmptyTagUnion | Bool | Num(_) | TagUnion(Enumeration(_)) -> Bool.true
+        Unit | EmptyTagUnion | Bool | Num(_) | TagUnion(Enumeration(_)) | Opaque(_) -> Bool.true
         RocStr -> Bool.true
         RocList(inner) | RocSet(inner) | RocBox(inner) ->
             inner_type = Types.shape(types, inner)
@@ -1875,7 +2022,8 @@ can_derive_copy = \types, type ->
 
         # unsized values are heap-allocated
         Unsized -> Bool.false
-        Unit | EmptyTagUnion | Bool | Num(_) | TagUnion(Enumeration(_)) -> Bool.true
+        # opaque values never contain refcounted data, so they're just bytes
+        Unit | EmptyTagUnion | Bool | Num(_) | TagUnion(Enumeration(_)) | Opaque(_) -> Bool.true
         RocStr | RocList(_) | RocDict(_, _) | RocSet(_) | RocBox(_) | TagUnion(NullableUnwrapped(_)) | TagUnion(NullableWrapped(_)) | TagUnion(Recursive(_)) | TagUnion(NonNullableUnwrapped(_)) | RecursivePointer(_) -> Bool.false
         TagUnion(SingleTagStruct({ payload: HasNoClosure(fields) })) ->
             List.all(fields, \{ id } -> can_derive_copy(types, Types.shape(types, id)))
@@ -1902,7 +2050,7 @@ can_derive_copy = \types, type ->
 cannot_support_default = \types, type ->
     when type is
         Unit | Unsized | EmptyTagUnion | TagUnion(_) | RocResult(_, _) | RecursivePointer(_) | Function(_) -> Bool.true
-        RocStr | Bool | Num(_) -> Bool.false
+        RocStr | Bool | Num(_) | Opaque(_) -> Bool.false
         RocList(id) | RocSet(id) | RocBox(id) ->
             cannot_support_default(types, Types.shape(types, id))
 
@@ -1928,7 +2076,7 @@ has_float_help = \types, type, do_not_recurse ->
                 F32 | F64 -> Bool.true
                 _ -> Bool.false
 
-        Unit | Unsized | EmptyTagUnion | RocStr | Bool | TagUnion(Enumeration(_)) | Function(_) -> Bool.false
+        Unit | Unsized | EmptyTagUnion | RocStr | Bool | TagUnion(Enumeration(_)) | Function(_) | Opaque(_) -> Bool.false
         RocList(id) | RocSet(id) | RocBox(id) ->
             has_float_help(types, Types.shape(types, id), do_not_recurse)
 
@@ -2121,7 +2269,7 @@ generate_roc_refcounted_named_fields = \types, fields, mode, wrapper ->
         escaped_name = escape_kw(name)
         when wrapper is
             Struct -> escaped_name
-            Tag -> "f${escaped_name}"
+            Tag -> positional_field_ident(escaped_name)
 
     method_name =
         when mode is
@@ -2152,7 +2300,7 @@ contains_refcounted_help = \types, type, do_not_recurse ->
         RocStr | RocList(_) | RocSet(_) | RocDict(_, _) | RocBox(_) | RecursivePointer(_) ->
             Bool.true
 
-        Unit | Unsized | EmptyTagUnion | Num(_) | Bool | TagUnion(Enumeration(_)) ->
+        Unit | Unsized | EmptyTagUnion | Num(_) | Bool | TagUnion(Enumeration(_)) | Opaque(_) ->
             Bool.false
 
         Function({ lambda_set: id }) ->
@@ -2235,7 +2383,12 @@ type_name = \types, id ->
             type_name(types, content)
 
         Struct({ name }) -> escape_kw(name)
-        TagUnionPayload({ name }) -> escape_kw(name)
+        TagUnionPayload({ name }) ->
+            when nested_payload_path(types, id) is
+                Ok(nested) -> "${nested_payload_module(types, nested.parent)}::${escape_kw(nested.name)}"
+                Err(NotNested) -> escape_kw(name)
+
+        Opaque(name) -> escape_kw(name)
         TagUnion(NonRecursive({ name })) -> escape_kw(name)
         TagUnion(Recursive({ name })) -> escape_kw(name)
         TagUnion(Enumeration({ name })) -> escape_kw(name)
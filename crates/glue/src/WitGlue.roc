@@ -0,0 +1,293 @@
+app [make_glue] { pf: platform "../platform/main.roc" }
+
+import pf.Types exposing [Types]
+import pf.Shape exposing [RocNum, RocTagUnion]
+import pf.File exposing [File]
+import pf.TypeId exposing [TypeId]
+
+## Generates a WebAssembly Interface Types (`.wit`) package describing the
+## platform's types, so a Roc module can be used as a wasm component.
+##
+## WIT doesn't depend on the architecture, so only one set of types is used:
+## the wasm32 one if it was generated, otherwise whichever came first.
+make_glue : List Types -> Result (List File) Str
+make_glue = \types_by_arch ->
+    chosen =
+        List.find_first(types_by_arch, \types -> (Types.target(types)).architecture == Wasm32)
+        |> Result.on_err(\_ -> List.first(types_by_arch))
+
+    when chosen is
+        Ok(types) ->
+            convert_types_to_file(types) |> Result.map_ok(\file -> [file])
+
+        Err(_) ->
+            Ok([])
+
+convert_types_to_file : Types -> Result File Str
+convert_types_to_file = \types ->
+    { defs, recursive } =
+        Types.walk_shapes(types, { defs: "", recursive: [] }, \state, shape, _id ->
+            when shape is
+                Struct({ name, fields: HasNoClosure(fields) }) ->
+                    { state & defs: generate_record(state.defs, types, name, fields) }
+
+                TagUnion(Enumeration({ name, tags })) ->
+                    { state & defs: generate_enum(state.defs, name, tags) }
+
+                TagUnion(NonRecursive({ name, tags })) ->
+                    { state & defs: generate_variant(state.defs, types, name, tags) }
+
+                TagUnion(SingleTagStruct({ name, tag_name, payload: HasNoClosure(fields) })) ->
+                    payload_type = tuple_type(types, List.map(fields, .id))
+
+                    { state & defs: generate_cases(state.defs, name, [{ name: tag_name, payload: Some(payload_type) }]) }
+
+                TagUnion(Recursive({ name })) | TagUnion(NullableWrapped({ name })) | TagUnion(NonNullableUnwrapped({ name })) | TagUnion(NullableUnwrapped({ name })) ->
+                    { state & recursive: List.append(state.recursive, name) }
+
+                _ ->
+                    state)
+
+    if List.is_empty(recursive) then
+        content =
+            if uses_128_bit_ints(types) then
+                Str.concat(file_header, "${int128_records}${defs}}\n")
+            else
+                Str.concat(file_header, "${defs}}\n")
+
+        Ok({ name: "roc-app.wit", content })
+    else
+        Err("WIT has no recursive types, so these can't be described: ${Str.join_with(recursive, ", ")}")
+
+generate_record : Str, Types, Str, List { name : Str, id : TypeId } -> Str
+generate_record = \buf, types, name, fields ->
+    field_lines =
+        fields
+        |> List.drop_if(\field -> Types.size(types, field.id) == 0)
+        |> List.map(\field -> "${indent}${indent}${wit_name(field.name)}: ${wit_type(types, field.id)},\n")
+        |> Str.join_with("")
+
+    Str.concat(buf, "\n${indent}record ${wit_name(name)} {\n${field_lines}${indent}}\n")
+
+generate_enum : Str, Str, List Str -> Str
+generate_enum = \buf, name, tags ->
+    case_lines =
+        tags
+        |> List.map(\tag -> "${indent}${indent}${wit_name(tag)},\n")
+        |> Str.join_with("")
+
+    Str.concat(buf, "\n${indent}enum ${wit_name(name)} {\n${case_lines}${indent}}\n")
+
+generate_variant : Str, Types, Str, List { name : Str, payload : [Some TypeId, None] } -> Str
+generate_variant = \buf, types, name, tags ->
+    cases =
+        List.map(tags, \tag ->
+            when tag.payload is
+                Some(id) if Types.size(types, id) > 0 ->
+                    { name: tag.name, payload: Some(wit_type(types, id)) }
+
+                _ ->
+                    { name: tag.name, payload: None })
+
+    generate_cases(buf, name, cases)
+
+generate_cases : Str, Str, List { name : Str, payload : [Some Str, None] } -> Str
+generate_cases = \buf, name, cases ->
+    case_lines =
+        cases
+        |> List.map(\case ->
+            when case.payload is
+                Some(payload_type) -> "${indent}${indent}${wit_name(case.name)}(${payload_type}),\n"
+                None -> "${indent}${indent}${wit_name(case.name)},\n")
+        |> Str.join_with("")
+
+    Str.concat(buf, "\n${indent}variant ${wit_name(name)} {\n${case_lines}${indent}}\n")
+
+wit_type : Types, TypeId -> Str
+wit_type = \types, id ->
+    when Types.shape(types, id) is
+        RocStr -> "string"
+        Bool -> "bool"
+        Num(num) -> num_type(num)
+        RocList(elem) -> "list<${wit_type(types, elem)}>"
+        RocSet(elem) -> "list<${wit_type(types, elem)}>"
+        RocDict(key, value) -> "list<tuple<${wit_type(types, key)}, ${wit_type(types, value)}>>"
+        RocResult(ok, err) -> result_type(types, ok, err)
+        # WIT values are always copied across the boundary, so a Box is just its contents.
+        RocBox(inner) -> wit_type(types, inner)
+        Struct({ name }) -> wit_name(name)
+        TagUnion(union) -> wit_name(union_name(union))
+        TagUnionPayload({ fields: HasNoClosure(fields) }) -> tuple_type(types, List.map(fields, .id))
+        RecursivePointer(union) -> wit_type(types, union)
+        Unit -> "tuple<>"
+        # Functions and unsized types have no WIT equivalent; passing their
+        # bytes through at least keeps the interface valid.
+        _ -> "list<u8>"
+
+result_type : Types, TypeId, TypeId -> Str
+result_type = \types, ok, err ->
+    ok_is_unit = Types.size(types, ok) == 0
+    err_is_unit = Types.size(types, err) == 0
+
+    if ok_is_unit and err_is_unit then
+        "result"
+    else if err_is_unit then
+        "result<${wit_type(types, ok)}>"
+    else if ok_is_unit then
+        "result<_, ${wit_type(types, err)}>"
+    else
+        "result<${wit_type(types, ok)}, ${wit_type(types, err)}>"
+
+tuple_type : Types, List TypeId -> Str
+tuple_type = \types, ids ->
+    when ids is
+        [id] -> wit_type(types, id)
+        _ -> "tuple<${ids |> List.map(\id -> wit_type(types, id)) |> Str.join_with(", ")}>"
+
+num_type : RocNum -> Str
+num_type = \num ->
+    when num is
+        I8 -> "s8"
+        U8 -> "u8"
+        I16 -> "s16"
+        U16 -> "u16"
+        I32 -> "s32"
+        U32 -> "u32"
+        I64 -> "s64"
+        U64 -> "u64"
+        F32 -> "f32"
+        F64 -> "f64"
+        U128 -> "roc-u128"
+        # Dec is an I128 scaled by 10^18.
+        I128 | Dec -> "roc-i128"
+
+uses_128_bit_ints : Types -> Bool
+uses_128_bit_ints = \types ->
+    Types.walk_shapes(types, Bool.false, \found, shape, _id ->
+        when shape is
+            Num(I128) | Num(U128) | Num(Dec) -> Bool.true
+            _ -> found)
+
+union_name : RocTagUnion -> Str
+union_name = \union ->
+    when union is
+        Enumeration({ name }) -> name
+        NonRecursive({ name }) -> name
+        Recursive({ name }) -> name
+        NullableWrapped({ name }) -> name
+        NonNullableUnwrapped({ name }) -> name
+        SingleTagStruct({ name }) -> name
+        NullableUnwrapped({ name }) -> name
+
+## WIT identifiers are kebab-case, so `MyType` becomes `my-type` and
+## `some_field` becomes `some-field`. Only the first word has to start with a
+## letter, so names that start with a digit (like the positional `0`) get an
+## `f` in front, as in `f0`. Names that clash with WIT keywords are escaped with a `%`.
+wit_name : Str -> Str
+wit_name = \name ->
+    kebab =
+        Str.to_utf8(name)
+        |> List.walk([], \bytes, byte ->
+            if byte >= 'A' and byte <= 'Z' then
+                lower = byte + 32
+
+                when List.last(bytes) is
+                    Ok(prev) if prev != '-' -> List.concat(bytes, ['-', lower])
+                    _ -> List.append(bytes, lower)
+            else if byte == '_' then
+                # Words can't be empty, so there's never a leading or doubled `-`.
+                when List.last(bytes) is
+                    Ok(prev) if prev != '-' -> List.append(bytes, '-')
+                    _ -> bytes
+            else
+                List.append(bytes, byte))
+        |> fix_first_and_last_words
+        |> Str.from_utf8
+        |> Result.with_default(name)
+
+    if List.contains(wit_keywords, kebab) then
+        "%${kebab}"
+    else
+        kebab
+
+## A trailing `-` would leave an empty last word, and the first word has to
+## start with a letter.
+fix_first_and_last_words : List U8 -> List U8
+fix_first_and_last_words = \bytes ->
+    trimmed =
+        when List.last(bytes) is
+            Ok('-') -> List.drop_last(bytes, 1)
+            _ -> bytes
+
+    when trimmed is
+        [first, ..] if first >= '0' and first <= '9' -> List.prepend(trimmed, 'f')
+        _ -> trimmed
+
+wit_keywords = [
+    "as",
+    "bool",
+    "borrow",
+    "char",
+    "constructor",
+    "enum",
+    "export",
+    "f32",
+    "f64",
+    "flags",
+    "from",
+    "func",
+    "import",
+    "include",
+    "interface",
+    "list",
+    "option",
+    "own",
+    "package",
+    "record",
+    "resource",
+    "result",
+    "s16",
+    "s32",
+    "s64",
+    "s8",
+    "static",
+    "string",
+    "tuple",
+    "type",
+    "u16",
+    "u32",
+    "u64",
+    "u8",
+    "use",
+    "variant",
+    "with",
+    "world",
+]
+
+indent = "    "
+
+file_header =
+    """
+    // ⚠️ GENERATED CODE ⚠️ - this entire file was generated by the `roc glue` CLI command
+
+    package roc:app;
+
+    interface types {
+    """
+
+int128_records =
+    """
+
+        /// warning: WIT has no 128-bit integers, so these are split into
+        /// their low and high 64 bits, in that order.
+        record roc-u128 {
+            low: u64,
+            high: u64,
+        }
+
+        record roc-i128 {
+            low: u64,
+            high: s64,
+        }
+
+    """
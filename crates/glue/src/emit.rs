@@ -0,0 +1,210 @@
+//! The glue specs that ship alongside this crate, and a single entry point
+//! for generating glue with one of them.
+//!
+//! Emitters themselves are Roc programs (see the `*Glue.roc` files in `src/`),
+//! so adding a language means adding a spec there and a variant here.
+//!
+//! The specs, and the glue platform they're written against, are embedded in
+//! the `roc` binary, so they work wherever it's installed.
+use crate::load::{generate, GlueOptions};
+use roc_build::link::{LinkType, LinkingStrategy};
+use roc_build::program::CodeGenBackend;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use strum_macros::EnumIter;
+
+/// The modules of the glue platform, which every spec refers to as `../platform/main.roc`.
+const PLATFORM_FILES: &[(&str, &str)] = &[
+    ("main.roc", include_str!("../platform/main.roc")),
+    ("File.roc", include_str!("../platform/File.roc")),
+    ("Shape.roc", include_str!("../platform/Shape.roc")),
+    ("Target.roc", include_str!("../platform/Target.roc")),
+    ("TypeId.roc", include_str!("../platform/TypeId.roc")),
+    ("Types.roc", include_str!("../platform/Types.roc")),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum EmitTarget {
+    C,
+    Rust,
+    Zig,
+    /// `ctypes` declarations, for loading a Roc library from Python.
+    Python,
+    /// A WebAssembly Interface Types (`.wit`) package, for wasm components.
+    Wit,
+    /// Not bindings, but a human-readable description of the types.
+    Describe,
+}
+
+impl EmitTarget {
+    pub const fn name(self) -> &'static str {
+        match self {
+            EmitTarget::C => "c",
+            EmitTarget::Rust => "rust",
+            EmitTarget::Zig => "zig",
+            EmitTarget::Python => "python",
+            EmitTarget::Wit => "wit",
+            EmitTarget::Describe => "describe",
+        }
+    }
+
+    pub const fn spec_file_name(self) -> &'static str {
+        match self {
+            EmitTarget::C => "CGlue.roc",
+            EmitTarget::Rust => "RustGlue.roc",
+            EmitTarget::Zig => "ZigGlue.roc",
+            EmitTarget::Python => "PythonGlue.roc",
+            EmitTarget::Wit => "WitGlue.roc",
+            EmitTarget::Describe => "DescribeGlue.roc",
+        }
+    }
+
+    pub const fn spec_source(self) -> &'static str {
+        match self {
+            EmitTarget::C => include_str!("CGlue.roc"),
+            EmitTarget::Rust => include_str!("RustGlue.roc"),
+            EmitTarget::Zig => include_str!("ZigGlue.roc"),
+            EmitTarget::Python => include_str!("PythonGlue.roc"),
+            EmitTarget::Wit => include_str!("WitGlue.roc"),
+            EmitTarget::Describe => include_str!("DescribeGlue.roc"),
+        }
+    }
+
+    /// Writes this target's spec into `dir`, along with the glue platform it's an
+    /// app for, and returns the path of the spec.
+    pub fn write_spec(self, dir: &Path) -> io::Result<PathBuf> {
+        let platform_dir = dir.join("platform");
+        let spec_dir = dir.join("src");
+
+        fs::create_dir_all(&platform_dir)?;
+        fs::create_dir_all(&spec_dir)?;
+
+        for (file_name, source) in PLATFORM_FILES {
+            fs::write(platform_dir.join(file_name), source)?;
+        }
+
+        let spec_path = spec_dir.join(self.spec_file_name());
+
+        fs::write(&spec_path, self.spec_source())?;
+
+        Ok(spec_path)
+    }
+}
+
+impl fmt::Display for EmitTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownEmitTarget(pub String);
+
+impl fmt::Display for UnknownEmitTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown glue target `{}`", self.0)
+    }
+}
+
+impl FromStr for EmitTarget {
+    type Err = UnknownEmitTarget;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+
+        EmitTarget::iter()
+            .find(|target| target.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| UnknownEmitTarget(s.to_string()))
+    }
+}
+
+/// Generate glue for the platform at `input_path` using the bundled spec for
+/// `target`, writing the files into `output_path`.
+pub fn write(
+    target: EmitTarget,
+    input_path: &Path,
+    output_path: &Path,
+    backend: CodeGenBackend,
+    link_type: LinkType,
+    linking_strategy: LinkingStrategy,
+    options: &GlueOptions,
+) -> io::Result<i32> {
+    // The spec has to be on disk to be built, and the tempdir has to outlive the build.
+    let spec_dir = tempfile::tempdir()?;
+    let spec_path = target.write_spec(spec_dir.path())?;
+
+    generate(
+        input_path,
+        output_path,
+        &spec_path,
+        backend,
+        link_type,
+        linking_strategy,
+        options,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn every_target_has_a_spec() {
+        for target in EmitTarget::iter() {
+            assert!(
+                target.spec_source().contains("make_glue"),
+                "{} does not provide make_glue",
+                target.spec_file_name()
+            );
+        }
+    }
+
+    #[test]
+    fn written_specs_can_find_their_platform() {
+        let dir = tempfile::tempdir().unwrap();
+
+        for target in EmitTarget::iter() {
+            let spec_path = target.write_spec(dir.path()).unwrap();
+            let spec = std::fs::read_to_string(&spec_path).unwrap();
+
+            assert_eq!(spec, target.spec_source());
+            assert!(spec.contains(r#"platform "../platform/main.roc""#));
+            assert!(spec_path
+                .parent()
+                .unwrap()
+                .join("../platform/main.roc")
+                .is_file());
+        }
+
+        // every module of the platform has to be embedded, or specs won't build
+        let platform_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("platform");
+
+        for entry in std::fs::read_dir(platform_dir).unwrap() {
+            let file_name = entry.unwrap().file_name();
+
+            assert!(
+                PLATFORM_FILES
+                    .iter()
+                    .any(|(embedded, _)| file_name.to_str() == Some(*embedded)),
+                "{file_name:?} is missing from PLATFORM_FILES"
+            );
+        }
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for target in EmitTarget::iter() {
+            assert_eq!(target.name().parse(), Ok(target));
+            assert_eq!(target.name().to_uppercase().parse(), Ok(target));
+        }
+
+        assert_eq!(
+            "swift".parse::<EmitTarget>(),
+            Err(UnknownEmitTarget("swift".to_string()))
+        );
+    }
+}
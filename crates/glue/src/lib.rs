@@ -2,13 +2,14 @@
 //! This tool is not necessary for writing a platform in another language,
 //! however, it's a great convenience! Currently supports Rust platforms, and
 //! the plan is to support any language via a plugin model.
+pub mod emit;
 pub mod enums;
 pub mod load;
 pub mod roc_type;
 pub mod structs;
 pub mod types;
 
-pub use load::generate;
+pub use load::{generate, GlueOptions};
 
 // required because we use roc_std here
 mod roc_externs {
@@ -1,5 +1,5 @@
 use crate::roc_type;
-use crate::types::Types;
+use crate::types::{PositionalNaming, Types};
 use bumpalo::Bump;
 use libloading::Library;
 use roc_build::{
@@ -9,7 +9,7 @@ use roc_build::{
         BuildFileError, BuildOrdering, BuiltFile, CodeGenBackend, CodeGenOptions,
     },
 };
-use roc_collections::MutMap;
+use roc_collections::{MutMap, VecSet};
 use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_gen_llvm::run_roc::RocCallResult;
 use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingProblem, Threading};
@@ -35,6 +35,34 @@ impl IgnoreErrors {
     const NONE: Self = IgnoreErrors { can: false };
 }
 
+/// Choices about how a platform's types are described to glue specs.
+#[derive(Debug, Clone, Default)]
+pub struct GlueOptions {
+    /// How the fields of tag payloads, which only have positions, are named.
+    pub positional_naming: PositionalNaming,
+    /// The names of the platform's types that hosts should only see the size
+    /// and alignment of. Their values can't contain anything refcounted.
+    pub opaque_names: VecSet<String>,
+    /// Whether tag union payload structs (e.g. `MyUnion_Variant`) should be declared
+    /// inside a module named after their union, for targets that have modules.
+    pub nest_payload_types: bool,
+    /// Whether the padding Roc leaves in structs should be declared as explicit
+    /// `_padN` fields, rather than left to the host compiler's layout rules.
+    pub explicit_padding: bool,
+    /// Whether specs should generate accessors which borrow a tag's payload,
+    /// returning `None` for the other tags.
+    pub payload_accessors: bool,
+    /// Whether specs should declare types alphabetically, wherever their dependencies
+    /// allow, rather than in the order they were found.
+    pub sort_by_name: bool,
+    /// Where to write a manifest of every named type's size and alignment, for tooling
+    /// to diff against a committed baseline (see [write_size_manifest]).
+    pub size_manifest: Option<PathBuf>,
+    /// If this isn't empty, only these named types are generated, along with the types
+    /// they use (see [Types::retain_named]).
+    pub keep_names: VecSet<String>,
+}
+
 pub fn generate(
     input_path: &Path,
     output_path: &Path,
@@ -42,6 +70,7 @@ pub fn generate(
     backend: CodeGenBackend,
     link_type: LinkType,
     linking_strategy: LinkingStrategy,
+    options: &GlueOptions,
 ) -> io::Result<i32> {
     let target = Triple::host().into();
     // TODO: Add verification around the paths. Make sure they have the correct file extension and what not.
@@ -50,8 +79,21 @@ pub fn generate(
         Threading::AllAvailable,
         IgnoreErrors::NONE,
         target,
+        options,
     ) {
         Ok(types) => {
+            if let Some(manifest_path) = &options.size_manifest {
+                write_size_manifest(manifest_path, &types).unwrap_or_else(|err| {
+                    eprintln!(
+                        "Unable to write the size manifest to {} - {:?}",
+                        manifest_path.display(),
+                        err
+                    );
+
+                    process::exit(1);
+                });
+            }
+
             // TODO: we should to modify the app file first before loading it.
             // Somehow it has to point to the correct platform file which may not exist on the target machine.
 
@@ -205,6 +247,10 @@ pub fn generate(
                 eprintln!("Platform module file not found: {}", input_path.display());
                 process::exit(1);
             }
+            ErrorKind::InvalidData => {
+                eprintln!("{err}");
+                process::exit(1);
+            }
             error => {
                 eprintln!(
                     "Error loading platform module file {} - {:?}",
@@ -217,6 +263,24 @@ pub fn generate(
     }
 }
 
+/// Writes the [Types::size_manifest] of each architecture to `path`, as one
+/// `architecture name size alignment` line per named type. Unlike the size checks
+/// specs put in the glue itself, this is for CI to diff against a committed copy,
+/// so a type that changes size gets noticed before any host is rebuilt.
+pub fn write_size_manifest(path: &Path, types_by_arch: &[Types]) -> io::Result<()> {
+    let mut manifest = String::new();
+
+    for types in types_by_arch {
+        let arch = types.target().architecture();
+
+        for (name, size, align) in types.size_manifest() {
+            manifest.push_str(&format!("{arch} {name} {size} {align}\n"));
+        }
+    }
+
+    std::fs::write(path, manifest)
+}
+
 fn call_roc_make_glue(
     lib: &Library,
     backend: CodeGenBackend,
@@ -406,6 +470,7 @@ pub fn load_types(
     threading: Threading,
     ignore_errors: IgnoreErrors,
     target: Target,
+    options: &GlueOptions,
 ) -> Result<Vec<Types>, io::Error> {
     let function_kind = FunctionKind::from_env();
     let arena = &Bump::new();
@@ -530,7 +595,7 @@ pub fn load_types(
             }
         }
 
-        let types = Types::new_with_entry_points(
+        let mut types = Types::new_with_entry_points(
             arena,
             subs,
             arena.alloc(interns),
@@ -538,7 +603,18 @@ pub fn load_types(
             layout_cache,
             target,
             exposed_to_host.clone(),
-        );
+            options.positional_naming,
+            options.opaque_names.clone(),
+            options.nest_payload_types,
+            options.explicit_padding,
+            options.payload_accessors,
+            options.sort_by_name,
+        )
+        .map_err(|err| io::Error::new(ErrorKind::InvalidData, err.to_string()))?;
+
+        if !options.keep_names.is_empty() {
+            types.retain_named(|name| options.keep_names.iter().any(|keep| keep == name));
+        }
 
         arch_types.push(types);
     }
@@ -63,9 +63,16 @@ pub struct Types {
     pub aligns: roc_std::RocList<u32>,
     pub deps: roc_std::RocList<Tuple2>,
     pub entrypoints: roc_std::RocList<Tuple1>,
+    pub payload_parents: roc_std::RocList<Tuple2>,
+    pub roots: roc_std::RocList<u64>,
     pub sizes: roc_std::RocList<u32>,
+    pub sorted_by_name: roc_std::RocList<u64>,
     pub types: roc_std::RocList<RocType>,
     pub types_by_name: roc_std::RocList<Tuple1>,
+    pub explicit_padding: bool,
+    pub nest_payload_types: bool,
+    pub payload_accessors: bool,
+    pub sort_by_name: bool,
     pub target: Target,
 }
 
@@ -74,7 +81,10 @@ impl RocRefcounted for Types {
         self.aligns.inc();
         self.deps.inc();
         self.entrypoints.inc();
+        self.payload_parents.inc();
+        self.roots.inc();
         self.sizes.inc();
+        self.sorted_by_name.inc();
         self.types.inc();
         self.types_by_name.inc();
         self.target.inc();
@@ -84,7 +94,10 @@ impl RocRefcounted for Types {
         self.aligns.dec();
         self.deps.dec();
         self.entrypoints.dec();
+        self.payload_parents.dec();
+        self.roots.dec();
         self.sizes.dec();
+        self.sorted_by_name.dec();
         self.types.dec();
         self.types_by_name.dec();
         self.target.dec();
@@ -117,18 +130,19 @@ pub enum discriminant_RocType {
     EmptyTagUnion = 1,
     Function = 2,
     Num = 3,
-    RecursivePointer = 4,
-    RocBox = 5,
-    RocDict = 6,
-    RocList = 7,
-    RocResult = 8,
-    RocSet = 9,
-    RocStr = 10,
-    Struct = 11,
-    TagUnion = 12,
-    TagUnionPayload = 13,
-    Unit = 14,
-    Unsized = 15,
+    Opaque = 4,
+    RecursivePointer = 5,
+    RocBox = 6,
+    RocDict = 7,
+    RocList = 8,
+    RocResult = 9,
+    RocSet = 10,
+    RocStr = 11,
+    Struct = 12,
+    TagUnion = 13,
+    TagUnionPayload = 14,
+    Unit = 15,
+    Unsized = 16,
 }
 
 impl core::fmt::Debug for discriminant_RocType {
@@ -138,6 +152,7 @@ impl core::fmt::Debug for discriminant_RocType {
             Self::EmptyTagUnion => f.write_str("discriminant_RocType::EmptyTagUnion"),
             Self::Function => f.write_str("discriminant_RocType::Function"),
             Self::Num => f.write_str("discriminant_RocType::Num"),
+            Self::Opaque => f.write_str("discriminant_RocType::Opaque"),
             Self::RecursivePointer => f.write_str("discriminant_RocType::RecursivePointer"),
             Self::RocBox => f.write_str("discriminant_RocType::RocBox"),
             Self::RocDict => f.write_str("discriminant_RocType::RocDict"),
@@ -159,6 +174,7 @@ impl core::fmt::Debug for discriminant_RocType {
 pub union RocType {
     Function: core::mem::ManuallyDrop<RocFn>,
     Num: RocNum,
+    Opaque: core::mem::ManuallyDrop<roc_std::RocStr>,
     RecursivePointer: u32,
     RocBox: u32,
     RocDict: RocType_RocDict,
@@ -171,13 +187,6 @@ pub union RocType {
     _sizer: [u8; 96],
 }
 
-#[cfg(any(target_arch = "arm", target_arch = "wasm32", target_arch = "x86"))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(transparent)]
-pub struct R16 {
-    pub id: u32,
-}
-
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
@@ -418,7 +427,7 @@ impl core::fmt::Debug for discriminant_RocSingleTagPayload {
 #[repr(C)]
 pub union RocSingleTagPayload {
     HasClosure: core::mem::ManuallyDrop<roc_std::RocList<R4>>,
-    HasNoClosure: core::mem::ManuallyDrop<roc_std::RocList<R16>>,
+    HasNoClosure: core::mem::ManuallyDrop<roc_std::RocList<R4>>,
     _sizer: [u8; 32],
 }
 
@@ -764,6 +773,7 @@ impl RocRefcounted for Tuple1 {
 pub union RocType {
     Function: core::mem::ManuallyDrop<RocFn>,
     Num: RocNum,
+    Opaque: core::mem::ManuallyDrop<roc_std::RocStr>,
     RecursivePointer: u64,
     RocBox: u64,
     RocDict: RocType_RocDict,
@@ -784,6 +794,7 @@ impl RocRefcounted for RocType {
                 discriminant_RocType::EmptyTagUnion => {}
                 discriminant_RocType::Function => self.Function.deref_mut().inc(),
                 discriminant_RocType::Num => self.Num.inc(),
+                discriminant_RocType::Opaque => self.Opaque.deref_mut().inc(),
                 discriminant_RocType::RecursivePointer => self.RecursivePointer.inc(),
                 discriminant_RocType::RocBox => self.RocBox.inc(),
                 discriminant_RocType::RocDict => self.RocDict.inc(),
@@ -807,6 +818,7 @@ impl RocRefcounted for RocType {
                 discriminant_RocType::EmptyTagUnion => {}
                 discriminant_RocType::Function => self.Function.deref_mut().dec(),
                 discriminant_RocType::Num => self.Num.dec(),
+                discriminant_RocType::Opaque => self.Opaque.deref_mut().dec(),
                 discriminant_RocType::RecursivePointer => self.RecursivePointer.dec(),
                 discriminant_RocType::RocBox => self.RocBox.dec(),
                 discriminant_RocType::RocDict => self.RocDict.dec(),
@@ -828,15 +840,6 @@ impl RocRefcounted for RocType {
     }
 }
 
-#[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(transparent)]
-pub struct R16 {
-    pub id: u64,
-}
-
-roc_std::roc_refcounted_noop_impl!(R16);
-
 #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
 #[derive(Clone, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
@@ -1404,6 +1407,70 @@ impl RocType {
         &payload
     }
 
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Opaque`, with the appropriate payload
+    pub fn Opaque(arg: roc_std::RocStr) -> Self {
+        let mut answer = Self {
+            Opaque: core::mem::ManuallyDrop::new(arg),
+        };
+
+        answer.set_discriminant(discriminant_RocType::Opaque);
+
+        answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `RocType` has a `.discriminant()` of `Opaque` and convert it to `Opaque`'s payload.
+    /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+    /// Panics in debug builds if the `.discriminant()` doesn't return `Opaque`.
+    pub unsafe fn into_Opaque(mut self) -> roc_std::RocStr {
+        debug_assert_eq!(self.discriminant(), discriminant_RocType::Opaque);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.Opaque,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `RocType` has a `.discriminant()` of `Opaque` and return its payload.
+    /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+    /// Panics in debug builds if the `.discriminant()` doesn't return `Opaque`.
+    pub unsafe fn as_Opaque(&self) -> &roc_std::RocStr {
+        debug_assert_eq!(self.discriminant(), discriminant_RocType::Opaque);
+        let payload = &self.Opaque;
+
+        payload
+    }
+
     #[cfg(any(target_arch = "arm", target_arch = "wasm32", target_arch = "x86"))]
     /// Construct a tag named `RecursivePointer`, with the appropriate payload
     pub fn RecursivePointer(arg: u32) -> Self {
@@ -2188,6 +2255,9 @@ impl Drop for RocType {
                 core::mem::ManuallyDrop::drop(&mut self.Function)
             },
             discriminant_RocType::Num => {}
+            discriminant_RocType::Opaque => unsafe {
+                core::mem::ManuallyDrop::drop(&mut self.Opaque)
+            },
             discriminant_RocType::RecursivePointer => {}
             discriminant_RocType::RocBox => {}
             discriminant_RocType::RocDict => {}
@@ -2231,6 +2301,7 @@ impl PartialEq for RocType {
                 discriminant_RocType::EmptyTagUnion => true,
                 discriminant_RocType::Function => self.Function == other.Function,
                 discriminant_RocType::Num => self.Num == other.Num,
+                discriminant_RocType::Opaque => self.Opaque == other.Opaque,
                 discriminant_RocType::RecursivePointer => {
                     self.RecursivePointer == other.RecursivePointer
                 }
@@ -2272,6 +2343,7 @@ impl PartialOrd for RocType {
                 discriminant_RocType::EmptyTagUnion => Some(core::cmp::Ordering::Equal),
                 discriminant_RocType::Function => self.Function.partial_cmp(&other.Function),
                 discriminant_RocType::Num => self.Num.partial_cmp(&other.Num),
+                discriminant_RocType::Opaque => self.Opaque.partial_cmp(&other.Opaque),
                 discriminant_RocType::RecursivePointer => {
                     self.RecursivePointer.partial_cmp(&other.RecursivePointer)
                 }
@@ -2313,6 +2385,7 @@ impl Ord for RocType {
                 discriminant_RocType::EmptyTagUnion => core::cmp::Ordering::Equal,
                 discriminant_RocType::Function => self.Function.cmp(&other.Function),
                 discriminant_RocType::Num => self.Num.cmp(&other.Num),
+                discriminant_RocType::Opaque => self.Opaque.cmp(&other.Opaque),
                 discriminant_RocType::RecursivePointer => {
                     self.RecursivePointer.cmp(&other.RecursivePointer)
                 }
@@ -2360,6 +2433,9 @@ impl Clone for RocType {
                 discriminant_RocType::Num => Self {
                     Num: self.Num.clone(),
                 },
+                discriminant_RocType::Opaque => Self {
+                    Opaque: self.Opaque.clone(),
+                },
                 discriminant_RocType::RecursivePointer => Self {
                     RecursivePointer: self.RecursivePointer.clone(),
                 },
@@ -2428,6 +2504,10 @@ impl core::hash::Hash for RocType {
                 discriminant_RocType::Num.hash(state);
                 self.Num.hash(state);
             },
+            discriminant_RocType::Opaque => unsafe {
+                discriminant_RocType::Opaque.hash(state);
+                self.Opaque.hash(state);
+            },
             discriminant_RocType::RecursivePointer => unsafe {
                 discriminant_RocType::RecursivePointer.hash(state);
                 self.RecursivePointer.hash(state);
@@ -2490,6 +2570,9 @@ impl core::fmt::Debug for RocType {
                     f.debug_tuple("Function").field(&*self.Function).finish()
                 }
                 discriminant_RocType::Num => f.debug_tuple("Num").field(&self.Num).finish(),
+                discriminant_RocType::Opaque => {
+                    f.debug_tuple("Opaque").field(&*self.Opaque).finish()
+                }
                 discriminant_RocType::RecursivePointer => f
                     .debug_tuple("RecursivePointer")
                     .field(&self.RecursivePointer)
@@ -3482,7 +3565,7 @@ impl RocSingleTagPayload {
         target_arch = "x86_64"
     ))]
     /// Construct a tag named `HasNoClosure`, with the appropriate payload
-    pub fn HasNoClosure(arg: roc_std::RocList<R16>) -> Self {
+    pub fn HasNoClosure(arg: roc_std::RocList<R4>) -> Self {
         let mut answer = Self {
             HasNoClosure: core::mem::ManuallyDrop::new(arg),
         };
@@ -3502,7 +3585,7 @@ impl RocSingleTagPayload {
     /// Unsafely assume the given `RocSingleTagPayload` has a `.discriminant()` of `HasNoClosure` and convert it to `HasNoClosure`'s payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `HasNoClosure`.
-    pub unsafe fn into_HasNoClosure(mut self) -> roc_std::RocList<R16> {
+    pub unsafe fn into_HasNoClosure(mut self) -> roc_std::RocList<R4> {
         debug_assert_eq!(
             self.discriminant(),
             discriminant_RocSingleTagPayload::HasNoClosure
@@ -3534,7 +3617,7 @@ impl RocSingleTagPayload {
     /// Unsafely assume the given `RocSingleTagPayload` has a `.discriminant()` of `HasNoClosure` and return its payload.
     /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
     /// Panics in debug builds if the `.discriminant()` doesn't return `HasNoClosure`.
-    pub unsafe fn as_HasNoClosure(&self) -> &roc_std::RocList<R16> {
+    pub unsafe fn as_HasNoClosure(&self) -> &roc_std::RocList<R4> {
         debug_assert_eq!(
             self.discriminant(),
             discriminant_RocSingleTagPayload::HasNoClosure
@@ -7,7 +7,7 @@ use roc_builtins::bitcode::{
     FloatWidth::*,
     IntWidth::{self, *},
 };
-use roc_collections::{MutMap, VecMap};
+use roc_collections::{MutMap, VecMap, VecSet};
 use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_module::{
     ident::TagName,
@@ -25,6 +25,7 @@ use roc_types::{
     subs::{Content, FlatType, GetSubsSlice, Label, Subs, SubsSlice, UnionLabels, Variable},
     types::{AliasKind, RecordField},
 };
+use std::cell::Cell;
 use std::convert::From;
 use std::fmt::Display;
 
@@ -37,6 +38,55 @@ pub struct File {
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct TypeId(usize);
 
+/// How deeply recursive traversals of a type will go before giving up.
+/// Real types never get anywhere near this deep; exceeding it means the
+/// type graph has a cycle which isn't broken up by a RecursivePointer.
+pub const MAX_TYPE_DEPTH: usize = 256;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindgenError {
+    /// A traversal of the type graph went deeper than the given limit.
+    RecursionLimit { limit: usize },
+    /// A type still refers to the placeholder TypeId that recursive pointers get
+    /// before their recursive type is known, so resolving them missed it.
+    PendingTypeId { referenced_by: TypeId },
+    /// A type, or one of its dependency edges, refers to a TypeId which isn't in Types.
+    UnknownTypeId { id: TypeId, referenced_by: TypeId },
+    /// The platform asked for a type to be opaque, but its values contain
+    /// refcounted data, whose refcounts a host can't keep without seeing it.
+    RefcountedOpaque { name: String },
+    /// These types' dependencies form a cycle (or lead into one), so there's no order
+    /// to declare them in where each comes after everything it depends on.
+    CyclicDependencies { ids: Vec<TypeId> },
+}
+
+impl Display for BindgenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindgenError::RecursionLimit { limit } => write!(
+                f,
+                "Exceeded the maximum type depth of {limit} while traversing glue types. This means there's a cycle in the type graph which isn't broken up by a recursive pointer."
+            ),
+            BindgenError::PendingTypeId { referenced_by } => write!(
+                f,
+                "{referenced_by:?} still refers to a pending recursive type, which should have been resolved once every entry point was added."
+            ),
+            BindgenError::UnknownTypeId { id, referenced_by } => write!(
+                f,
+                "{referenced_by:?} refers to {id:?}, which is not in the glue Types."
+            ),
+            BindgenError::RefcountedOpaque { name } => write!(
+                f,
+                "{name} was marked opaque, but it contains reference-counted values, so hosts must be able to see its fields."
+            ),
+            BindgenError::CyclicDependencies { ids } => write!(
+                f,
+                "The dependencies of {ids:?} form a cycle, so there's no order to declare them in. A type that refers to itself should do so through a recursive pointer, which isn't a dependency."
+            ),
+        }
+    }
+}
+
 impl TypeId {
     /// Used when making recursive pointers, which need to temporarily
     /// have *some* TypeId value until we later in the process determine
@@ -61,10 +111,32 @@ pub struct Types {
     // Needed to check for duplicates
     types_by_name: FnvHashMap<String, TypeId>,
 
+    /// Types whose name came from a Roc type alias, rather than being generated.
+    /// These are kept distinct even if they're structurally identical to another type.
+    aliased: VecSet<TypeId>,
+
     /// Dependencies - that is, which type depends on which other type.
     /// This is important for declaration order in C; we need to output a
     /// type declaration earlier in the file than where it gets referenced by another type.
     deps: VecMap<TypeId, Vec<TypeId>>,
+
+    /// The tag unions that each generated payload struct is a payload of. That's usually
+    /// just the union it was made for, but dedup_structural merges identical payloads,
+    /// so several unions can end up sharing one.
+    payload_parents: VecMap<TypeId, Vec<TypeId>>,
+
+    /// Whether specs should declare payload structs inside their union, where the
+    /// target language has somewhere to put them.
+    nest_payload_types: bool,
+
+    /// Whether specs should declare struct padding as explicit fields.
+    explicit_padding: bool,
+
+    /// Whether specs should generate accessors for tag union payloads.
+    payload_accessors: bool,
+
+    /// Whether specs should declare types in [Types::sorted_ids_by_name] order.
+    sort_by_name: bool,
     target: Target,
 }
 
@@ -86,8 +158,14 @@ impl Types {
             sizes,
             aligns,
             types_by_name: FnvHashMap::with_capacity_and_hasher(10, Default::default()),
+            aliased: VecSet::default(),
             entry_points: Vec::new(),
             deps: VecMap::with_capacity(cap),
+            payload_parents: VecMap::default(),
+            nest_payload_types: false,
+            explicit_padding: false,
+            payload_accessors: false,
+            sort_by_name: false,
         }
     }
 
@@ -100,8 +178,19 @@ impl Types {
         layout_cache: LayoutCache<'a>,
         target: Target,
         mut entry_points: MutMap<Symbol, Variable>,
-    ) -> Self {
+        positional_field_naming: PositionalNaming,
+        opaque_names: VecSet<String>,
+        nest_payload_types: bool,
+        explicit_padding: bool,
+        payload_accessors: bool,
+        sort_by_name: bool,
+    ) -> Result<Self, BindgenError> {
         let mut types = Self::with_capacity(entry_points.len(), target);
+        types.nest_payload_types = nest_payload_types;
+        types.explicit_padding = explicit_padding;
+        types.payload_accessors = payload_accessors;
+        types.sort_by_name = sort_by_name;
+
         let mut env = Env::new(
             arena,
             subs,
@@ -109,6 +198,8 @@ impl Types {
             layout_cache.interner,
             glue_procs_by_layout,
             target,
+            positional_field_naming,
+            opaque_names,
         );
 
         for (_symbol, var) in entry_points.clone() {
@@ -128,22 +219,155 @@ impl Types {
 
         debug_assert!(entry_points.is_empty());
 
+        if let Some(err) = env.error.take() {
+            return Err(err);
+        }
+
         env.resolve_pending_recursive_types(&mut types);
 
-        types
+        debug_assert!(
+            env.pending_recursive_types.is_empty(),
+            "recursive types were still pending after resolving them: {:?}",
+            env.pending_recursive_types
+        );
+
+        types.dedup_structural();
+        types.renumber_generated_names();
+
+        if cfg!(debug_assertions) {
+            if let Err(err) = types.check_invariants() {
+                internal_error!("{err}");
+            }
+        }
+
+        Ok(types)
     }
 
     pub fn entry_points(&self) -> &[(String, TypeId)] {
         self.entry_points.as_slice()
     }
 
+    /// Whether this type is one a host would name: either an entry point, or a type
+    /// named by a Roc alias. Everything else (e.g. the `MyUnion_Variant` payload structs
+    /// that tag unions generate) is a helper that only exists to describe a root.
+    pub fn is_root(&self, id: TypeId) -> bool {
+        self.aliased.contains(&id) || self.entry_points.iter().any(|(_, entry)| *entry == id)
+    }
+
+    /// The tag union that a generated payload struct (e.g. `MyUnion_Variant`) was made
+    /// for, so an emitter can declare it inside that union instead of as an unrelated
+    /// top-level type. Returns None for anything that isn't a payload, and for payloads
+    /// which several unions share, since no one union can declare those.
+    pub fn payload_parent(&self, id: TypeId) -> Option<TypeId> {
+        match self.payload_parents(id) {
+            [parent] => Some(*parent),
+            _ => None,
+        }
+    }
+
+    /// Every tag union which has this payload struct as one of its payloads. This is
+    /// empty for anything that isn't a payload struct.
+    pub fn payload_parents(&self, id: TypeId) -> &[TypeId] {
+        self.payload_parents
+            .get(&id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Whether specs were asked to declare payload structs inside their unions
+    /// (see [Types::payload_parent]) rather than as top-level types.
+    pub fn nest_payload_types(&self) -> bool {
+        self.nest_payload_types
+    }
+
+    /// Whether specs were asked to declare the padding in structs as explicit fields,
+    /// at the offsets [Types::field_offset] gives, rather than leave it to the host.
+    pub fn explicit_padding(&self) -> bool {
+        self.explicit_padding
+    }
+
+    /// Whether specs were asked to generate a `get_<tag>` accessor for each tag's
+    /// payload, on top of the constructors and `is_<tag>` checks they always have.
+    pub fn payload_accessors(&self) -> bool {
+        self.payload_accessors
+    }
+
+    /// Whether specs were asked to declare types in [Types::sorted_ids_by_name] order,
+    /// so that their output doesn't churn when unrelated types come and go.
+    pub fn sort_by_name(&self) -> bool {
+        self.sort_by_name
+    }
+
+    /// Records `union` as a parent of each of its payload structs.
+    fn record_payload_parents(&mut self, union: TypeId) {
+        let RocType::TagUnion(tag_union) = self.get_type(union) else {
+            internal_error!("{union:?} is not a tag union");
+        };
+
+        let mut payloads = Vec::new();
+
+        tag_union.for_each_tag_payload(|_, payload| {
+            if let RocTypeOrPending::Type(RocType::TagUnionPayload { .. }) =
+                self.get_type_or_pending(payload)
+            {
+                payloads.push(payload);
+            }
+        });
+
+        for payload in payloads {
+            let parents = self.payload_parents.get_or_insert(payload, Vec::new);
+
+            if !parents.contains(&union) {
+                parents.push(union);
+            }
+        }
+    }
+
     pub fn is_equivalent(&self, a: &RocType, b: &RocType) -> bool {
-        self.is_equivalent_help(RocTypeOrPending::Type(a), RocTypeOrPending::Type(b))
+        match self.try_is_equivalent(a, b) {
+            Ok(answer) => answer,
+            Err(err) => internal_error!("{err}"),
+        }
+    }
+
+    /// Like is_equivalent, except a malformed type graph (one with a cycle that
+    /// doesn't go through a RecursivePointer) results in an error rather than
+    /// recursing until the stack overflows.
+    pub fn try_is_equivalent(&self, a: &RocType, b: &RocType) -> Result<bool, BindgenError> {
+        let exceeded = Cell::new(false);
+        let answer = self.is_equivalent_help(
+            RocTypeOrPending::Type(a),
+            RocTypeOrPending::Type(b),
+            0,
+            &exceeded,
+        );
+
+        if exceeded.get() {
+            Err(BindgenError::RecursionLimit {
+                limit: MAX_TYPE_DEPTH,
+            })
+        } else {
+            Ok(answer)
+        }
     }
 
-    fn is_equivalent_help(&self, a: RocTypeOrPending, b: RocTypeOrPending) -> bool {
+    /// Once `depth` exceeds MAX_TYPE_DEPTH, this sets `exceeded` and returns false,
+    /// which every caller propagates straight back up.
+    fn is_equivalent_help(
+        &self,
+        a: RocTypeOrPending,
+        b: RocTypeOrPending,
+        depth: usize,
+        exceeded: &Cell<bool>,
+    ) -> bool {
         use RocType::*;
 
+        if depth > MAX_TYPE_DEPTH {
+            exceeded.set(true);
+
+            return false;
+        }
+
         let (a, b) = match (a, b) {
             (RocTypeOrPending::Type(a), RocTypeOrPending::Type(b)) => (a, b),
             (RocTypeOrPending::Pending, RocTypeOrPending::Pending) => return true,
@@ -152,31 +376,45 @@ impl Types {
 
         match (a, b) {
             (Unsized, Unsized) => true,
+            (Opaque { name: name_a }, Opaque { name: name_b }) => name_a == name_b,
             (RocStr, RocStr) | (Bool, Bool) | (EmptyTagUnion, EmptyTagUnion) | (Unit, Unit) => true,
             (RocResult(ok_a, err_a), RocResult(ok_b, err_b)) => {
                 self.is_equivalent_help(
                     self.get_type_or_pending(*ok_a),
                     self.get_type_or_pending(*ok_b),
+                    depth + 1,
+                    exceeded,
                 ) && self.is_equivalent_help(
                     self.get_type_or_pending(*err_a),
                     self.get_type_or_pending(*err_b),
+                    depth + 1,
+                    exceeded,
                 )
             }
             (Num(num_a), Num(num_b)) => num_a == num_b,
             (RocList(elem_a), RocList(elem_b))
             | (RocSet(elem_a), RocSet(elem_b))
-            | (RocBox(elem_a), RocBox(elem_b))
-            | (RecursivePointer(elem_a), RecursivePointer(elem_b)) => self.is_equivalent_help(
+            | (RocBox(elem_a), RocBox(elem_b)) => self.is_equivalent_help(
                 self.get_type_or_pending(*elem_a),
                 self.get_type_or_pending(*elem_b),
+                depth + 1,
+                exceeded,
             ),
+            // A recursive pointer is a back edge in the type graph, so following it
+            // would never terminate. Two pointers (including two which are both still
+            // pending) are equivalent exactly when they point to the same type.
+            (RecursivePointer(elem_a), RecursivePointer(elem_b)) => elem_a == elem_b,
             (RocDict(key_a, val_a), RocDict(key_b, val_b)) => {
                 self.is_equivalent_help(
                     self.get_type_or_pending(*key_a),
                     self.get_type_or_pending(*key_b),
+                    depth + 1,
+                    exceeded,
                 ) && self.is_equivalent_help(
                     self.get_type_or_pending(*val_a),
                     self.get_type_or_pending(*val_b),
+                    depth + 1,
+                    exceeded,
                 )
             }
             (TagUnion(union_a), TagUnion(union_b)) => {
@@ -211,6 +449,8 @@ impl Types {
                             && self.is_equivalent_help(
                                 self.get_type_or_pending(*payload_a),
                                 self.get_type_or_pending(*payload_b),
+                                depth + 1,
+                                exceeded,
                             )
                     }
                     (Enumeration { tags: tags_a, .. }, Enumeration { tags: tags_b, .. }) => {
@@ -250,6 +490,8 @@ impl Types {
                                             (Some(id_a), Some(id_b)) => self.is_equivalent_help(
                                                 self.get_type_or_pending(*id_a),
                                                 self.get_type_or_pending(*id_b),
+                                                depth + 1,
+                                                exceeded,
                                             ),
                                             (None, None) => true,
                                             (None, Some(_)) | (Some(_), None) => false,
@@ -272,6 +514,8 @@ impl Types {
                                             (Some(id_a), Some(id_b)) => self.is_equivalent_help(
                                                 self.get_type_or_pending(*id_a),
                                                 self.get_type_or_pending(*id_b),
+                                                depth + 1,
+                                                exceeded,
                                             ),
                                             (None, None) => true,
                                             (None, Some(_)) | (Some(_), None) => false,
@@ -346,6 +590,8 @@ impl Types {
                                 && self.is_equivalent_help(
                                     self.get_type_or_pending(*id_a),
                                     self.get_type_or_pending(*id_b),
+                                    depth + 1,
+                                    exceeded,
                                 )
                         },
                     )
@@ -382,6 +628,8 @@ impl Types {
                                 && self.is_equivalent_help(
                                     self.get_type_or_pending(*id_a),
                                     self.get_type_or_pending(*id_b),
+                                    depth + 1,
+                                    exceeded,
                                 )
                         })
                 } else {
@@ -455,16 +703,22 @@ impl Types {
                     && self.is_equivalent_help(
                         self.get_type_or_pending(*lambda_a),
                         self.get_type_or_pending(*lambda_b),
+                        depth + 1,
+                        exceeded,
                     )
                     && self.is_equivalent_help(
                         self.get_type_or_pending(*ret_a),
                         self.get_type_or_pending(*ret_b),
+                        depth + 1,
+                        exceeded,
                     )
                 {
                     args_a.iter().zip(args_b.iter()).all(|(id_a, id_b)| {
                         self.is_equivalent_help(
                             self.get_type_or_pending(*id_a),
                             self.get_type_or_pending(*id_b),
+                            depth + 1,
+                            exceeded,
                         )
                     })
                 } else {
@@ -562,6 +816,468 @@ impl Types {
         id
     }
 
+    /// Collapses structurally-identical types into a single TypeId, and rewrites
+    /// every reference to the removed duplicates. Types named by a Roc type alias
+    /// are never merged, so each alias still gets its own declaration.
+    ///
+    /// For example, several entry points which each return `{ x : I64, y : I64 }`
+    /// should share one generated struct rather than each getting their own.
+    pub fn dedup_structural(&mut self) {
+        // For each TypeId, the TypeId it's being merged into (which may be itself).
+        // A type is always merged into an earlier one, so Types::UNIT stays put.
+        let mut canonical: Vec<TypeId> = self.ids().collect();
+
+        for id in self.ids() {
+            if self.aliased.contains(&id) {
+                continue;
+            }
+
+            let typ = self.get_type(id);
+            let existing = (0..id.0).map(TypeId).find(|other| {
+                canonical[other.0] == *other
+                    && !self.aliased.contains(other)
+                    && self.sizes[other.0] == self.sizes[id.0]
+                    && self.aligns[other.0] == self.aligns[id.0]
+                    && self.is_equivalent(typ, self.get_type(*other))
+            });
+
+            if let Some(other) = existing {
+                canonical[id.0] = other;
+            }
+        }
+
+        self.merge_into_canonical(&canonical);
+    }
+
+    /// Removes every type whose entry in `canonical` isn't itself, redirecting its
+    /// references to the type it names instead. Each type must be merged into an
+    /// earlier one.
+    fn merge_into_canonical(&mut self, canonical: &[TypeId]) {
+        if canonical
+            .iter()
+            .enumerate()
+            .all(|(index, id)| index == id.0)
+        {
+            return;
+        }
+
+        // Compact the types that remain, so TypeIds stay contiguous.
+        let mut new_ids = Vec::with_capacity(canonical.len());
+        let mut next_id = 0;
+
+        for (index, id) in canonical.iter().enumerate() {
+            if index == id.0 {
+                new_ids.push(Some(TypeId(next_id)));
+                next_id += 1;
+            } else {
+                new_ids.push(new_ids[id.0]);
+            }
+        }
+
+        let kept: Vec<bool> = canonical
+            .iter()
+            .enumerate()
+            .map(|(index, id)| index == id.0)
+            .collect();
+
+        // A merged type's name would otherwise follow it to the type it was merged
+        // into, leaving that type reachable under a name it isn't declared with.
+        self.types_by_name.retain(|_, id| kept[id.0]);
+
+        self.compact(&kept, &new_ids);
+    }
+
+    /// Combines the types from two separately-loaded modules into one graph, so their
+    /// bindings can be emitted into a single file. The types from `other` get new TypeIds.
+    ///
+    /// A name both graphs use refers to a single type if the two types are structurally
+    /// identical. Otherwise the type from `other` is renamed with a `_2` (or `_3`, ...)
+    /// suffix, and a renamed union's payload structs are renamed to match. Generated
+    /// names like `R1` are then renumbered across the merged graph.
+    pub fn merge(mut self, other: Types) -> Types {
+        if self.target != other.target {
+            internal_error!(
+                "Cannot merge glue types for {:?} with glue types for {:?}",
+                self.target,
+                other.target
+            );
+        }
+
+        // Both graphs start with UNIT, so every other type from `other` shifts down by one.
+        let offset = self.types.len() - 1;
+        let remap = |id: TypeId| {
+            if id == Types::UNIT || id == TypeId::PENDING {
+                id
+            } else {
+                TypeId(id.0 + offset)
+            }
+        };
+
+        for ((mut typ, size), align) in other
+            .types
+            .into_iter()
+            .zip(other.sizes)
+            .zip(other.aligns)
+            .skip(1)
+        {
+            typ.for_each_type_id_mut(&mut |id| *id = remap(*id));
+
+            self.types.push(typ);
+            self.sizes.push(size);
+            self.aligns.push(align);
+        }
+
+        self.entry_points.extend(
+            other
+                .entry_points
+                .into_iter()
+                .map(|(name, id)| (name, remap(id))),
+        );
+        self.aliased.extend(other.aliased.into_iter().map(remap));
+
+        for (id, depends_on) in other.deps.into_iter() {
+            for dep in depends_on {
+                self.depends(remap(id), remap(dep));
+            }
+        }
+
+        for (payload, parents) in other.payload_parents.into_iter() {
+            self.payload_parents
+                .get_or_insert(remap(payload), Vec::new)
+                .extend(parents.into_iter().map(remap));
+        }
+
+        // Sort the names so the suffixes don't depend on hash order.
+        let mut other_names: Vec<(String, TypeId)> = other.types_by_name.into_iter().collect();
+        other_names.sort();
+
+        let mut canonical: Vec<TypeId> = self.ids().collect();
+        let mut renamed = Vec::new();
+
+        // Payload structs are named after their union (e.g. `MyUnion_Variant`), so they
+        // have to wait until we know whether their union is being renamed.
+        let mut payloads = Vec::new();
+
+        for (name, id) in other_names {
+            let id = remap(id);
+
+            if self.payload_parent(id).is_some() {
+                payloads.push((name, id));
+            } else if let Some(name) = self.claim_name(name, id, &mut canonical) {
+                renamed.push((name, id));
+            }
+        }
+
+        let renamed: Vec<(TypeId, String, String)> = renamed
+            .into_iter()
+            .map(|(name, id)| {
+                let new_name = self.rename_with_suffix(id, &name);
+
+                (id, name, new_name)
+            })
+            .collect();
+
+        // The payloads of a renamed union follow it, so `MyUnion_Variant` becomes
+        // `MyUnion_2_Variant` rather than colliding with the other `MyUnion_Variant`.
+        let mut renamed_payloads = Vec::new();
+
+        for (mut name, id) in payloads {
+            let parent = self.payload_parent(id);
+            let new_name = renamed
+                .iter()
+                .find(|(union, _, _)| Some(*union) == parent)
+                .and_then(|(_, old, new)| {
+                    let tag_name = name.strip_prefix(old.as_str())?.strip_prefix('_')?;
+
+                    Some(format!("{new}_{tag_name}"))
+                });
+
+            if let Some(new_name) = new_name {
+                self.set_name(id, &new_name);
+                name = new_name;
+            }
+
+            if let Some(name) = self.claim_name(name, id, &mut canonical) {
+                renamed_payloads.push((name, id));
+            }
+        }
+
+        for (name, id) in renamed_payloads {
+            self.rename_with_suffix(id, &name);
+        }
+
+        self.merge_into_canonical(&canonical);
+        self.dedup_structural();
+        self.renumber_generated_names();
+
+        self
+    }
+
+    /// Registers `name` for `id`, a type that [Types::merge] brought in from another
+    /// graph. If a structurally identical type already has that name, `id` is merged
+    /// into it instead. If a different type has it, the name is handed back.
+    fn claim_name(&mut self, name: String, id: TypeId, canonical: &mut [TypeId]) -> Option<String> {
+        match self.types_by_name.get(&name) {
+            None => {
+                self.types_by_name.insert(name, id);
+
+                None
+            }
+            Some(existing)
+                if self.sizes[existing.0] == self.sizes[id.0]
+                    && self.aligns[existing.0] == self.aligns[id.0]
+                    && self.is_equivalent(self.get_type(*existing), self.get_type(id)) =>
+            {
+                canonical[id.0] = *existing;
+
+                None
+            }
+            Some(_) => Some(name),
+        }
+    }
+
+    /// Renames `id` to `name` plus the first of `_2`, `_3`, ... that isn't taken yet.
+    fn rename_with_suffix(&mut self, id: TypeId, name: &str) -> String {
+        let new_name = (2..)
+            .map(|suffix| format!("{name}_{suffix}"))
+            .find(|candidate| !self.types_by_name.contains_key(candidate))
+            .unwrap();
+
+        self.set_name(id, &new_name);
+        self.types_by_name.insert(new_name.clone(), id);
+
+        new_name
+    }
+
+    /// Removes every type that isn't either registered under a name for which `keep`
+    /// returns true, or (transitively) referred to by one that is. This lets a host
+    /// generate bindings for just its public surface, without the declarations those
+    /// types depend on going missing.
+    ///
+    /// Entry points aren't kept for their own sake: one survives only if its type does,
+    /// and the rest are dropped from [Types::entry_points], so specs don't generate
+    /// wrappers for them.
+    pub fn retain_named(&mut self, keep: impl Fn(&str) -> bool) {
+        let mut kept = vec![false; self.types.len()];
+        let mut stack: Vec<TypeId> = self
+            .types_by_name
+            .iter()
+            .filter(|(name, _)| keep(name))
+            .map(|(_, id)| *id)
+            .collect();
+
+        // UNIT is what non-toplevel functions without a lambda set refer to.
+        stack.push(Types::UNIT);
+
+        while let Some(id) = stack.pop() {
+            if id == TypeId::PENDING || kept[id.0] {
+                continue;
+            }
+
+            kept[id.0] = true;
+
+            // for_each_type_id_mut is the only traversal RocType offers, so walk a copy.
+            self.get_type(id)
+                .clone()
+                .for_each_type_id_mut(&mut |child| stack.push(*child));
+        }
+
+        if kept.iter().all(|kept| *kept) {
+            return;
+        }
+
+        let mut next_id = 0;
+        let new_ids: Vec<Option<TypeId>> = kept
+            .iter()
+            .map(|kept| {
+                kept.then(|| {
+                    next_id += 1;
+                    TypeId(next_id - 1)
+                })
+            })
+            .collect();
+
+        self.compact(&kept, &new_ids);
+    }
+
+    /// Drops the types whose slot in `kept` is false, and rewrites every TypeId
+    /// according to `new_ids`. References which map to `None` are removed; a type
+    /// that's kept must only refer to types which map to `Some`.
+    fn compact(&mut self, kept: &[bool], new_ids: &[Option<TypeId>]) {
+        let remap = |id: TypeId| {
+            if id == TypeId::PENDING {
+                Some(id)
+            } else {
+                new_ids[id.0]
+            }
+        };
+
+        let old_types = core::mem::take(&mut self.types);
+        let old_sizes = core::mem::take(&mut self.sizes);
+        let old_aligns = core::mem::take(&mut self.aligns);
+
+        for (index, ((mut typ, size), align)) in old_types
+            .into_iter()
+            .zip(old_sizes)
+            .zip(old_aligns)
+            .enumerate()
+        {
+            if kept[index] {
+                typ.for_each_type_id_mut(&mut |id| {
+                    *id = remap(*id).unwrap_or_else(|| {
+                        internal_error!("kept type {index} refers to removed type {id:?}")
+                    })
+                });
+
+                self.types.push(typ);
+                self.sizes.push(size);
+                self.aligns.push(align);
+            }
+        }
+
+        self.entry_points = core::mem::take(&mut self.entry_points)
+            .into_iter()
+            .filter_map(|(name, id)| Some((name, remap(id)?)))
+            .collect();
+
+        self.types_by_name = core::mem::take(&mut self.types_by_name)
+            .into_iter()
+            .filter_map(|(name, id)| Some((name, remap(id)?)))
+            .collect();
+
+        self.aliased = core::mem::take(&mut self.aliased)
+            .into_iter()
+            .filter_map(remap)
+            .collect();
+
+        self.deps = compact_edges(core::mem::take(&mut self.deps), remap);
+
+        // When two unions were merged, or two payloads were, their parents are combined.
+        self.payload_parents = compact_edges(core::mem::take(&mut self.payload_parents), remap);
+    }
+
+    /// Renames the records and tag unions whose names were generated (`R1`, `U1`, and
+    /// the payload structs named after those unions) so that the numbering follows
+    /// their structure, not the order they happened to be reached in. Otherwise the
+    /// same platform could get different names depending on how its exports are listed.
+    ///
+    /// Types named by a Roc alias keep their names.
+    pub fn renumber_generated_names(&mut self) {
+        let mut structs = Vec::new();
+        let mut unions = Vec::new();
+
+        for id in self.ids() {
+            if self.aliased.contains(&id) {
+                continue;
+            }
+
+            match self.get_type(id) {
+                RocType::Struct { .. } => structs.push((render_roc_type(self, id), id)),
+                RocType::TagUnion(_) => unions.push((render_roc_type(self, id), id)),
+                _ => {}
+            }
+        }
+
+        // Structurally identical types were already merged by dedup_structural, so
+        // the TypeId only breaks ties between types like recursive unions, whose
+        // rendering mentions their own generated name.
+        structs.sort();
+        unions.sort();
+
+        let mut new_names: Vec<Option<String>> = vec![None; self.types.len()];
+
+        for (index, (_, id)) in structs.iter().enumerate() {
+            new_names[id.0] = Some(format!("R{}", index + 1));
+        }
+
+        for (index, (_, id)) in unions.iter().enumerate() {
+            let name = format!("U{}", index + 1);
+
+            if let RocType::TagUnion(union) = self.get_type(*id) {
+                union.for_each_tag_payload(|tag_name, payload| {
+                    if let RocType::TagUnionPayload { .. } = self.get_type(payload) {
+                        new_names[payload.0] = Some(format!("{name}_{tag_name}"));
+                    }
+                });
+            }
+
+            new_names[id.0] = Some(name);
+        }
+
+        for (id, new_name) in new_names.iter().enumerate() {
+            let Some(new_name) = new_name else {
+                continue;
+            };
+
+            self.set_name(TypeId(id), new_name);
+        }
+
+        // Drop the old names of the renamed types; their new names are added below.
+        self.types_by_name.retain(|_, id| new_names[id.0].is_none());
+
+        for (id, new_name) in new_names.into_iter().enumerate() {
+            if let Some(new_name) = new_name {
+                self.types_by_name.insert(new_name, TypeId(id));
+            }
+        }
+    }
+
+    fn set_name(&mut self, id: TypeId, new_name: &str) {
+        match &mut self.types[id.0] {
+            RocType::Struct { name, .. }
+            | RocType::TagUnionPayload { name, .. }
+            | RocType::Opaque { name } => *name = new_name.to_string(),
+            RocType::TagUnion(union) => *union.name_mut() = new_name.to_string(),
+            other => internal_error!("{other:?} does not have a name"),
+        }
+    }
+
+    /// Checks that every TypeId stored in a type, a dependency edge, or a payload's parents
+    /// refers to a type
+    /// that exists, and that no recursive pointer is still [TypeId::PENDING]. Building
+    /// Types runs this in debug builds, so a mistake in resolving recursive types is
+    /// reported where it happens rather than as a panic somewhere in an emitter.
+    pub fn check_invariants(&self) -> Result<(), BindgenError> {
+        let check = |id: TypeId, referenced_by: TypeId| {
+            if id == TypeId::PENDING {
+                Err(BindgenError::PendingTypeId { referenced_by })
+            } else if id.0 >= self.types.len() {
+                Err(BindgenError::UnknownTypeId { id, referenced_by })
+            } else {
+                Ok(())
+            }
+        };
+
+        for referenced_by in self.ids() {
+            let mut result = Ok(());
+
+            // for_each_type_id_mut is the only traversal RocType offers, so walk a copy.
+            self.get_type(referenced_by)
+                .clone()
+                .for_each_type_id_mut(&mut |id| result = result.and(check(*id, referenced_by)));
+
+            result?;
+        }
+
+        for (id, depends_on) in self.deps.iter() {
+            check(*id, *id)?;
+
+            for dep in depends_on {
+                check(*dep, *id)?;
+            }
+        }
+
+        for (payload, parents) in self.payload_parents.iter() {
+            check(*payload, *payload)?;
+
+            for parent in parents {
+                check(*parent, *payload)?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn depends(&mut self, id: TypeId, depends_on: TypeId) {
         self.deps.get_or_insert(id, Vec::new).push(depends_on);
     }
@@ -604,6 +1320,102 @@ impl Types {
         }
     }
 
+    /// The byte offset of a struct's field, where `field_index` counts fields in the
+    /// order they're stored (which is already sorted into memory order). Returns `None`
+    /// if the type isn't a struct, the index is out of range, or the struct contains
+    /// closures, whose sizes are only known at runtime.
+    pub fn field_offset(&self, struct_id: TypeId, field_index: usize) -> Option<u32> {
+        let fields = match self.get_type(struct_id) {
+            RocType::Struct {
+                fields: RocStructFields::HasNoClosure { fields },
+                ..
+            }
+            | RocType::TagUnionPayload {
+                fields: RocStructFields::HasNoClosure { fields },
+                ..
+            } => fields,
+            _ => return None,
+        };
+
+        let (_, field_id) = fields.get(field_index)?;
+        let end_of_previous = fields[..field_index].iter().fold(0, |offset, (_, id)| {
+            round_up_to_alignment(offset, self.align(*id)) + self.size_rounded_to_alignment(*id)
+        });
+
+        Some(round_up_to_alignment(
+            end_of_previous,
+            self.align(*field_id),
+        ))
+    }
+
+    /// Whether a value of this type owns heap allocations, directly or through any of
+    /// its fields or payloads, so a host must hand it back to Roc to be deallocated.
+    ///
+    /// Recursive tag unions always do. Types whose contents hosts can't see (opaque
+    /// types and lambda sets, which are currently unsized) are assumed to as well.
+    pub fn owns_heap(&self, id: TypeId) -> bool {
+        let mut visited = vec![false; self.types.len()];
+        let mut stack = vec![id];
+
+        while let Some(id) = stack.pop() {
+            if core::mem::replace(&mut visited[id.0], true) {
+                continue;
+            }
+
+            match self.get_type(id) {
+                RocType::RocStr
+                | RocType::RocList(_)
+                | RocType::RocDict(_, _)
+                | RocType::RocSet(_)
+                | RocType::RocBox(_)
+                | RocType::RecursivePointer(_)
+                | RocType::Unsized
+                | RocType::Opaque { .. }
+                | RocType::TagUnion(
+                    RocTagUnion::Recursive { .. }
+                    | RocTagUnion::NonNullableUnwrapped { .. }
+                    | RocTagUnion::NullableWrapped { .. }
+                    | RocTagUnion::NullableUnwrapped { .. },
+                ) => return true,
+                RocType::Bool | RocType::Num(_) | RocType::Unit | RocType::EmptyTagUnion => {}
+                // A function value is its closure, so only the captures matter.
+                RocType::Function(RocFn { lambda_set, .. }) => stack.push(*lambda_set),
+                RocType::RocResult(_, _)
+                | RocType::Struct { .. }
+                | RocType::TagUnionPayload { .. }
+                | RocType::TagUnion(_) => {
+                    // for_each_type_id_mut is the only traversal RocType offers, so walk a copy.
+                    self.get_type(id)
+                        .clone()
+                        .for_each_type_id_mut(&mut |child| stack.push(*child));
+                }
+            }
+        }
+
+        false
+    }
+
+    /// `(name, size, alignment)` for every named type, sorted by name, with sizes
+    /// rounded up to alignment as `sizeof` would report them. Meant for tooling to
+    /// diff against a committed baseline, so a type silently changing size gets noticed.
+    pub fn size_manifest(&self) -> Vec<(String, u32, u32)> {
+        let mut manifest: Vec<_> = self
+            .types_by_name
+            .iter()
+            .map(|(name, id)| {
+                (
+                    name.clone(),
+                    self.size_rounded_to_alignment(*id),
+                    self.align(*id),
+                )
+            })
+            .collect();
+
+        manifest.sort();
+
+        manifest
+    }
+
     pub fn replace(&mut self, id: TypeId, typ: RocType) {
         debug_assert!(self.types.get(id.0).is_some());
 
@@ -615,7 +1427,55 @@ impl Types {
     }
 
     pub fn sorted_ids(&self) -> Vec<TypeId> {
-        use roc_collections::{ReferenceMatrix, TopologicalSort};
+        match self.try_sorted_ids() {
+            Ok(ids) => ids,
+            Err(err) => internal_error!("{err}"),
+        }
+    }
+
+    /// Like [Types::sorted_ids], except dependencies which form a cycle result in an
+    /// error rather than a panic.
+    pub fn try_sorted_ids(&self) -> Result<Vec<TypeId>, BindgenError> {
+        Ok(self.dependency_groups()?.into_iter().flatten().collect())
+    }
+
+    /// Like [Types::sorted_ids], but types that don't depend on each other are ordered
+    /// alphabetically by name instead of by TypeId. TypeIds shift whenever an unrelated
+    /// type is added or removed, so this keeps generated declarations from churning.
+    pub fn sorted_ids_by_name(&self) -> Vec<TypeId> {
+        match self.try_sorted_ids_by_name() {
+            Ok(ids) => ids,
+            Err(err) => internal_error!("{err}"),
+        }
+    }
+
+    /// Like [Types::sorted_ids_by_name], except dependencies which form a cycle result
+    /// in an error rather than a panic.
+    pub fn try_sorted_ids_by_name(&self) -> Result<Vec<TypeId>, BindgenError> {
+        let mut names = vec![None; self.types.len()];
+
+        for (name, id) in self.types_by_name.iter() {
+            names[id.0] = Some(name.as_str());
+        }
+
+        Ok(self
+            .dependency_groups()?
+            .into_iter()
+            .flat_map(|mut group| {
+                group.sort_by_key(|id| (names[id.0], *id));
+                group
+            })
+            .collect())
+    }
+
+    /// Groups of types which don't depend on each other, with every type's
+    /// dependencies appearing in an earlier group.
+    ///
+    /// Recursive types refer to themselves through a RecursivePointer, which isn't
+    /// a dependency, so a cycle here means the graph is malformed and there is no
+    /// order an emitter could declare these types in.
+    fn dependency_groups(&self) -> Result<Vec<Vec<TypeId>>, BindgenError> {
+        use roc_collections::{ReferenceMatrix, TopologicalSort};
 
         let mut matrix = ReferenceMatrix::new(self.types.len());
 
@@ -626,16 +1486,27 @@ impl Types {
         }
 
         match matrix.topological_sort_into_groups() {
-            TopologicalSort::Groups { groups } => groups
+            TopologicalSort::Groups { groups } => Ok(groups
                 .into_iter()
-                .flatten()
                 .rev()
-                .map(|n| TypeId(n as usize))
-                .collect(),
-            TopologicalSort::HasCycles {
-                groups: _,
-                nodes_in_cycle,
-            } => unreachable!("Cyclic type definitions: {:?}", nodes_in_cycle),
+                .map(|group| {
+                    group
+                        .into_iter()
+                        .rev()
+                        .map(|n| TypeId(n as usize))
+                        .collect()
+                })
+                .collect()),
+            TopologicalSort::HasCycles { nodes_in_cycle, .. } => {
+                let mut ids: Vec<TypeId> = nodes_in_cycle
+                    .into_iter()
+                    .map(|n| TypeId(n as usize))
+                    .collect();
+
+                ids.sort();
+
+                Err(BindgenError::CyclicDependencies { ids })
+            }
         }
     }
 
@@ -644,6 +1515,31 @@ impl Types {
     }
 }
 
+/// Rewrites the TypeIds in a set of edges (like [Types::deps]) according to `remap`,
+/// dropping the ones which map to None. Keys which now coincide get their edges combined.
+fn compact_edges(
+    edges: VecMap<TypeId, Vec<TypeId>>,
+    remap: impl Fn(TypeId) -> Option<TypeId>,
+) -> VecMap<TypeId, Vec<TypeId>> {
+    let mut compacted: VecMap<TypeId, Vec<TypeId>> = VecMap::with_capacity(edges.len());
+
+    for (id, targets) in edges.into_iter() {
+        let Some(id) = remap(id) else {
+            continue;
+        };
+
+        let new_targets = compacted.get_or_insert(id, Vec::new);
+
+        for target in targets.into_iter().filter_map(&remap) {
+            if !new_targets.contains(&target) {
+                new_targets.push(target);
+            }
+        }
+    }
+
+    compacted
+}
+
 impl From<&Types> for roc_type::Types {
     fn from(types: &Types) -> Self {
         let deps = types
@@ -663,11 +1559,40 @@ impl From<&Types> for roc_type::Types {
             .map(|(k, v)| roc_type::Tuple1::T(k.as_str().into(), v.0 as _))
             .collect();
 
+        let payload_parents = types
+            .payload_parents
+            .iter()
+            .map(|(k, v)| roc_type::Tuple2::T(k.0 as _, v.iter().map(|x| x.0 as _).collect()))
+            .collect();
+
+        let roots = types
+            .ids()
+            .filter(|id| types.is_root(*id))
+            .map(|id| id.0 as _)
+            .collect();
+
+        let sorted_by_name = if types.sort_by_name {
+            types
+                .sorted_ids_by_name()
+                .into_iter()
+                .map(|id| id.0 as _)
+                .collect()
+        } else {
+            roc_std::RocList::empty()
+        };
+
         roc_type::Types {
             aligns: types.aligns.as_slice().into(),
             deps,
             entrypoints,
+            payload_parents,
+            roots,
+            explicit_padding: types.explicit_padding,
+            nest_payload_types: types.nest_payload_types,
+            payload_accessors: types.payload_accessors,
+            sort_by_name: types.sort_by_name,
             sizes: types.sizes.as_slice().into(),
+            sorted_by_name,
             types: types.types.iter().map(roc_type::RocType::from).collect(),
             types_by_name,
             target: types.target.into(),
@@ -716,6 +1641,7 @@ impl From<&RocType> for roc_type::RocType {
             }),
             RocType::Unit => roc_type::RocType::Unit,
             RocType::Unsized => roc_type::RocType::Unsized,
+            RocType::Opaque { name } => roc_type::RocType::Opaque(name.as_str().into()),
         }
     }
 }
@@ -877,7 +1803,10 @@ impl From<&RocSingleTagPayload> for roc_type::RocSingleTagPayload {
                 roc_type::RocSingleTagPayload::HasNoClosure(
                     payload_fields
                         .iter()
-                        .map(|id| roc_type::R16 { id: id.0 as _ })
+                        .map(|(name, id)| roc_type::R4 {
+                            id: id.0 as _,
+                            name: name.as_str().into(),
+                        })
                         .collect(),
                 )
             }
@@ -938,6 +1867,475 @@ impl From<OperatingSystem> for roc_type::OperatingSystem {
     }
 }
 
+impl Types {
+    /// Serializes the whole type graph as JSON, for tooling that can't link against this crate.
+    ///
+    /// Types refer to one another by id (their index in `"types"`), never inline,
+    /// so recursive types don't expand forever. Output order is deterministic.
+    pub fn to_json(&self) -> String {
+        let types = self.ids().map(|id| {
+            let mut fields = vec![
+                ("id", id.0.to_string()),
+                ("size", self.size_ignoring_alignment(id).to_string()),
+                ("align", self.align(id).to_string()),
+                ("aliased", self.aliased.contains(&id).to_string()),
+                ("shape", roc_type_to_json(self.get_type(id))),
+            ];
+
+            let mut deps: Vec<TypeId> = self.deps.get(&id).cloned().unwrap_or_default();
+            deps.sort();
+            fields.push(("deps", json_array(deps.into_iter().map(json_id))));
+
+            json_object(fields)
+        });
+
+        let mut by_name: Vec<(&String, &TypeId)> = self.types_by_name.iter().collect();
+        by_name.sort();
+
+        json_object([
+            (
+                "target",
+                json_object([
+                    (
+                        "architecture",
+                        json_string(&format!("{:?}", self.target.architecture())),
+                    ),
+                    (
+                        "operating_system",
+                        json_string(&format!("{:?}", self.target.operating_system())),
+                    ),
+                ]),
+            ),
+            (
+                "entry_points",
+                json_array(
+                    self.entry_points
+                        .iter()
+                        .map(|(name, id)| json_named_id(name, *id)),
+                ),
+            ),
+            (
+                "types_by_name",
+                json_array(
+                    by_name
+                        .into_iter()
+                        .map(|(name, id)| json_named_id(name, *id)),
+                ),
+            ),
+            ("types", json_array(types)),
+        ])
+    }
+}
+
+fn roc_type_to_json(typ: &RocType) -> String {
+    let kind = |name: &str| ("kind", json_string(name));
+
+    match typ {
+        RocType::RocStr => json_object([kind("RocStr")]),
+        RocType::Bool => json_object([kind("Bool")]),
+        RocType::EmptyTagUnion => json_object([kind("EmptyTagUnion")]),
+        RocType::Unit => json_object([kind("Unit")]),
+        RocType::Unsized => json_object([kind("Unsized")]),
+        RocType::Opaque { name } => json_object([kind("Opaque"), ("name", json_string(name))]),
+        RocType::Num(num) => json_object([kind("Num"), ("num", json_string(&format!("{num:?}")))]),
+        RocType::RocResult(ok, err) => json_object([
+            kind("RocResult"),
+            ("ok", json_id(*ok)),
+            ("err", json_id(*err)),
+        ]),
+        RocType::RocDict(key, val) => json_object([
+            kind("RocDict"),
+            ("key", json_id(*key)),
+            ("value", json_id(*val)),
+        ]),
+        RocType::RocList(elem) => json_object([kind("RocList"), ("elem", json_id(*elem))]),
+        RocType::RocSet(elem) => json_object([kind("RocSet"), ("elem", json_id(*elem))]),
+        RocType::RocBox(elem) => json_object([kind("RocBox"), ("elem", json_id(*elem))]),
+        RocType::RecursivePointer(pointee) => {
+            json_object([kind("RecursivePointer"), ("pointee", json_id(*pointee))])
+        }
+        RocType::Struct { name, fields } => json_object([
+            kind("Struct"),
+            ("name", json_string(name)),
+            ("fields", struct_fields_to_json(fields)),
+        ]),
+        RocType::TagUnionPayload { name, fields } => json_object([
+            kind("TagUnionPayload"),
+            ("name", json_string(name)),
+            ("fields", struct_fields_to_json(fields)),
+        ]),
+        RocType::Function(RocFn {
+            function_name,
+            extern_name,
+            is_toplevel,
+            args,
+            lambda_set,
+            ret,
+        }) => json_object([
+            kind("Function"),
+            ("function_name", json_string(function_name)),
+            ("extern_name", json_string(extern_name)),
+            ("is_toplevel", is_toplevel.to_string()),
+            ("args", json_array(args.iter().copied().map(json_id))),
+            ("lambda_set", json_id(*lambda_set)),
+            ("ret", json_id(*ret)),
+        ]),
+        RocType::TagUnion(union) => tag_union_to_json(union),
+    }
+}
+
+fn tag_union_to_json(union: &RocTagUnion) -> String {
+    let kind = |name: &str| ("kind", json_string(name));
+    let tags_to_json = |tags: &[(String, Option<TypeId>)]| {
+        json_array(tags.iter().map(|(name, payload)| {
+            json_object([
+                ("name", json_string(name)),
+                (
+                    "payload",
+                    payload.map_or_else(|| "null".to_string(), json_id),
+                ),
+            ])
+        }))
+    };
+
+    match union {
+        RocTagUnion::Enumeration { name, tags, size } => json_object([
+            kind("Enumeration"),
+            ("name", json_string(name)),
+            ("tags", json_array(tags.iter().map(|tag| json_string(tag)))),
+            ("size", size.to_string()),
+        ]),
+        RocTagUnion::NonRecursive {
+            name,
+            tags,
+            discriminant_offset,
+            discriminant_size,
+        }
+        | RocTagUnion::Recursive {
+            name,
+            tags,
+            discriminant_offset,
+            discriminant_size,
+        } => json_object([
+            kind(match union {
+                RocTagUnion::NonRecursive { .. } => "NonRecursive",
+                _ => "Recursive",
+            }),
+            ("name", json_string(name)),
+            ("tags", tags_to_json(tags)),
+            ("discriminant_offset", discriminant_offset.to_string()),
+            ("discriminant_size", discriminant_size.to_string()),
+        ]),
+        RocTagUnion::NonNullableUnwrapped {
+            name,
+            tag_name,
+            payload,
+        } => json_object([
+            kind("NonNullableUnwrapped"),
+            ("name", json_string(name)),
+            ("tag_name", json_string(tag_name)),
+            ("payload", json_id(*payload)),
+        ]),
+        RocTagUnion::SingleTagStruct {
+            name,
+            tag_name,
+            payload,
+        } => {
+            let payload = match payload {
+                RocSingleTagPayload::HasNoClosure { payload_fields } => {
+                    json_array(payload_fields.iter().map(|(name, id)| {
+                        json_object([("name", json_string(name)), ("id", json_id(*id))])
+                    }))
+                }
+                RocSingleTagPayload::HasClosure { payload_getters } => {
+                    json_array(payload_getters.iter().map(|(id, getter)| {
+                        json_object([("id", json_id(*id)), ("getter", json_string(getter))])
+                    }))
+                }
+            };
+
+            json_object([
+                kind("SingleTagStruct"),
+                ("name", json_string(name)),
+                ("tag_name", json_string(tag_name)),
+                ("payload", payload),
+            ])
+        }
+        RocTagUnion::NullableWrapped {
+            name,
+            index_of_null_tag,
+            tags,
+            discriminant_size,
+            discriminant_offset,
+        } => json_object([
+            kind("NullableWrapped"),
+            ("name", json_string(name)),
+            ("index_of_null_tag", index_of_null_tag.to_string()),
+            ("tags", tags_to_json(tags)),
+            ("discriminant_offset", discriminant_offset.to_string()),
+            ("discriminant_size", discriminant_size.to_string()),
+        ]),
+        RocTagUnion::NullableUnwrapped {
+            name,
+            null_tag,
+            non_null_tag,
+            non_null_payload,
+            null_represents_first_tag,
+        } => json_object([
+            kind("NullableUnwrapped"),
+            ("name", json_string(name)),
+            ("null_tag", json_string(null_tag)),
+            ("non_null_tag", json_string(non_null_tag)),
+            ("non_null_payload", json_id(*non_null_payload)),
+            (
+                "null_represents_first_tag",
+                null_represents_first_tag.to_string(),
+            ),
+        ]),
+    }
+}
+
+fn struct_fields_to_json(fields: &RocStructFields) -> String {
+    match fields {
+        RocStructFields::HasNoClosure { fields } => {
+            json_array(fields.iter().map(|(name, id)| json_named_id(name, *id)))
+        }
+        RocStructFields::HasClosure { fields } => {
+            json_array(fields.iter().map(|(name, id, accessors)| {
+                json_object([
+                    ("name", json_string(name)),
+                    ("id", json_id(*id)),
+                    ("getter", json_string(&accessors.getter)),
+                ])
+            }))
+        }
+    }
+}
+
+fn json_named_id(name: &str, id: TypeId) -> String {
+    json_object([("name", json_string(name)), ("id", json_id(id))])
+}
+
+fn json_id(id: TypeId) -> String {
+    id.0.to_string()
+}
+
+fn json_object<'k>(fields: impl IntoIterator<Item = (&'k str, String)>) -> String {
+    let fields: Vec<String> = fields
+        .into_iter()
+        .map(|(key, value)| format!("{}:{value}", json_string(key)))
+        .collect();
+
+    format!("{{{}}}", fields.join(","))
+}
+
+fn json_array(elems: impl IntoIterator<Item = String>) -> String {
+    format!("[{}]", elems.into_iter().collect::<Vec<_>>().join(","))
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+
+    out.push('"');
+
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+
+    out.push('"');
+
+    out
+}
+
+/// Renders a type in Roc syntax, e.g. `{ x : I64, y : Str }` or `[Ok I64, Err Str]`,
+/// for diagnostics which need to show a type to a human. This is not glue for any
+/// particular language; use the `*Glue.roc` specs for that.
+///
+/// Recursive references are rendered using the recursive union's name.
+pub fn render_roc_type(types: &Types, id: TypeId) -> String {
+    match try_render_roc_type(types, id) {
+        Ok(rendered) => rendered,
+        Err(err) => internal_error!("{err}"),
+    }
+}
+
+/// Like [render_roc_type], except a malformed type graph (one with a cycle that
+/// doesn't go through a RecursivePointer) results in an error rather than
+/// recursing until the stack overflows.
+pub fn try_render_roc_type(types: &Types, id: TypeId) -> Result<String, BindgenError> {
+    render_roc_type_help(types, id, 0)
+}
+
+fn render_roc_type_help(types: &Types, id: TypeId, depth: usize) -> Result<String, BindgenError> {
+    if depth > MAX_TYPE_DEPTH {
+        return Err(BindgenError::RecursionLimit {
+            limit: MAX_TYPE_DEPTH,
+        });
+    }
+
+    let arg = |id: &TypeId| render_roc_type_arg(types, *id, depth + 1);
+
+    let rendered = match types.get_type(id) {
+        RocType::RocStr => "Str".to_string(),
+        RocType::Bool => "Bool".to_string(),
+        RocType::Num(num) => format!("{num:?}"),
+        RocType::Unit => "{}".to_string(),
+        RocType::EmptyTagUnion => "[]".to_string(),
+        RocType::Unsized => "*".to_string(),
+        RocType::Opaque { name } => name.clone(),
+        RocType::RocResult(ok, err) => format!("Result {} {}", arg(ok)?, arg(err)?),
+        RocType::RocList(elem) => format!("List {}", arg(elem)?),
+        RocType::RocSet(elem) => format!("Set {}", arg(elem)?),
+        RocType::RocBox(elem) => format!("Box {}", arg(elem)?),
+        RocType::RocDict(key, val) => format!("Dict {} {}", arg(key)?, arg(val)?),
+        RocType::RecursivePointer(pointee) => match types.get_type(*pointee) {
+            RocType::TagUnion(union) => union.name().to_string(),
+            _ => render_roc_type_help(types, *pointee, depth + 1)?,
+        },
+        RocType::Struct { fields, .. } | RocType::TagUnionPayload { fields, .. } => {
+            let fields = struct_field_ids(fields)
+                .into_iter()
+                .map(|(name, id)| {
+                    render_roc_type_help(types, id, depth + 1)
+                        .map(|rendered| format!("{name} : {rendered}"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if fields.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {} }}", fields.join(", "))
+            }
+        }
+        RocType::Function(RocFn { args, ret, .. }) => {
+            let args = args.iter().map(arg).collect::<Result<Vec<_>, _>>()?;
+
+            // Roc functions always take at least one argument, so a host-facing
+            // function with none is shown the way Roc would write it.
+            let args = if args.is_empty() {
+                "{}".to_string()
+            } else {
+                args.join(", ")
+            };
+
+            format!(
+                "{args} -> {}",
+                render_roc_type_help(types, *ret, depth + 1)?
+            )
+        }
+        RocType::TagUnion(union) => render_tag_union(types, union, depth + 1)?,
+    };
+
+    Ok(rendered)
+}
+
+/// Like [render_roc_type], but wrapped in parens when it's more than one word,
+/// so it can be passed as a type argument, e.g. the `(List I64)` in `List (List I64)`.
+fn render_roc_type_arg(types: &Types, id: TypeId, depth: usize) -> Result<String, BindgenError> {
+    let rendered = render_roc_type_help(types, id, depth)?;
+
+    if rendered.contains(' ') && !rendered.starts_with(['{', '[']) {
+        Ok(format!("({rendered})"))
+    } else {
+        Ok(rendered)
+    }
+}
+
+fn render_tag_union(
+    types: &Types,
+    union: &RocTagUnion,
+    depth: usize,
+) -> Result<String, BindgenError> {
+    let render_tag = |tag_name: &str, field_ids: &[TypeId]| -> Result<String, BindgenError> {
+        let mut rendered = tag_name.to_string();
+
+        for id in field_ids {
+            rendered.push(' ');
+            rendered.push_str(&render_roc_type_arg(types, *id, depth)?);
+        }
+
+        Ok(rendered)
+    };
+
+    // A tag's payload is usually a TagUnionPayload, whose fields are the tag's arguments.
+    let payload_field_ids = |payload: Option<TypeId>| match payload {
+        None => Vec::new(),
+        Some(payload) => match types.get_type(payload) {
+            RocType::TagUnionPayload { fields, .. } => struct_field_ids(fields)
+                .into_iter()
+                .map(|(_, id)| id)
+                .collect(),
+            _ => vec![payload],
+        },
+    };
+
+    let tags: Vec<String> = match union {
+        RocTagUnion::Enumeration { tags, .. } => tags.clone(),
+        RocTagUnion::NonRecursive { tags, .. }
+        | RocTagUnion::Recursive { tags, .. }
+        | RocTagUnion::NullableWrapped { tags, .. } => tags
+            .iter()
+            .map(|(tag_name, payload)| render_tag(tag_name, &payload_field_ids(*payload)))
+            .collect::<Result<_, BindgenError>>()?,
+        RocTagUnion::NonNullableUnwrapped {
+            tag_name, payload, ..
+        } => vec![render_tag(tag_name, &payload_field_ids(Some(*payload)))?],
+        RocTagUnion::SingleTagStruct {
+            tag_name, payload, ..
+        } => {
+            let field_ids: Vec<TypeId> = match payload {
+                RocSingleTagPayload::HasNoClosure { payload_fields } => {
+                    payload_fields.iter().map(|(_, id)| *id).collect()
+                }
+                RocSingleTagPayload::HasClosure { payload_getters } => {
+                    payload_getters.iter().map(|(id, _)| *id).collect()
+                }
+            };
+
+            vec![render_tag(tag_name, &field_ids)?]
+        }
+        RocTagUnion::NullableUnwrapped {
+            null_tag,
+            non_null_tag,
+            non_null_payload,
+            null_represents_first_tag,
+            ..
+        } => {
+            let null = null_tag.clone();
+            let non_null = render_tag(non_null_tag, &payload_field_ids(Some(*non_null_payload)))?;
+
+            if *null_represents_first_tag {
+                vec![null, non_null]
+            } else {
+                vec![non_null, null]
+            }
+        }
+    };
+
+    Ok(format!("[{}]", tags.join(", ")))
+}
+
+fn struct_field_ids(fields: &RocStructFields) -> Vec<(&str, TypeId)> {
+    match fields {
+        RocStructFields::HasNoClosure { fields } => fields
+            .iter()
+            .map(|(name, id)| (name.as_str(), *id))
+            .collect(),
+        RocStructFields::HasClosure { fields } => fields
+            .iter()
+            .map(|(name, id, _)| (name.as_str(), *id))
+            .collect(),
+    }
+}
+
 enum RocTypeOrPending<'a> {
     Type(&'a RocType),
     /// A pending recursive pointer
@@ -973,6 +2371,21 @@ impl RocStructFields {
             RocStructFields::HasClosure { fields } => fields.len(),
         }
     }
+
+    fn for_each_type_id_mut(&mut self, f: &mut impl FnMut(&mut TypeId)) {
+        match self {
+            RocStructFields::HasNoClosure { fields } => {
+                for (_, id) in fields {
+                    f(id);
+                }
+            }
+            RocStructFields::HasClosure { fields } => {
+                for (_, id, _) in fields {
+                    f(id);
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1014,6 +2427,55 @@ pub enum RocType {
     Unit,
     /// A type that has a size that is not statically known
     Unsized,
+    /// A type the platform asked to keep opaque, so hosts only learn its name,
+    /// size, and alignment (which live alongside it in `Types`) - never its fields.
+    Opaque {
+        name: String,
+    },
+}
+
+impl RocType {
+    /// Calls the given function on every TypeId this type directly refers to.
+    pub(crate) fn for_each_type_id_mut(&mut self, f: &mut impl FnMut(&mut TypeId)) {
+        match self {
+            RocType::RocStr
+            | RocType::Bool
+            | RocType::Num(_)
+            | RocType::EmptyTagUnion
+            | RocType::Unit
+            | RocType::Unsized
+            | RocType::Opaque { .. } => {}
+            RocType::RocResult(ok, err) => {
+                f(ok);
+                f(err);
+            }
+            RocType::RocDict(key, val) => {
+                f(key);
+                f(val);
+            }
+            RocType::RocList(elem)
+            | RocType::RocSet(elem)
+            | RocType::RocBox(elem)
+            | RocType::RecursivePointer(elem) => f(elem),
+            RocType::TagUnion(union) => union.for_each_type_id_mut(f),
+            RocType::Struct { fields, .. } | RocType::TagUnionPayload { fields, .. } => {
+                fields.for_each_type_id_mut(f)
+            }
+            RocType::Function(RocFn {
+                args,
+                lambda_set,
+                ret,
+                ..
+            }) => {
+                for arg in args {
+                    f(arg);
+                }
+
+                f(lambda_set);
+                f(ret);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
@@ -1083,8 +2545,9 @@ pub enum RocSingleTagPayload {
     HasClosure {
         payload_getters: Vec<(TypeId, String)>,
     },
+    /// The fields are named by the Env's [PositionalNaming], in payload order.
     HasNoClosure {
-        payload_fields: Vec<TypeId>,
+        payload_fields: Vec<(String, TypeId)>,
     },
 }
 
@@ -1161,6 +2624,88 @@ pub enum RocTagUnion {
     },
 }
 
+impl RocTagUnion {
+    pub fn name(&self) -> &str {
+        match self {
+            RocTagUnion::Enumeration { name, .. }
+            | RocTagUnion::NonRecursive { name, .. }
+            | RocTagUnion::Recursive { name, .. }
+            | RocTagUnion::NonNullableUnwrapped { name, .. }
+            | RocTagUnion::SingleTagStruct { name, .. }
+            | RocTagUnion::NullableWrapped { name, .. }
+            | RocTagUnion::NullableUnwrapped { name, .. } => name,
+        }
+    }
+
+    fn name_mut(&mut self) -> &mut String {
+        match self {
+            RocTagUnion::Enumeration { name, .. }
+            | RocTagUnion::NonRecursive { name, .. }
+            | RocTagUnion::Recursive { name, .. }
+            | RocTagUnion::NonNullableUnwrapped { name, .. }
+            | RocTagUnion::SingleTagStruct { name, .. }
+            | RocTagUnion::NullableWrapped { name, .. }
+            | RocTagUnion::NullableUnwrapped { name, .. } => name,
+        }
+    }
+
+    /// Calls the given function with each tag's name and payload, for the tags which
+    /// have a payload stored as a single TypeId.
+    fn for_each_tag_payload(&self, mut f: impl FnMut(&str, TypeId)) {
+        match self {
+            RocTagUnion::Enumeration { .. } | RocTagUnion::SingleTagStruct { .. } => {}
+            RocTagUnion::NonRecursive { tags, .. }
+            | RocTagUnion::Recursive { tags, .. }
+            | RocTagUnion::NullableWrapped { tags, .. } => {
+                for (tag_name, payload) in tags {
+                    if let Some(id) = payload {
+                        f(tag_name, *id);
+                    }
+                }
+            }
+            RocTagUnion::NonNullableUnwrapped {
+                tag_name, payload, ..
+            } => f(tag_name, *payload),
+            RocTagUnion::NullableUnwrapped {
+                non_null_tag,
+                non_null_payload,
+                ..
+            } => f(non_null_tag, *non_null_payload),
+        }
+    }
+
+    fn for_each_type_id_mut(&mut self, f: &mut impl FnMut(&mut TypeId)) {
+        match self {
+            RocTagUnion::Enumeration { .. } => {}
+            RocTagUnion::NonRecursive { tags, .. }
+            | RocTagUnion::Recursive { tags, .. }
+            | RocTagUnion::NullableWrapped { tags, .. } => {
+                for (_, payload) in tags {
+                    if let Some(id) = payload {
+                        f(id);
+                    }
+                }
+            }
+            RocTagUnion::NonNullableUnwrapped { payload, .. } => f(payload),
+            RocTagUnion::NullableUnwrapped {
+                non_null_payload, ..
+            } => f(non_null_payload),
+            RocTagUnion::SingleTagStruct { payload, .. } => match payload {
+                RocSingleTagPayload::HasClosure { payload_getters } => {
+                    for (id, _) in payload_getters {
+                        f(id);
+                    }
+                }
+                RocSingleTagPayload::HasNoClosure { payload_fields } => {
+                    for (_, id) in payload_fields {
+                        f(id);
+                    }
+                }
+            },
+        }
+    }
+}
+
 struct Env<'a> {
     arena: &'a Bump,
     subs: &'a Subs,
@@ -1172,6 +2717,11 @@ struct Env<'a> {
     enum_names: Enums,
     pending_recursive_types: VecMap<TypeId, Variable>,
     known_recursive_types: VecMap<Variable, TypeId>,
+    positional_field_naming: PositionalNaming,
+    opaque_names: VecSet<String>,
+    /// The first problem with the platform's types that isn't a bug in glue,
+    /// which is reported once every entry point has been added.
+    error: Option<BindgenError>,
 }
 
 impl<'a> Env<'a> {
@@ -1182,6 +2732,8 @@ impl<'a> Env<'a> {
         layout_interner: TLLayoutInterner<'a>,
         glue_procs_by_layout: MutMap<Layout<'a>, &'a [String]>,
         target: Target,
+        positional_field_naming: PositionalNaming,
+        opaque_names: VecSet<String>,
     ) -> Self {
         Env {
             arena,
@@ -1191,6 +2743,9 @@ impl<'a> Env<'a> {
             enum_names: Default::default(),
             pending_recursive_types: Default::default(),
             known_recursive_types: Default::default(),
+            positional_field_naming,
+            opaque_names,
+            error: None,
             glue_procs_by_layout,
             lambda_set_ids: Default::default(),
             layout_cache: LayoutCache::new(layout_interner, target),
@@ -1370,9 +2925,15 @@ fn add_type_help<'a>(
                 None => env.struct_names.get_name(var),
             };
 
-            add_struct(env, name, it, types, layout, |name, fields| {
+            let type_id = add_struct(env, name, it, types, layout, |name, fields| {
                 RocType::Struct { name, fields }
-            })
+            });
+
+            if opt_name.is_some() {
+                types.aliased.insert(type_id);
+            }
+
+            type_id
         }
         Content::Structure(FlatType::TagUnion(tags, ext_var)) => {
             debug_assert!(ext_var_is_empty_tag_union(subs, *ext_var));
@@ -1542,6 +3103,12 @@ fn add_type_help<'a>(
                         unreachable!()
                     }
                 }
+            } else if env
+                .opaque_names
+                .iter()
+                .any(|opaque| opaque == name.as_str(env.interns))
+            {
+                add_opaque(env, name.as_str(env.interns).to_string(), types, layout)
             } else {
                 // If this was a non-builtin type alias, we can use that alias name
                 // in the generated bindings.
@@ -1586,6 +3153,30 @@ fn add_type_help<'a>(
     }
 }
 
+fn add_opaque<'a>(
+    env: &mut Env<'a>,
+    name: String,
+    types: &mut Types,
+    layout: InLayout<'a>,
+) -> TypeId {
+    // A host can't keep the refcounts of values it can't see, so it would end up
+    // copying them around without incrementing them.
+    if env.layout_cache.interner.contains_refcounted(layout) && env.error.is_none() {
+        env.error = Some(BindgenError::RefcountedOpaque { name: name.clone() });
+    }
+
+    let type_id = types.add_named(
+        &env.layout_cache.interner,
+        name.clone(),
+        RocType::Opaque { name },
+        layout,
+    );
+
+    types.aliased.insert(type_id);
+
+    type_id
+}
+
 fn add_builtin_type<'a>(
     env: &mut Env<'a>,
     builtin: Builtin<'a>,
@@ -1885,21 +3476,83 @@ where
     )
 }
 
-trait UnionTag: Label + std::fmt::Debug {
-    fn union_tag_name(&self) -> String;
+/// How fields that only have a position (e.g. the payloads of a tag) are named.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PositionalNaming {
+    /// `0`, `1`, ...
+    #[default]
+    Index,
+    /// `_0`, `_1`, ...
+    Underscore,
+    /// `f0`, `f1`, ...
+    F,
+    /// `field0`, `field1`, ...
+    Field,
 }
 
-impl UnionTag for TagName {
-    fn union_tag_name(&self) -> String {
-        self.0.as_str().to_string()
-    }
-}
+impl PositionalNaming {
+    pub const ALL: [Self; 4] = [Self::Index, Self::Underscore, Self::F, Self::Field];
 
-impl UnionTag for Symbol {
-    fn union_tag_name(&self) -> String {
-        format!("C{:?}_{}", self.module_id(), self.ident_id().index())
+    /// The name this scheme goes by on the command line.
+    pub const fn name(self) -> &'static str {
+        match self {
+            PositionalNaming::Index => "index",
+            PositionalNaming::Underscore => "underscore",
+            PositionalNaming::F => "f",
+            PositionalNaming::Field => "field",
+        }
     }
-}
+
+    pub fn field_name(self, index: usize) -> String {
+        match self {
+            PositionalNaming::Index => format!("{index}"),
+            PositionalNaming::Underscore => format!("_{index}"),
+            PositionalNaming::F => format!("f{index}"),
+            PositionalNaming::Field => format!("field{index}"),
+        }
+    }
+}
+
+impl std::str::FromStr for PositionalNaming {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|naming| naming.name() == s)
+            .ok_or_else(|| format!("unknown positional field naming `{s}`"))
+    }
+}
+
+/// A positional field label. It's ordered by index rather than by its name,
+/// so that e.g. `f10` still sorts after `f2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct PositionalLabel {
+    index: usize,
+    naming: PositionalNaming,
+}
+
+impl Display for PositionalLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.naming.field_name(self.index))
+    }
+}
+
+trait UnionTag: Label + std::fmt::Debug {
+    fn union_tag_name(&self) -> String;
+}
+
+impl UnionTag for TagName {
+    fn union_tag_name(&self) -> String {
+        self.0.as_str().to_string()
+    }
+}
+
+impl UnionTag for Symbol {
+    fn union_tag_name(&self) -> String {
+        format!("C{:?}_{}", self.module_id(), self.ident_id().index())
+    }
+}
 
 fn tag_union_type_from_layout<'a>(
     env: &mut Env<'a>,
@@ -1950,11 +3603,14 @@ fn tag_union_type_from_layout<'a>(
                         .tag_id_offset(&env.layout_cache.interner)
                         .unwrap();
 
-                    RocTagUnion::NonRecursive {
-                        name: name.clone(),
-                        tags,
-                        discriminant_size,
-                        discriminant_offset,
+                    match nullary_enumeration(&name, &tags, discriminant_size) {
+                        Some(enumeration) => enumeration,
+                        None => RocTagUnion::NonRecursive {
+                            name: name.clone(),
+                            tags,
+                            discriminant_size,
+                            discriminant_offset,
+                        },
                     }
                 }
                 // A recursive tag union (general case)
@@ -2085,7 +3741,7 @@ fn tag_union_type_from_layout<'a>(
                 tag_name,
                 payload: RocSingleTagPayload::HasNoClosure {
                     // Builtins have no closures
-                    payload_fields: vec![type_id],
+                    payload_fields: vec![(env.positional_field_naming.field_name(0), type_id)],
                 },
             }
         }
@@ -2136,6 +3792,12 @@ fn add_tag_union<'a>(
     let typ = RocType::TagUnion(tag_union_type);
     let type_id = types.add_named(&env.layout_cache.interner, name, typ, layout);
 
+    types.record_payload_parents(type_id);
+
+    if opt_name.is_some() {
+        types.aliased.insert(type_id);
+    }
+
     if let Some(rec_var) = rec_root {
         env.known_recursive_types.insert(rec_var, type_id);
     }
@@ -2160,6 +3822,28 @@ fn add_int_enumeration(
     }
 }
 
+/// If none of a non-recursive union's tags carry any data, it's really an enumeration,
+/// even though its layout came through as a `Union` (e.g. because every payload is `{}`).
+/// Emitting it as one spares hosts a tagged union whose payloads are all empty.
+///
+/// tag_to_type already drops zero-sized payloads like `{}`, so a nullary tag is
+/// simply one without a payload.
+fn nullary_enumeration(
+    name: &str,
+    tags: &[(String, Option<TypeId>)],
+    discriminant_size: u32,
+) -> Option<RocTagUnion> {
+    if tags.iter().any(|(_, payload)| payload.is_some()) {
+        return None;
+    }
+
+    Some(RocTagUnion::Enumeration {
+        name: name.to_string(),
+        tags: tags.iter().map(|(tag_name, _)| tag_name.clone()).collect(),
+        size: discriminant_size,
+    })
+}
+
 fn union_tags_to_types<'a>(
     name: &str,
     union_tags: &UnionLabels<impl UnionTag>,
@@ -2258,8 +3942,11 @@ fn single_tag_payload_fields<'a, 'b>(
             payload_fields: payload_vars
                 .iter()
                 .zip(field_layouts.iter())
-                .map(|(field_var, field_layout)| {
-                    add_type_help(env, *field_layout, *field_var, None, types)
+                .enumerate()
+                .map(|(index, (field_var, field_layout))| {
+                    let type_id = add_type_help(env, *field_layout, *field_var, None, types);
+
+                    (env.positional_field_naming.field_name(index), type_id)
                 })
                 .collect(),
         },
@@ -2298,7 +3985,12 @@ fn tag_to_type<'a, D: Display>(
         _ => {
             // create a RocType for the payload and save it
             let struct_name = format!("{}_{}", &name, tag_name); // e.g. "MyUnion_MyVariant"
-            let fields = payload_vars.iter().copied().enumerate();
+            let naming = env.positional_field_naming;
+            let fields = payload_vars
+                .iter()
+                .copied()
+                .enumerate()
+                .map(|(index, var)| (PositionalLabel { index, naming }, var));
             let struct_id = add_struct(env, struct_name, fields, types, layout, |name, fields| {
                 RocType::TagUnionPayload { name, fields }
             });
@@ -2322,3 +4014,1018 @@ fn struct_fields_needed<I: IntoIterator<Item = Variable>>(env: &mut Env<'_>, var
         }
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::load::{load_types, GlueOptions, IgnoreErrors};
+    use roc_load::Threading;
+
+    fn push_type(types: &mut Types, typ: RocType, size: u32, align: u32) -> TypeId {
+        let id = TypeId(types.types.len());
+
+        types.types.push(typ);
+        types.sizes.push(size);
+        types.aligns.push(align);
+
+        id
+    }
+
+    fn point_struct(name: &str, i64_id: TypeId) -> RocType {
+        RocType::Struct {
+            name: name.to_string(),
+            fields: RocStructFields::HasNoClosure {
+                fields: vec![("x".to_string(), i64_id), ("y".to_string(), i64_id)],
+            },
+        }
+    }
+
+    fn returning(name: &str, ret: TypeId) -> RocType {
+        RocType::Function(RocFn {
+            function_name: name.to_string(),
+            extern_name: format!("roc__{name}_1_exposed"),
+            is_toplevel: true,
+            args: Vec::new(),
+            lambda_set: Types::UNIT,
+            ret,
+        })
+    }
+
+    #[test]
+    fn field_offsets_account_for_alignment() {
+        let types = load_declarations(&["Mixed : { a : U8, b : U64, c : U16 }"], "Mixed");
+        let mixed = id_named(&types, "Mixed");
+
+        let fields = match types.get_type(mixed) {
+            RocType::Struct {
+                fields: RocStructFields::HasNoClosure { fields },
+                ..
+            } => fields,
+            other => panic!("Mixed should be a struct, not {other:?}"),
+        };
+        let offsets: Vec<_> = fields
+            .iter()
+            .enumerate()
+            .map(|(index, (name, _))| (name.as_str(), types.field_offset(mixed, index)))
+            .collect();
+
+        // Fields are stored in memory order, biggest alignment first.
+        assert_eq!(offsets, [("b", Some(0)), ("c", Some(8)), ("a", Some(10))]);
+        assert_eq!(types.field_offset(mixed, fields.len()), None);
+        assert_eq!(types.field_offset(fields[0].1, 0), None);
+    }
+
+    #[test]
+    fn sizes_already_include_trailing_padding() {
+        let types = load_declarations(&["Elem : { a : U64, b : U8 }"], "List Elem");
+        let elem = id_named(&types, "Elem");
+
+        // So a host can step through a List by the size; there's no separate stride.
+        assert_eq!(types.size_ignoring_alignment(elem), 16);
+        assert_eq!(types.size_rounded_to_alignment(elem), 16);
+    }
+
+    fn push_named(types: &mut Types, name: &str, i64_id: TypeId) -> TypeId {
+        let id = push_type(types, point_struct(name, i64_id), 16, 8);
+
+        types.types_by_name.insert(name.to_string(), id);
+
+        id
+    }
+
+    /// Loads the types a platform exposes to its host, the same way `roc glue`
+    /// does, and returns the ones for x86_64.
+    fn load_platform(platform: &str, options: &GlueOptions) -> std::io::Result<Types> {
+        let dir = tempfile::tempdir().unwrap();
+        let platform_path = dir.path().join("platform.roc");
+
+        std::fs::write(&platform_path, platform).unwrap();
+
+        let types = load_types(
+            platform_path,
+            Threading::Single,
+            IgnoreErrors { can: false },
+            Target::LinuxX64,
+            options,
+        )?
+        .into_iter()
+        .find(|types| types.target().architecture() == Architecture::X86_64)
+        .unwrap();
+
+        Ok(types)
+    }
+
+    /// The source of a platform which declares each of `declarations`, and then
+    /// provides `main_for_host : main_type` to its host.
+    fn platform_source(declarations: &[&str], main_type: &str) -> String {
+        let declarations = declarations.join("\n\n");
+
+        indoc::formatdoc!(
+            r#"
+            platform "test-platform"
+                requires {{}} {{ main : _ }}
+                exposes []
+                packages {{}}
+                imports []
+                provides [main_for_host]
+
+            {declarations}
+
+            main_for_host : {main_type}
+            main_for_host = main
+            "#
+        )
+    }
+
+    /// Loads the platform [platform_source] makes, with the default [GlueOptions].
+    fn load_declarations(declarations: &[&str], main_type: &str) -> Types {
+        load_platform(
+            &platform_source(declarations, main_type),
+            &GlueOptions::default(),
+        )
+        .unwrap()
+    }
+
+    fn id_named(types: &Types, name: &str) -> TypeId {
+        *types
+            .types_by_name
+            .get(name)
+            .unwrap_or_else(|| panic!("no type is named {name}"))
+    }
+
+    #[test]
+    fn independent_types_sort_alphabetically() {
+        let types = load_declarations(
+            &[
+                "Zebra : { stripes : I64 }",
+                "Apple : { seeds : I64 }",
+                "Mango : { ripe : Bool }",
+            ],
+            "{ zebra : Zebra, apple : Apple, mango : Mango }",
+        );
+        let [zebra, apple, mango] = ["Zebra", "Apple", "Mango"].map(|name| id_named(&types, name));
+
+        let named: Vec<_> = types
+            .sorted_ids_by_name()
+            .into_iter()
+            .filter(|id| [zebra, apple, mango].contains(id))
+            .collect();
+
+        assert_eq!(named, vec![apple, mango, zebra]);
+    }
+
+    #[test]
+    fn sorting_by_name_keeps_dependencies_first() {
+        let types = load_declarations(
+            &["Zebra : { stripes : I64 }", "Apple : { zebra : Zebra }"],
+            "Apple",
+        );
+        let [zebra, apple] = ["Zebra", "Apple"].map(|name| id_named(&types, name));
+
+        let sorted = types.sorted_ids_by_name();
+        let position = |id| sorted.iter().position(|&other| other == id).unwrap();
+
+        assert_eq!(sorted.len(), types.ids().len());
+        assert!(position(zebra) < position(apple));
+    }
+
+    #[test]
+    fn only_aliases_and_entry_points_are_roots() {
+        let types = load_declarations(&["Shape : [Circle I64 I64, Square I64]"], "Shape");
+        let shape = id_named(&types, "Shape");
+        let payload = id_named(&types, "Shape_Circle");
+        let [(_, main)] = types.entry_points() else {
+            panic!("there should be exactly one entry point");
+        };
+
+        let i64_id = match types.get_type(payload) {
+            RocType::TagUnionPayload {
+                fields: RocStructFields::HasNoClosure { fields },
+                ..
+            } => fields[0].1,
+            other => panic!("Shape_Circle should be a tag union payload, not {other:?}"),
+        };
+
+        assert!(types.is_root(shape));
+        assert!(types.is_root(*main));
+        assert!(!types.is_root(payload));
+        assert!(!types.is_root(i64_id));
+
+        // Specs see the same roots, through Types.is_root.
+        let roots = roc_type::Types::from(&types).roots;
+        let mut expected = vec![shape.0 as u64, main.0 as u64];
+        expected.sort();
+        expected.dedup();
+
+        assert_eq!(roots.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn payloads_know_their_union() {
+        let options = GlueOptions {
+            nest_payload_types: true,
+            ..Default::default()
+        };
+        let types = load_platform(
+            &platform_source(&["Shape : [Circle I64 I64, Label Str Str]"], "Shape"),
+            &options,
+        )
+        .unwrap();
+        let [shape, circle, label] =
+            ["Shape", "Shape_Circle", "Shape_Label"].map(|name| id_named(&types, name));
+
+        assert!(types.nest_payload_types());
+        assert_eq!(types.payload_parent(circle), Some(shape));
+        assert_eq!(types.payload_parent(label), Some(shape));
+        assert_eq!(types.payload_parent(shape), None);
+
+        // Specs get the parents along with the option, so they can do the nesting.
+        let for_specs = roc_type::Types::from(&types);
+
+        assert!(for_specs.nest_payload_types);
+        assert_eq!(for_specs.payload_parents.len(), 2);
+    }
+
+    #[test]
+    fn shared_payloads_have_no_single_parent() {
+        let types = load_declarations(
+            &[
+                "Shape : [Circle I64 I64, Dot]",
+                "Span : [Range I64 I64, Empty]",
+            ],
+            "{ shape : Shape, span : Span }",
+        );
+        let [shape, span] = ["Shape", "Span"].map(|name| id_named(&types, name));
+        let first_payload = |id: TypeId| match types.get_type(id) {
+            RocType::TagUnion(RocTagUnion::NonRecursive { tags, .. }) => {
+                tags.iter().find_map(|(_, payload)| *payload).unwrap()
+            }
+            other => panic!("expected a non-recursive union, got {other:?}"),
+        };
+
+        // Circle and Range carry the same fields, so dedup gives them one payload struct.
+        let payload = first_payload(shape);
+        assert_eq!(first_payload(span), payload);
+
+        let mut parents = types.payload_parents(payload).to_vec();
+        parents.sort();
+
+        let mut expected = vec![shape, span];
+        expected.sort();
+
+        assert_eq!(parents, expected);
+        assert_eq!(types.payload_parent(payload), None);
+        assert!(!types.nest_payload_types());
+    }
+
+    #[test]
+    fn owns_heap_looks_through_fields() {
+        let types = load_declarations(
+            &[
+                "Plain : { small : U8, medium : U16 }",
+                "Named : { name : Str }",
+                "Outer : { plain : Plain, named : Named }",
+            ],
+            "U8 -> Outer",
+        );
+        let [plain, named, outer] = ["Plain", "Named", "Outer"].map(|name| id_named(&types, name));
+
+        assert!(!types.owns_heap(plain));
+        assert!(types.owns_heap(named));
+        assert!(types.owns_heap(outer));
+
+        // A toplevel function captures nothing, no matter what it returns.
+        let [(_, main)] = types.entry_points() else {
+            panic!("there should be exactly one entry point");
+        };
+
+        assert!(matches!(
+            types.get_type(*main),
+            RocType::Function(RocFn {
+                is_toplevel: true,
+                ..
+            })
+        ));
+        assert!(!types.owns_heap(*main));
+    }
+
+    #[test]
+    fn render_record_and_tag_union() {
+        let types = load_declarations(
+            &[
+                "Labeled : { x : I64, y : Str }",
+                "Outcome : [Err Str, Missing, Ok (List I64) Labeled]",
+            ],
+            "I64 -> Outcome",
+        );
+
+        assert_eq!(
+            render_roc_type(&types, id_named(&types, "Labeled")),
+            "{ x : I64, y : Str }"
+        );
+        assert_eq!(
+            render_roc_type(&types, id_named(&types, "Outcome")),
+            "[Err Str, Missing, Ok (List I64) { x : I64, y : Str }]"
+        );
+
+        let [(_, main)] = types.entry_points() else {
+            panic!("there should be exactly one entry point");
+        };
+
+        assert_eq!(
+            render_roc_type(&types, *main),
+            "I64 -> [Err Str, Missing, Ok (List I64) { x : I64, y : Str }]"
+        );
+    }
+
+    #[test]
+    fn merge_shares_identical_names_and_renames_the_rest() {
+        // Each module exposes the same `Point`, but the two `Size`s have different fields.
+        let module = |size: &str| {
+            load_declarations(
+                &["Point : { x : I64, y : I64 }", &format!("Size : {size}")],
+                "{ point : Point, size : Size }",
+            )
+        };
+
+        let merged = module("{ width : I64 }").merge(module("{ width : I64, height : I64 }"));
+        let named = |name: &str| {
+            merged
+                .ids()
+                .filter(|id| matches!(merged.get_type(*id), RocType::Struct { name: n, .. } if n == name))
+                .count()
+        };
+
+        assert_eq!(named("Point"), 1);
+        assert_eq!(named("Size"), 1);
+        assert_eq!(named("Size_2"), 1);
+
+        let field = |entry: usize, field_name: &str| match merged
+            .get_type(merged.entry_points()[entry].1)
+        {
+            RocType::Struct {
+                fields: RocStructFields::HasNoClosure { fields },
+                ..
+            } => {
+                fields
+                    .iter()
+                    .find(|(name, _)| name == field_name)
+                    .unwrap()
+                    .1
+            }
+            other => panic!("expected a record, got {other:?}"),
+        };
+
+        assert_eq!(field(0, "point"), id_named(&merged, "Point"));
+        assert_eq!(field(1, "point"), id_named(&merged, "Point"));
+        assert_eq!(field(0, "size"), id_named(&merged, "Size"));
+        assert_eq!(field(1, "size"), id_named(&merged, "Size_2"));
+        assert_eq!(merged.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn merge_renames_payloads_along_with_their_union() {
+        let module = |shape: &str| load_declarations(&[&format!("Shape : {shape}")], "Shape");
+
+        let merged = module("[Circle I64 I64, Square I64 I64]")
+            .merge(module("[Circle Str Str, Line I64 I64 I64]"));
+        let payload_name = |id: TypeId| match merged.get_type(id) {
+            RocType::TagUnionPayload { name, .. } => name.as_str(),
+            other => panic!("expected a payload, got {other:?}"),
+        };
+
+        let [shape, shape_2] = ["Shape", "Shape_2"].map(|name| id_named(&merged, name));
+
+        for (payload, parent) in [
+            ("Shape_Circle", shape),
+            ("Shape_Square", shape),
+            ("Shape_2_Circle", shape_2),
+            ("Shape_2_Line", shape_2),
+        ] {
+            let id = id_named(&merged, payload);
+
+            assert_eq!(payload_name(id), payload);
+            assert_eq!(merged.payload_parent(id), Some(parent));
+        }
+
+        assert!(!merged.types_by_name.contains_key("Shape_Circle_2"));
+        assert_eq!(merged.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn generated_names_do_not_depend_on_root_order() {
+        // What adding two entry points produces, in either order: each returns a
+        // record, and one of those records holds an anonymous tag union.
+        let build = |points_first: bool| {
+            let mut types = Types::with_capacity(8, Target::LinuxX64);
+            let i64_id = push_type(&mut types, RocType::Num(RocNum::I64), 8, 8);
+            let str_id = push_type(&mut types, RocType::RocStr, 24, 8);
+            let mut next = [1, 1];
+
+            let add_point = |types: &mut Types, next: &mut [u32; 2]| {
+                let name = format!("R{}", next[0]);
+                next[0] += 1;
+
+                let id = push_named(types, &name, i64_id);
+                let main = push_type(types, returning("points", id), 16, 8);
+                types.entry_points.push(("points".to_string(), main));
+            };
+
+            let add_labeled = |types: &mut Types, next: &mut [u32; 2]| {
+                let union_name = format!("U{}", next[1]);
+                let record_name = format!("R{}", next[0]);
+                next[1] += 1;
+                next[0] += 1;
+
+                let payload_name = format!("{union_name}_Named");
+                let payload = push_type(
+                    types,
+                    RocType::TagUnionPayload {
+                        name: payload_name.clone(),
+                        fields: RocStructFields::HasNoClosure {
+                            fields: vec![("0".to_string(), str_id)],
+                        },
+                    },
+                    24,
+                    8,
+                );
+                types.types_by_name.insert(payload_name, payload);
+
+                let union = push_type(
+                    types,
+                    RocType::TagUnion(RocTagUnion::NonRecursive {
+                        name: union_name.clone(),
+                        tags: vec![
+                            ("Named".to_string(), Some(payload)),
+                            ("Unnamed".to_string(), None),
+                        ],
+                        discriminant_offset: 24,
+                        discriminant_size: 1,
+                    }),
+                    32,
+                    8,
+                );
+                types.types_by_name.insert(union_name, union);
+
+                let record = push_type(
+                    types,
+                    RocType::Struct {
+                        name: record_name.clone(),
+                        fields: RocStructFields::HasNoClosure {
+                            fields: vec![("label".to_string(), union)],
+                        },
+                    },
+                    32,
+                    8,
+                );
+                types.types_by_name.insert(record_name, record);
+
+                let main = push_type(types, returning("labeled", record), 32, 8);
+                types.entry_points.push(("labeled".to_string(), main));
+            };
+
+            if points_first {
+                add_point(&mut types, &mut next);
+                add_labeled(&mut types, &mut next);
+            } else {
+                add_labeled(&mut types, &mut next);
+                add_point(&mut types, &mut next);
+            }
+
+            types.renumber_generated_names();
+
+            let mut named: Vec<(String, String)> = types
+                .types_by_name
+                .iter()
+                .map(|(name, id)| (name.clone(), render_roc_type(&types, *id)))
+                .collect();
+
+            named.sort();
+
+            named
+        };
+
+        let named = build(true);
+
+        assert_eq!(named, build(false));
+        assert_eq!(
+            named,
+            [
+                ("R1", "{ label : [Named Str, Unnamed] }"),
+                ("R2", "{ x : I64, y : I64 }"),
+                ("U1", "[Named Str, Unnamed]"),
+                ("U1_Named", "{ 0 : Str }"),
+            ]
+            .map(|(name, rendered)| (name.to_string(), rendered.to_string()))
+        );
+    }
+
+    #[test]
+    fn nullary_union_becomes_enumeration() {
+        let types = load_declarations(
+            &[
+                "Color : [Red {}, Green, Blue {}]",
+                "Label : [Named Str, Unnamed]",
+            ],
+            "{ color : Color, label : Label }",
+        );
+
+        assert_eq!(
+            types.get_type(id_named(&types, "Color")),
+            &RocType::TagUnion(RocTagUnion::Enumeration {
+                name: "Color".to_string(),
+                tags: vec!["Blue".to_string(), "Green".to_string(), "Red".to_string()],
+                size: 1,
+            })
+        );
+
+        match types.get_type(id_named(&types, "Label")) {
+            RocType::TagUnion(RocTagUnion::NonRecursive { tags, .. }) => {
+                assert!(tags.iter().any(|(_, payload)| payload.is_some()))
+            }
+            other => panic!("Label should be a non-recursive union, not {other:?}"),
+        }
+    }
+
+    #[test]
+    fn size_manifest_lists_named_types() {
+        let types = load_declarations(
+            &[
+                "Point : { x : I64, y : I64 }",
+                "Tagged : { point : Point, tag : U8 }",
+            ],
+            "Tagged",
+        );
+        let manifest = types.size_manifest();
+
+        let mut sorted = manifest.clone();
+        sorted.sort();
+        assert_eq!(manifest, sorted);
+
+        // 17 bytes of fields, padded out to the 8-byte alignment
+        let ours: Vec<_> = manifest
+            .into_iter()
+            .filter(|(name, _, _)| name == "Point" || name == "Tagged")
+            .collect();
+
+        assert_eq!(
+            ours,
+            vec![("Point".to_string(), 16, 8), ("Tagged".to_string(), 24, 8)]
+        );
+    }
+
+    #[test]
+    fn retain_named_prunes_unreachable_types() {
+        // A private type which a public one depends on must survive, whereas
+        // PrivateByte is only reachable from the entry point, which isn't kept.
+        let mut types = load_declarations(
+            &[
+                "PrivateSegment : { start : I64, end : I64 }",
+                "PublicLine : { segment : PrivateSegment }",
+                "PrivateByte : { value : U8 }",
+            ],
+            "{ line : PublicLine, byte : PrivateByte }",
+        );
+
+        types.retain_named(|name| name.starts_with("Public"));
+
+        let names: Vec<_> = types
+            .size_manifest()
+            .into_iter()
+            .map(|(name, _, _)| name)
+            .collect();
+
+        assert_eq!(names, ["PrivateSegment", "PublicLine"]);
+        assert!(types.entry_points().is_empty());
+
+        // unit, I64, PrivateSegment, PublicLine
+        assert_eq!(types.ids().len(), 4);
+
+        let line = types.types_by_name["PublicLine"];
+        let segment = types.types_by_name["PrivateSegment"];
+
+        match types.get_type(line) {
+            RocType::Struct {
+                fields: RocStructFields::HasNoClosure { fields },
+                ..
+            } => assert_eq!(fields[0].1, segment),
+            other => panic!("expected a struct, got {other:?}"),
+        }
+
+        assert_eq!(types.deps.get(&line), Some(&vec![segment]));
+        assert_eq!(types.sorted_ids().len(), types.ids().len());
+    }
+
+    #[test]
+    fn dedup_identical_anonymous_structs() {
+        let mut types = Types::with_capacity(8, Target::LinuxX64);
+        let i64_id = push_type(&mut types, RocType::Num(RocNum::I64), 8, 8);
+
+        let r1 = push_type(&mut types, point_struct("R1", i64_id), 16, 8);
+        types.types_by_name.insert("R1".to_string(), r1);
+        let first = push_type(&mut types, returning("first", r1), 16, 8);
+        types.depends(first, r1);
+        types.entry_points.push(("first".to_string(), first));
+
+        let r2 = push_type(&mut types, point_struct("R2", i64_id), 16, 8);
+        types.types_by_name.insert("R2".to_string(), r2);
+        let second = push_type(&mut types, returning("second", r2), 16, 8);
+        types.depends(second, r2);
+        types.entry_points.push(("second".to_string(), second));
+
+        types.dedup_structural();
+
+        let structs: Vec<TypeId> = types
+            .ids()
+            .filter(|id| matches!(types.get_type(*id), RocType::Struct { .. }))
+            .collect();
+
+        assert_eq!(structs.len(), 1);
+
+        // only the surviving struct's name is left, and it names that struct
+        assert_eq!(types.types_by_name.get("R1"), Some(&structs[0]));
+        assert_eq!(types.types_by_name.get("R2"), None);
+
+        for (_, fn_id) in types.entry_points() {
+            match types.get_type(*fn_id) {
+                RocType::Function(RocFn { ret, .. }) => assert_eq!(*ret, structs[0]),
+                other => panic!("expected a function, got {other:?}"),
+            }
+
+            assert_eq!(types.deps.get(fn_id), Some(&vec![structs[0]]));
+        }
+
+        // sorting must still work after ids were compacted
+        assert_eq!(types.sorted_ids().len(), types.ids().len());
+    }
+
+    #[test]
+    fn dedup_keeps_aliases_distinct() {
+        let mut types = Types::with_capacity(8, Target::LinuxX64);
+        let i64_id = push_type(&mut types, RocType::Num(RocNum::I64), 8, 8);
+
+        let point = push_type(&mut types, point_struct("Point", i64_id), 16, 8);
+        types.aliased.insert(point);
+        types.types_by_name.insert("Point".to_string(), point);
+
+        let anonymous = push_type(&mut types, point_struct("R1", i64_id), 16, 8);
+        types.types_by_name.insert("R1".to_string(), anonymous);
+
+        types.dedup_structural();
+
+        assert_eq!(types.ids().len(), 4);
+        assert_ne!(types.types_by_name["Point"], types.types_by_name["R1"]);
+    }
+
+    #[test]
+    fn equivalence_of_broken_cycle_hits_recursion_limit() {
+        let mut types = Types::with_capacity(4, Target::LinuxX64);
+
+        // A and B refer to each other directly, without a RecursivePointer in between,
+        // which resolving recursive types should never produce.
+        let a = TypeId(types.types.len());
+        let b = TypeId(a.0 + 1);
+
+        let struct_referring_to = |name: &str, id: TypeId| RocType::Struct {
+            name: name.to_string(),
+            fields: RocStructFields::HasNoClosure {
+                fields: vec![("next".to_string(), id)],
+            },
+        };
+
+        push_type(&mut types, struct_referring_to("A", b), 8, 8);
+        push_type(&mut types, struct_referring_to("B", a), 8, 8);
+
+        assert_eq!(
+            types.try_is_equivalent(types.get_type(a), types.get_type(b)),
+            Err(BindgenError::RecursionLimit {
+                limit: MAX_TYPE_DEPTH
+            })
+        );
+    }
+
+    #[test]
+    fn sorting_and_rendering_a_broken_cycle_are_errors() {
+        let mut types = Types::with_capacity(4, Target::LinuxX64);
+        let a = TypeId(types.types.len());
+        let b = TypeId(a.0 + 1);
+
+        let struct_referring_to = |name: &str, id: TypeId| RocType::Struct {
+            name: name.to_string(),
+            fields: RocStructFields::HasNoClosure {
+                fields: vec![("next".to_string(), id)],
+            },
+        };
+
+        push_type(&mut types, struct_referring_to("A", b), 8, 8);
+        push_type(&mut types, struct_referring_to("B", a), 8, 8);
+        types.depends(a, b);
+        types.depends(b, a);
+
+        let cycle = BindgenError::CyclicDependencies { ids: vec![a, b] };
+
+        assert_eq!(types.try_sorted_ids(), Err(cycle.clone()));
+        assert_eq!(types.try_sorted_ids_by_name(), Err(cycle.clone()));
+        assert!(cycle
+            .to_string()
+            .starts_with(&format!("The dependencies of {:?} form a cycle", [a, b])));
+
+        let limit = BindgenError::RecursionLimit {
+            limit: MAX_TYPE_DEPTH,
+        };
+
+        assert_eq!(try_render_roc_type(&types, a), Err(limit));
+    }
+
+    #[test]
+    fn equivalence_of_recursive_pointers_compares_targets() {
+        let mut types = Types::with_capacity(4, Target::LinuxX64);
+        let list = push_type(&mut types, RocType::RocList(Types::UNIT), 24, 8);
+
+        assert_eq!(
+            types.try_is_equivalent(
+                &RocType::RecursivePointer(list),
+                &RocType::RecursivePointer(list)
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            types.try_is_equivalent(
+                &RocType::RecursivePointer(list),
+                &RocType::RecursivePointer(Types::UNIT)
+            ),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn json_lists_every_type_and_struct_fields() {
+        let mut types = Types::with_capacity(8, Target::LinuxX64);
+        let i64_id = push_type(&mut types, RocType::Num(RocNum::I64), 8, 8);
+
+        let point = push_type(&mut types, point_struct("Point", i64_id), 16, 8);
+        types.types_by_name.insert("Point".to_string(), point);
+
+        let main = push_type(&mut types, returning("main", point), 16, 8);
+        types.depends(main, point);
+        types.entry_points.push(("main".to_string(), main));
+
+        let json = types.to_json();
+
+        for id in types.ids() {
+            assert!(json.contains(&format!("{{\"id\":{},", id.0)), "{json}");
+        }
+
+        assert!(json.contains(&format!(
+            r#""kind":"Struct","name":"Point","fields":[{{"name":"x","id":{0}}},{{"name":"y","id":{0}}}]"#,
+            i64_id.0
+        )));
+        assert!(json.contains(&format!(r#""deps":[{}]"#, point.0)));
+        assert!(json.contains(&format!(
+            r#""entry_points":[{{"name":"main","id":{}}}]"#,
+            main.0
+        )));
+    }
+
+    #[test]
+    fn json_refers_to_recursive_types_by_id() {
+        let mut types = Types::with_capacity(4, Target::LinuxX64);
+
+        // ConsList : [Nil, Cons Str ConsList]
+        let str_id = push_type(&mut types, RocType::RocStr, 24, 8);
+        let cons_list = TypeId(types.types.len());
+        let pointer = TypeId(cons_list.0 + 1);
+        let payload = TypeId(cons_list.0 + 2);
+
+        push_type(
+            &mut types,
+            RocType::TagUnion(RocTagUnion::NullableUnwrapped {
+                name: "ConsList".to_string(),
+                null_tag: "Nil".to_string(),
+                non_null_tag: "Cons".to_string(),
+                non_null_payload: payload,
+                null_represents_first_tag: false,
+            }),
+            8,
+            8,
+        );
+        push_type(&mut types, RocType::RecursivePointer(cons_list), 8, 8);
+        push_type(
+            &mut types,
+            RocType::TagUnionPayload {
+                name: "ConsList_Cons".to_string(),
+                fields: RocStructFields::HasNoClosure {
+                    fields: vec![("0".to_string(), str_id), ("1".to_string(), pointer)],
+                },
+            },
+            32,
+            8,
+        );
+
+        let json = types.to_json();
+
+        assert!(json.contains(&format!(
+            r#""kind":"RecursivePointer","pointee":{}"#,
+            cons_list.0
+        )));
+        assert_eq!(json.matches(r#""name":"ConsList""#).count(), 1);
+    }
+
+    #[test]
+    fn invariants_hold_for_recursive_union() {
+        let mut types = Types::with_capacity(4, Target::LinuxX64);
+
+        // ConsList : [Nil, Cons Str ConsList]
+        let str_id = push_type(&mut types, RocType::RocStr, 24, 8);
+        let cons_list = TypeId(types.types.len());
+        let pointer = TypeId(cons_list.0 + 1);
+        let payload = TypeId(cons_list.0 + 2);
+
+        push_type(
+            &mut types,
+            RocType::TagUnion(RocTagUnion::NullableUnwrapped {
+                name: "ConsList".to_string(),
+                null_tag: "Nil".to_string(),
+                non_null_tag: "Cons".to_string(),
+                non_null_payload: payload,
+                null_represents_first_tag: false,
+            }),
+            8,
+            8,
+        );
+        push_type(&mut types, RocType::RecursivePointer(cons_list), 8, 8);
+        push_type(
+            &mut types,
+            RocType::TagUnionPayload {
+                name: "ConsList_Cons".to_string(),
+                fields: RocStructFields::HasNoClosure {
+                    fields: vec![("0".to_string(), str_id), ("1".to_string(), pointer)],
+                },
+            },
+            32,
+            8,
+        );
+        types.depends(payload, str_id);
+
+        assert_eq!(types.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn invariants_catch_pending_and_unknown_ids() {
+        let mut types = Types::with_capacity(4, Target::LinuxX64);
+        let pointer = push_type(&mut types, RocType::RecursivePointer(TypeId::PENDING), 8, 8);
+
+        assert_eq!(
+            types.check_invariants(),
+            Err(BindgenError::PendingTypeId {
+                referenced_by: pointer
+            })
+        );
+
+        types.replace(pointer, RocType::RecursivePointer(Types::UNIT));
+        assert_eq!(types.check_invariants(), Ok(()));
+
+        let missing = TypeId(types.types.len());
+        types.depends(pointer, missing);
+
+        assert_eq!(
+            types.check_invariants(),
+            Err(BindgenError::UnknownTypeId {
+                id: missing,
+                referenced_by: pointer
+            })
+        );
+    }
+
+    #[test]
+    fn positional_labels_use_the_naming_scheme_but_sort_by_index() {
+        let labels = |naming| {
+            let mut labels: Vec<_> = [10, 2, 0]
+                .into_iter()
+                .map(|index| PositionalLabel { index, naming })
+                .collect();
+
+            labels.sort();
+            labels
+                .iter()
+                .map(|label| label.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(labels(PositionalNaming::Index), ["0", "2", "10"]);
+        assert_eq!(labels(PositionalNaming::Underscore), ["_0", "_2", "_10"]);
+        assert_eq!(labels(PositionalNaming::F), ["f0", "f2", "f10"]);
+        assert_eq!(
+            labels(PositionalNaming::Field),
+            ["field0", "field2", "field10"]
+        );
+    }
+
+    #[test]
+    fn positional_naming_applies_to_every_kind_of_tag_payload() {
+        let options = GlueOptions {
+            positional_naming: PositionalNaming::Underscore,
+            ..Default::default()
+        };
+        let types = load_platform(
+            &platform_source(
+                &["Op : [Add I64 I64, Neg I64]", "Wrapper : [Wrap U8 U16]"],
+                "{ op : Op, wrapper : Wrapper }",
+            ),
+            &options,
+        )
+        .unwrap();
+
+        let field_names = |fields: &RocStructFields| -> Vec<String> {
+            match fields {
+                RocStructFields::HasNoClosure { fields } => {
+                    fields.iter().map(|(name, _)| name.clone()).collect()
+                }
+                RocStructFields::HasClosure { .. } => panic!("there are no closures here"),
+            }
+        };
+
+        match types.get_type(id_named(&types, "Op_Add")) {
+            RocType::TagUnionPayload { fields, .. } => {
+                assert_eq!(field_names(fields), ["_0", "_1"])
+            }
+            other => panic!("Op_Add should be a tag union payload, not {other:?}"),
+        }
+
+        match types.get_type(id_named(&types, "Wrapper")) {
+            RocType::TagUnion(RocTagUnion::SingleTagStruct {
+                payload: RocSingleTagPayload::HasNoClosure { payload_fields },
+                ..
+            }) => {
+                let names: Vec<_> = payload_fields
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect();
+
+                assert_eq!(names, ["_0", "_1"]);
+            }
+            other => panic!("Wrapper should be a single-tag struct, not {other:?}"),
+        }
+    }
+
+    fn opaque_platform() -> String {
+        platform_source(
+            &[
+                "Handle : { descriptor : I64, flags : U32 }",
+                "Named : { label : Str }",
+            ],
+            "{ handle : Handle, named : Named }",
+        )
+    }
+
+    #[test]
+    fn opaque_types_expose_only_name_size_and_align() {
+        let options = GlueOptions {
+            opaque_names: VecSet::singleton("Handle".to_string()),
+            ..Default::default()
+        };
+        let types = load_platform(&opaque_platform(), &options).unwrap();
+        let handle = id_named(&types, "Handle");
+
+        assert_eq!(
+            types.get_type(handle),
+            &RocType::Opaque {
+                name: "Handle".to_string()
+            }
+        );
+        assert_eq!(types.size_ignoring_alignment(handle), 16);
+        assert_eq!(types.align(handle), 8);
+
+        // Its fields don't leak into any other type, either.
+        let json = types.to_json();
+
+        assert!(json.contains(r#""kind":"Opaque""#));
+        assert!(!json.contains("descriptor"));
+        assert!(!json.contains("flags"));
+
+        // Specs get a real Opaque shape, rather than something that looks like a struct.
+        let for_specs = roc_type::Types::from(&types);
+        let shape = &for_specs.types[handle.0];
+
+        assert_eq!(shape.discriminant(), roc_type::discriminant_RocType::Opaque);
+        assert_eq!(unsafe { shape.as_Opaque() }.as_str(), "Handle");
+    }
+
+    #[test]
+    fn refcounted_types_cannot_be_opaque() {
+        let options = GlueOptions {
+            opaque_names: VecSet::singleton("Named".to_string()),
+            ..Default::default()
+        };
+        let err = load_platform(&opaque_platform(), &options).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            BindgenError::RefcountedOpaque {
+                name: "Named".to_string()
+            }
+            .to_string()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+platform "test-platform"
+    requires {} { main : _ }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+Inner : { flag : Bool, count : U16 }
+
+Mixed : { a : U8, b : U64, c : U16, inner : Inner, name : Str, ratio : F32 }
+
+Numbers : { items : List I64, last : U8 }
+
+Shape : [Circle F64 F64, Label Str U8, Empty]
+
+main_for_host : { mixed : Mixed, numbers : Numbers, shape : Shape }
+main_for_host = main
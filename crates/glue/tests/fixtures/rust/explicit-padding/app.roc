@@ -0,0 +1,3 @@
+app [main] { pf: platform "platform.roc" }
+
+main = { a: 1995, b: 42, c: 7 }
@@ -0,0 +1,11 @@
+platform "test-platform"
+    requires {} { main : _ }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+Padded : { a : U64, b : U8, c : U16 }
+
+main_for_host : Padded
+main_for_host = main
@@ -0,0 +1,15 @@
+platform "test-platform"
+    requires {} { main : _ }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+Segment : { start : I64, end : I64 }
+
+Line : { segment : Segment }
+
+Byte : { value : U8 }
+
+main_for_host : { line : Line, byte : Byte }
+main_for_host = main
@@ -0,0 +1,3 @@
+app [main] { pf: platform "platform.roc" }
+
+main = Cons("World!", Cons("Hello ", Nil))
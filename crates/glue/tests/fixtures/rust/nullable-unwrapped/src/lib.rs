@@ -22,6 +22,11 @@ pub extern "C" fn rust_main() {
     assert!(tag_union.partial_cmp(&tag_union) == Some(Ordering::Equal)); // PartialOrd
     assert!(tag_union.cmp(&tag_union) == Ordering::Equal); // Ord
 
+    // Verify that the payload accessor handles both tags.
+
+    assert!(tag_union.get_Cons().is_some());
+    assert!(StrConsList::Nil().get_Cons().is_none());
+
     print!(
         indoc!(
             r#"
@@ -0,0 +1,13 @@
+platform "test-platform"
+    requires {} { main : _ }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+Point : { x : I32, y : I32 }
+
+Padded : { a : U64, b : U8 }
+
+main_for_host : { point : Point, padded : Padded }
+main_for_host = main
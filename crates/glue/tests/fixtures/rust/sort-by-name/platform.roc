@@ -0,0 +1,15 @@
+platform "test-platform"
+    requires {} { main : _ }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+Zebra : { stripes : U64 }
+
+Aardvark : { ants : U8 }
+
+Burrow : { zebra : Zebra, aardvark : Aardvark }
+
+main_for_host : Burrow
+main_for_host = main
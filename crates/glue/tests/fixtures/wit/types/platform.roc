@@ -0,0 +1,25 @@
+platform "test-platform"
+    requires {} { main : _ }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+Color : [Red, Green, Blue]
+
+Shape : [Circle F64, Rect F64 F64, Empty]
+
+Wrapper : [Wrapped I64 Str]
+
+Settings : {
+    list : List U8,
+    field_1 : U8,
+    big : U128,
+    color : Color,
+    shape : Shape,
+    wrapper : Wrapper,
+    outcome : Result Str U8,
+}
+
+main_for_host : Settings
+main_for_host = main
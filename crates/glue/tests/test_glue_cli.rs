@@ -14,6 +14,7 @@ mod glue_cli_tests {
     use cli_test_utils::{command::CmdOut, exec_cli::ExecCli};
 
     use crate::helpers::fixtures_dir;
+    use roc_glue::emit::EmitTarget;
     use std::path::{Path, PathBuf};
 
     #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
@@ -33,20 +34,25 @@ mod glue_cli_tests {
     /// without it, it would be possible that the fixtures are just exiting without running
     /// any assertions, and we would have no way to find out!
     ///
+    /// A fixture can also give `roc glue` extra arguments in brackets after its directory,
+    /// e.g. `name:"rust/dir" ["--flag"] => ...`.
+    ///
     /// Second, this generates an extra test which (non-recursively) traverses the
     /// fixtures/ directory and verifies that each of the .roc files in there
     /// has had a corresponding test generated in the previous step. This test
     /// will fail if we ever add a new .roc file to fixtures/ and forget to
     /// add a test for it here!
     macro_rules! fixtures {
-        ($($test_name:ident:$fixture_dir:expr => $ends_with:expr,)+) => {
+        ($($test_name:ident:$fixture_dir:literal $([$($glue_arg:literal),*])? => $ends_with:expr,)+) => {
             $(
                 #[test]
                 #[allow(non_snake_case)]
                 fn $test_name() {
                     let dir = fixtures_dir($fixture_dir);
 
-                    generate_glue_for(&dir, std::iter::empty());
+                    let glue_args: &[&str] = &[$($($glue_arg),*)?];
+
+                    generate_glue_for(&dir, glue_args.iter().copied());
 
                     fn validate<'a, I: IntoIterator<Item = &'a str> + std::fmt::Debug>(dir: PathBuf, args: I) {
                         let out = run_app(&dir.join("app.roc"), args);
@@ -67,7 +73,7 @@ mod glue_cli_tests {
                     let test_name_str = stringify!($test_name);
 
                     // TODO after #5924 is fixed; remove this
-                    let skip_on_linux_surgical_linker = ["rust_closures", "rust_option", "rust_nullable_wrapped", "rust_nullable_unwrapped", "rust_nonnullable_unwrapped", "rust_enumeration", "rust_nested_record", "rust_advanced_recursive_union"];
+                    let skip_on_linux_surgical_linker = ["rust_closures", "rust_option", "rust_nullable_wrapped", "rust_nullable_unwrapped", "rust_nested_payloads_nullable", "rust_nonnullable_unwrapped", "rust_enumeration", "rust_nested_record", "rust_advanced_recursive_union"];
 
                     // Validate linux with the default linker.
                     if !(cfg!(target_os = "linux") && (skip_on_linux_surgical_linker.contains(&test_name_str))) {
@@ -100,6 +106,7 @@ mod glue_cli_tests {
         rust_basic_record:"rust/basic-record" => "Record was: MyRcd { b: 42, a: 1995 }\n",
         rust_nested_record:"rust/nested-record" => "Record was: Outer { y: \"foo\", z: [1, 2], x: Inner { b: 24.0, a: 5 } }\n",
         rust_enumeration:"rust/enumeration" => "tag_union was: MyEnum::Foo, Bar is: MyEnum::Bar, Baz is: MyEnum::Baz\n",
+        rust_explicit_padding:"rust/explicit-padding" ["--explicit-padding"] => "Record was: Padded { a: 1995, b: 42, c: 7 }\n",
         rust_single_tag_union:"rust/single-tag-union" => indoc!(r#"
             tag_union was: SingleTagUnion::OneTag
         "#),
@@ -125,7 +132,7 @@ mod glue_cli_tests {
             `Single "small str"` is: StrFingerTree::Single("small str")
             `Empty` is: StrFingerTree::Empty
         "#),
-        rust_nullable_unwrapped:"rust/nullable-unwrapped" => indoc!(r#"
+        rust_nullable_unwrapped:"rust/nullable-unwrapped" ["--payload-accessors"] => indoc!(r#"
             tag_union was: StrConsList::Cons("World!", StrConsList::Cons("Hello ", StrConsList::Nil))
             `Cons "small str" Nil` is: StrConsList::Cons("small str", StrConsList::Nil)
             `Nil` is: StrConsList::Nil
@@ -139,11 +146,31 @@ mod glue_cli_tests {
             `Concat (String "Hello, ") (String "World!")` is: Expr::Concat(Expr::String("Hello, "), Expr::String("World!"))
             `String "this is a test"` is: Expr::String("this is a test")
         "#),
+        rust_positional_fields_underscore:"rust/positional-fields-underscore" ["--positional-fields", "underscore"] => indoc!(r#"
+            tag_union was: Expr::Concat(Expr::String("Hello, "), Expr::String("World!"))
+            `Concat (String "Hello, ") (String "World!")` is: Expr::Concat(Expr::String("Hello, "), Expr::String("World!"))
+            `String "this is a test"` is: Expr::String("this is a test")
+        "#),
+        rust_positional_fields_field:"rust/positional-fields-field" ["--positional-fields", "field"] => indoc!(r#"
+            tag_union was: Expr::Concat(Expr::String("Hello, "), Expr::String("World!"))
+            `Concat (String "Hello, ") (String "World!")` is: Expr::Concat(Expr::String("Hello, "), Expr::String("World!"))
+            `String "this is a test"` is: Expr::String("this is a test")
+        "#),
+        rust_nested_payloads_recursive:"rust/nested-payloads-recursive" ["--nest-payloads"] => indoc!(r#"
+            tag_union was: Expr::Concat(Expr::String("Hello, "), Expr::String("World!"))
+            `Concat (String "Hello, ") (String "World!")` is: Expr::Concat(Expr::String("Hello, "), Expr::String("World!"))
+            `String "this is a test"` is: Expr::String("this is a test")
+        "#),
+        rust_nested_payloads_nullable:"rust/nested-payloads-nullable" ["--nest-payloads", "--payload-accessors"] => indoc!(r#"
+            tag_union was: StrConsList::Cons("World!", StrConsList::Cons("Hello ", StrConsList::Nil))
+            `Cons "small str" Nil` is: StrConsList::Cons("small str", StrConsList::Nil)
+            `Nil` is: StrConsList::Nil
+        "#),
         rust_advanced_recursive_union:"rust/advanced-recursive-union" => indoc!(r#"
-            rbt was: Rbt { default: Job::Job(R1 { command: Command::Command(R2 { tool: Tool::SystemTool(R4 { name: "test", num: 42 }) }), input_files: ["foo"] }) }
+            rbt was: Rbt { default: Job::Job(R1 { command: Command::Command(R4 { tool: Tool::SystemTool(R3 { name: "test", num: 42 }) }), input_files: ["foo"] }) }
         "#),
         rust_list_recursive_union:"rust/list-recursive-union" => indoc!(r#"
-            rbt was: Rbt { default: Job::Job(R1 { command: Command::Command(R2 { args: [], tool: Tool::SystemTool(R3 { name: "test" }) }), input_files: ["foo"], job: [] }) }
+            rbt was: Rbt { default: Job::Job(R2 { command: Command::Command(R1 { args: [], tool: Tool::SystemTool(R3 { name: "test" }) }), input_files: ["foo"], job: [] }) }
         "#),
         rust_multiple_modules:"rust/multiple-modules" => indoc!(r#"
             combined was: Combined { s1: DepStr1::S("hello"), s2: DepStr2::R("world") }
@@ -171,6 +198,394 @@ mod glue_cli_tests {
         "#),
     }
 
+    #[test]
+    fn python_layouts_match_types() {
+        use roc_glue::load::{load_types, IgnoreErrors};
+        use roc_glue::types::{RocStructFields, RocType};
+        use roc_glue::GlueOptions;
+        use roc_load::Threading;
+        use roc_target::Target;
+
+        let dir = fixtures_dir("python/layouts");
+
+        generate_glue_for(&dir, std::iter::empty());
+
+        let target: Target = target_lexicon::Triple::host().into();
+        let types = load_types(
+            dir.join("platform.roc"),
+            Threading::Single,
+            IgnoreErrors { can: false },
+            target,
+            &GlueOptions::default(),
+        )
+        .unwrap()
+        .into_iter()
+        .find(|types| types.target().architecture() == target.architecture())
+        .unwrap();
+
+        // The spec declares a ctypes.Structure for each struct and payload without closures.
+        let expected: Vec<String> = types
+            .ids()
+            .filter_map(|id| match types.get_type(id) {
+                RocType::Struct {
+                    name,
+                    fields: RocStructFields::HasNoClosure { .. },
+                }
+                | RocType::TagUnionPayload {
+                    name,
+                    fields: RocStructFields::HasNoClosure { .. },
+                } => Some(format!(
+                    "{name} {} {}",
+                    types.size_rounded_to_alignment(id),
+                    types.align(id)
+                )),
+                _ => None,
+            })
+            .collect();
+
+        // Inner, Mixed, Numbers, the entry point's record, and Shape's two payloads
+        assert_eq!(expected.len(), 6, "{expected:?}");
+
+        let script = indoc!(
+            r#"
+            import ctypes, sys
+
+            sys.path.insert(0, sys.argv[1])
+
+            import roc_app
+
+            for name in sys.argv[2:]:
+                declared = getattr(roc_app, name)
+                print(name, ctypes.sizeof(declared), ctypes.alignment(declared))
+            "#
+        );
+        let out = std::process::Command::new("python3")
+            .arg("-c")
+            .arg(script)
+            .arg(dir.join("test_glue"))
+            .args(expected.iter().map(|line| line.split(' ').next().unwrap()))
+            .output()
+            .expect("running the generated glue requires python3");
+
+        assert!(
+            out.status.success(),
+            "{}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+
+        let actual: Vec<&str> = std::str::from_utf8(&out.stdout).unwrap().lines().collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn rust_payload_accessors_are_opt_in() {
+        let dir = fixtures_dir("rust/payload-accessors");
+        let accessor = "pub fn get_Cons(&self) -> Option<&StrConsList_Cons> {";
+
+        generate_glue_for(&dir, std::iter::empty());
+
+        let rust = generated_rust(&dir);
+        assert!(!rust.contains(accessor), "{rust}");
+
+        generate_glue_for(&dir, ["--payload-accessors"]);
+
+        let rust = generated_rust(&dir);
+        assert!(rust.contains(accessor), "{rust}");
+    }
+
+    #[test]
+    fn rust_sort_by_name_declares_dependencies_first_then_alphabetically() {
+        let dir = fixtures_dir("rust/sort-by-name");
+
+        generate_glue_for(&dir, ["--sort-by-name"]);
+
+        let rust = generated_rust(&dir);
+        let position = |name: &str| {
+            rust.find(&format!("pub struct {name} "))
+                .unwrap_or_else(|| panic!("{name} was not declared in:\n\n{rust}"))
+        };
+
+        // Burrow depends on the other two, which don't depend on each other.
+        assert!(position("Aardvark") < position("Zebra"), "{rust}");
+        assert!(position("Zebra") < position("Burrow"), "{rust}");
+    }
+
+    #[test]
+    fn size_manifest_lists_named_types() {
+        let dir = fixtures_dir("rust/size-manifest");
+        let manifest_dir = tempfile::tempdir().unwrap();
+        let manifest_path = manifest_dir.path().join("sizes.txt");
+
+        generate_glue_for(&dir, ["--size-manifest", manifest_path.to_str().unwrap()]);
+
+        let manifest = std::fs::read_to_string(&manifest_path).unwrap();
+        let lines: Vec<&str> = manifest.lines().collect();
+
+        // Padded's size includes the padding after `b`, as `sizeof` would report it.
+        assert!(lines.contains(&"x86_64 Padded 16 8"), "{manifest}");
+        assert!(lines.contains(&"x86_64 Point 8 4"), "{manifest}");
+    }
+
+    #[test]
+    fn keep_generates_only_the_named_types_and_their_dependencies() {
+        let dir = fixtures_dir("rust/keep");
+
+        generate_glue_for(&dir, ["--keep", "Line"]);
+
+        let rust = generated_rust(&dir);
+
+        assert!(rust.contains("pub struct Line "), "{rust}");
+        assert!(rust.contains("pub struct Segment "), "{rust}");
+        assert!(!rust.contains("Byte"), "{rust}");
+
+        // main_for_host returns a record which wasn't kept, so it's left out too.
+        assert!(!rust.contains("main_for_host"), "{rust}");
+    }
+
+    /// The Rust glue generated for each architecture, one after the other.
+    fn generated_rust(platform_dir: &Path) -> String {
+        let src_dir = platform_dir.join("test_glue").join("roc_app").join("src");
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(src_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+
+        paths.sort();
+
+        paths
+            .iter()
+            .map(|path| std::fs::read_to_string(path).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn wit_glue_parses() {
+        let dir = fixtures_dir("wit/types");
+
+        generate_glue_for(&dir, std::iter::empty());
+
+        let wit = std::fs::read_to_string(dir.join("test_glue").join("roc-app.wit")).unwrap();
+        let declared = parse_wit(&wit).unwrap_or_else(|err| panic!("{err} in:\n\n{wit}"));
+
+        for name in ["color", "shape", "wrapper", "settings", "roc-u128"] {
+            assert!(
+                declared.contains(&name),
+                "{name} was not declared in:\n\n{wit}"
+            );
+        }
+    }
+
+    /// Parses the subset of WIT that the wit spec generates: a package with one interface
+    /// of records, variants and enums. Returns the names of the declared types, after
+    /// checking that every name is a valid WIT identifier and every type refers to either
+    /// a builtin or one of those declarations.
+    fn parse_wit(wit: &str) -> Result<Vec<&str>, String> {
+        let mut lines = wit
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with("//"));
+
+        if lines.next() != Some("package roc:app;") || lines.next() != Some("interface types {") {
+            return Err("expected `package roc:app;` and then `interface types {`".to_string());
+        }
+
+        let mut declared = Vec::new();
+        let mut referenced = Vec::new();
+
+        loop {
+            let line = lines.next().ok_or("the interface is never closed")?;
+
+            if line == "}" {
+                break;
+            }
+
+            let (kind, name) = line
+                .strip_suffix(" {")
+                .and_then(|header| header.split_once(' '))
+                .ok_or_else(|| format!("expected a declaration, got `{line}`"))?;
+
+            declared.push(parse_wit_name(name)?);
+
+            loop {
+                let line = lines
+                    .next()
+                    .ok_or_else(|| format!("{name} is never closed"))?;
+
+                if line == "}" {
+                    break;
+                }
+
+                let member = line
+                    .strip_suffix(',')
+                    .ok_or_else(|| format!("expected a trailing comma in `{line}`"))?;
+
+                match kind {
+                    "record" => {
+                        let (field, typ) = member
+                            .split_once(": ")
+                            .ok_or_else(|| format!("expected `name: type`, got `{member}`"))?;
+
+                        parse_wit_name(field)?;
+                        parse_wit_type(typ, &mut referenced)?;
+                    }
+                    "variant" => match member.split_once('(') {
+                        Some((case, payload)) => {
+                            parse_wit_name(case)?;
+                            parse_wit_type(
+                                payload
+                                    .strip_suffix(')')
+                                    .ok_or_else(|| format!("unclosed payload in `{member}`"))?,
+                                &mut referenced,
+                            )?;
+                        }
+                        None => {
+                            parse_wit_name(member)?;
+                        }
+                    },
+                    "enum" => {
+                        parse_wit_name(member)?;
+                    }
+                    _ => return Err(format!("unexpected declaration kind `{kind}`")),
+                }
+            }
+        }
+
+        if let Some(extra) = lines.next() {
+            return Err(format!("unexpected `{extra}` after the interface"));
+        }
+
+        match referenced.iter().find(|name| !declared.contains(name)) {
+            Some(name) => Err(format!("{name} is used, but never declared")),
+            None => Ok(declared),
+        }
+    }
+
+    const WIT_KEYWORDS: &[&str] = &[
+        "as",
+        "bool",
+        "borrow",
+        "char",
+        "constructor",
+        "enum",
+        "export",
+        "f32",
+        "f64",
+        "flags",
+        "from",
+        "func",
+        "import",
+        "include",
+        "interface",
+        "list",
+        "option",
+        "own",
+        "package",
+        "record",
+        "resource",
+        "result",
+        "s16",
+        "s32",
+        "s64",
+        "s8",
+        "static",
+        "string",
+        "tuple",
+        "type",
+        "u16",
+        "u32",
+        "u64",
+        "u8",
+        "use",
+        "variant",
+        "with",
+        "world",
+    ];
+
+    /// A WIT identifier is kebab-case words, where only the first has to start with a
+    /// letter. Keywords have to be escaped with a `%`, and the escape is not part of the name.
+    fn parse_wit_name(name: &str) -> Result<&str, String> {
+        let unescaped = name.strip_prefix('%').unwrap_or(name);
+        let valid_word = |word: &str| {
+            !word.is_empty()
+                && (word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                    || word
+                        .chars()
+                        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()))
+        };
+        let starts_with_letter = unescaped
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic());
+
+        if !starts_with_letter || !unescaped.split('-').all(valid_word) {
+            Err(format!("`{name}` is not a valid WIT identifier"))
+        } else if name == unescaped && WIT_KEYWORDS.contains(&name) {
+            Err(format!(
+                "`{name}` is a WIT keyword, so it has to be escaped"
+            ))
+        } else {
+            Ok(unescaped)
+        }
+    }
+
+    fn parse_wit_type<'a>(typ: &'a str, referenced: &mut Vec<&'a str>) -> Result<(), String> {
+        const PRIMITIVES: &[&str] = &[
+            "bool", "s8", "u8", "s16", "u16", "s32", "u32", "s64", "u64", "f32", "f64", "char",
+            "string",
+        ];
+
+        let Some((outer, args)) = typ
+            .strip_suffix('>')
+            .and_then(|generic| generic.split_once('<'))
+        else {
+            if PRIMITIVES.contains(&typ) || typ == "result" {
+                return Ok(());
+            }
+
+            referenced.push(parse_wit_name(typ)?);
+
+            return Ok(());
+        };
+
+        // Split the arguments on the commas which aren't nested inside another `<...>`.
+        let mut depth = 0;
+        let mut start = 0;
+        let mut parts = Vec::new();
+
+        for (index, c) in args.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(args[start..index].trim());
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+
+        if !args.trim().is_empty() {
+            parts.push(args[start..].trim());
+        }
+
+        match (outer, parts.as_slice()) {
+            ("list", [elem]) => parse_wit_type(*elem, referenced),
+            ("tuple", elems) => elems
+                .iter()
+                .try_for_each(|elem| parse_wit_type(*elem, referenced)),
+            ("result", ["_", err]) => parse_wit_type(*err, referenced),
+            ("result", [ok]) => parse_wit_type(*ok, referenced),
+            ("result", [ok, err]) => {
+                parse_wit_type(*ok, referenced)?;
+                parse_wit_type(*err, referenced)
+            }
+            _ => Err(format!("`{typ}` is not a valid WIT type")),
+        }
+    }
+
     fn check_for_tests(all_fixtures: &mut roc_collections::VecSet<String>) {
         use roc_collections::VecSet;
 
@@ -236,20 +651,18 @@ mod glue_cli_tests {
             // std::fs::create_dir(&glue_dir)
         }
 
-        let glue_spec_filename = match fixtures_subfolder_name.to_str().unwrap() {
-            "rust" => "RustGlue.roc",
-            "zig" => "ZigGlue.roc",
-            "c" => "CGlue.roc",
-            unknown_subfolder => panic!("I don't know which glue file to use for tests in the `{}` subfolder! Please add one here!", unknown_subfolder),
-        };
-
-        println!("here");
-
-        let rust_glue_spec = tests_dir
-            .parent()
+        let glue_target: EmitTarget = fixtures_subfolder_name
+            .to_str()
             .unwrap()
-            .join("src")
-            .join(glue_spec_filename);
+            .parse()
+            .unwrap_or_else(|unknown| {
+                panic!("I don't know which glue file to use for tests: {unknown}! Please add an EmitTarget for it.")
+            });
+
+        let spec_dir = tempfile::tempdir().unwrap();
+        let glue_spec = glue_target
+            .write_spec(spec_dir.path())
+            .expect("Unable to write the bundled glue spec");
 
         // Generate a fresh test_glue for this platform
         let all_args : Vec<_> =
@@ -259,7 +672,7 @@ mod glue_cli_tests {
                 platform_module_path.to_str().unwrap().to_string(),
             ]).collect();
 
-        let glue_cmd = ExecCli::new("glue", rust_glue_spec).add_args(all_args);
+        let glue_cmd = ExecCli::new("glue", glue_spec).add_args(all_args);
         let glue_cmd_out = glue_cmd.run();
 
         glue_cmd_out.assert_clean_success();